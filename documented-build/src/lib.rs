@@ -0,0 +1,556 @@
+//! Extract doc comments from Rust source files in build scripts.
+//!
+//! This is for doc consumers that need the docs before the crate itself is
+//! compiled, and therefore cannot use the macros of `documented`. Extraction
+//! and trimming work exactly like they do in the macros.
+//!
+//! # Example
+//!
+//! In `build.rs`:
+//!
+//! ```rust,no_run
+//! use std::{env, fs, path::Path};
+//!
+//! let items = documented_build::extract_file("src/openings.rs", true).unwrap();
+//! let out_dir = env::var("OUT_DIR").unwrap();
+//! fs::write(
+//!     Path::new(&out_dir).join("opening_docs.rs"),
+//!     documented_build::render_consts(&items),
+//! )
+//! .unwrap();
+//! println!("cargo:rerun-if-changed=src/openings.rs");
+//! ```
+//!
+//! Then anywhere in the crate (or another crate using the same build script):
+//!
+//! ```rust,ignore
+//! include!(concat!(env!("OUT_DIR"), "/opening_docs.rs"));
+//! ```
+
+use std::{fs, io, path::Path};
+
+use convert_case::{Case, Casing};
+use syn::{
+    punctuated::Punctuated, spanned::Spanned, Attribute, Expr, ExprGroup, ExprLit, ExprMacro,
+    ExprParen, Fields, Item, Lit, Macro, Meta, Token,
+};
+
+/// The docs of a single item in a source file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ItemDocs {
+    /// The path of the item relative to the file, e.g. `Board`,
+    /// `pieces::Piece::Knight`.
+    ///
+    /// Fields and variants are included as children of their type.
+    pub path: Vec<String>,
+    /// The (optionally trimmed) doc comments of the item.
+    pub docs: String,
+}
+
+/// Errors of `documented-build`.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The source file could not be read.
+    #[error("Failed to read source file: {0}")]
+    Io(#[from] io::Error),
+    /// The source could not be parsed, or contains doc comments that cannot be
+    /// evaluated.
+    #[error("Failed to extract docs: {0}")]
+    Syn(#[from] syn::Error),
+}
+
+/// Extract the doc comments of a list of attributes, optionally trimming each
+/// line.
+///
+/// Besides string literals, doc attributes may use the macros `include_str!`,
+/// `concat!`, and `env!`, e.g. `#[doc = env!("CARGO_PKG_DESCRIPTION")]`.
+/// The compiler resolves relative `include_str!` paths against the directory
+/// of the file containing them. As that file is not known here, relative paths
+/// are an error; use an absolute one such as
+/// `include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/docs/board.md"))`, or
+/// [`extract_file`], which knows the file.
+///
+/// Docs in `#[cfg_attr(...)]` are included if the predicate holds according
+/// to the `CARGO_CFG_*` and `CARGO_FEATURE_*` environment variables that Cargo
+/// sets for build scripts. The macros never see `cfg_attr` themselves, as the
+/// compiler resolves them beforehand. Likewise, [`extract`] skips items,
+/// fields, and variants whose `#[cfg(...)]` does not hold.
+///
+/// Evaluating `cfg` predicates therefore only works in build scripts; outside
+/// of one, they are an error rather than silently treated as false.
+///
+/// Returns `None` if there are no doc comments. This is the same logic used by
+/// the macros of `documented`.
+pub fn get_docs(attrs: &[Attribute], trim: bool) -> syn::Result<Option<String>> {
+    get_docs_in(attrs, trim, None)
+}
+
+/// [`get_docs`], resolving relative `include_str!` paths against `dir` if
+/// given.
+fn get_docs_in(attrs: &[Attribute], trim: bool, dir: Option<&Path>) -> syn::Result<Option<String>> {
+    let mut string_literals = Vec::new();
+    for attr in attrs {
+        push_doc_values(&attr.meta, dir, &mut string_literals)?;
+    }
+
+    if string_literals.is_empty() {
+        return Ok(None);
+    }
+
+    let docs = if trim {
+        string_literals
+            .iter()
+            .flat_map(|lit| lit.split('\n').collect::<Vec<_>>())
+            .map(|line| line.trim().to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    } else {
+        string_literals.join("\n")
+    };
+
+    Ok(Some(docs))
+}
+
+/// Evaluate the doc attributes in `meta`, which may be a (nested) `cfg_attr`.
+fn push_doc_values(meta: &Meta, dir: Option<&Path>, values: &mut Vec<String>) -> syn::Result<()> {
+    match meta {
+        Meta::NameValue(name_value) if name_value.path.is_ident("doc") => {
+            values.push(eval_doc_value(&name_value.value, dir)?);
+        }
+        Meta::List(list) if list.path.is_ident("cfg_attr") => {
+            let args = list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+            let mut args = args.iter();
+            let predicate = args
+                .next()
+                .ok_or_else(|| syn::Error::new(list.span(), "Missing cfg_attr predicate"))?;
+            if build_script_cfg_enabled(predicate)? {
+                for meta in args {
+                    push_doc_values(meta, dir, values)?;
+                }
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Whether all `#[cfg(...)]` attributes in `attrs` hold, i.e. whether the
+/// item is compiled.
+fn cfg_attrs_enabled(attrs: &[Attribute]) -> syn::Result<bool> {
+    for attr in attrs {
+        if let Meta::List(list) = &attr.meta {
+            if list.path.is_ident("cfg") && !build_script_cfg_enabled(&list.parse_args()?)? {
+                return Ok(false);
+            }
+        }
+    }
+    Ok(true)
+}
+
+/// [`cfg_enabled`], erroring if not run in a build script, where the
+/// environment variables it relies on would be missing.
+fn build_script_cfg_enabled(predicate: &Meta) -> syn::Result<bool> {
+    // always set by Cargo for build scripts
+    if std::env::var_os("CARGO_CFG_TARGET_ARCH").is_none() {
+        return Err(syn::Error::new(
+            predicate.span(),
+            "cfg predicates can only be evaluated in build scripts, as `CARGO_CFG_*` \
+            environment variables are not set",
+        ));
+    }
+    cfg_enabled(predicate)
+}
+
+/// Evaluate a `cfg` predicate using the environment variables Cargo sets for
+/// build scripts, e.g. `CARGO_FEATURE_ENGINE` for `feature = "engine"` and
+/// `CARGO_CFG_TARGET_OS` for `target_os = "linux"`.
+fn cfg_enabled(predicate: &Meta) -> syn::Result<bool> {
+    let env_name = |name: &str| name.to_uppercase().replace('-', "_");
+    match predicate {
+        Meta::List(list) => {
+            let args = list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+            let mut results = args.iter().map(cfg_enabled);
+            if list.path.is_ident("all") {
+                results.try_fold(true, |all, result| Ok(all && result?))
+            } else if list.path.is_ident("any") {
+                results.try_fold(false, |any, result| Ok(any || result?))
+            } else if list.path.is_ident("not") && args.len() == 1 {
+                Ok(!cfg_enabled(&args[0])?)
+            } else {
+                Err(syn::Error::new(list.span(), "Invalid cfg predicate"))
+            }
+        }
+        Meta::Path(path) => {
+            let name = path
+                .get_ident()
+                .ok_or_else(|| syn::Error::new(path.span(), "Invalid cfg predicate"))?;
+            Ok(std::env::var_os(format!("CARGO_CFG_{}", env_name(&name.to_string()))).is_some())
+        }
+        Meta::NameValue(name_value) => {
+            let name = name_value
+                .path
+                .get_ident()
+                .ok_or_else(|| syn::Error::new(name_value.path.span(), "Invalid cfg predicate"))?
+                .to_string();
+            let value = match &name_value.value {
+                Expr::Lit(ExprLit { lit: Lit::Str(s), .. }) => s.value(),
+                other => Err(syn::Error::new(other.span(), "Invalid cfg predicate"))?,
+            };
+            if name == "feature" {
+                Ok(std::env::var_os(format!("CARGO_FEATURE_{}", env_name(&value))).is_some())
+            } else {
+                let values = std::env::var(format!("CARGO_CFG_{}", env_name(&name)));
+                Ok(values.map_or(false, |values| values.split(',').any(|v| v == value)))
+            }
+        }
+    }
+}
+
+/// Evaluate the value of a doc attribute. See [`get_docs`].
+fn eval_doc_value(expr: &Expr, dir: Option<&Path>) -> syn::Result<String> {
+    match expr {
+        Expr::Lit(ExprLit { lit: Lit::Str(s), .. }) => Ok(s.value()),
+        Expr::Macro(ExprMacro { mac, .. }) => eval_doc_macro(mac, dir),
+        Expr::Group(ExprGroup { expr, .. }) | Expr::Paren(ExprParen { expr, .. }) => {
+            eval_doc_value(expr, dir)
+        }
+        other => Err(syn::Error::new(
+            other.span(),
+            "Doc comment is not a string literal",
+        )),
+    }
+}
+
+fn eval_doc_macro(mac: &Macro, dir: Option<&Path>) -> syn::Result<String> {
+    let name = mac
+        .path
+        .segments
+        .last()
+        .map(|segment| segment.ident.to_string())
+        .unwrap_or_default();
+    let args = mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated)?;
+    let args = args.iter().collect::<Vec<_>>();
+    match (name.as_str(), args.as_slice()) {
+        ("concat", args) => args.iter().map(|arg| eval_concat_arg(arg, dir)).collect(),
+        ("env", [name] | [name, _]) => {
+            let name = eval_doc_value(name, dir)?;
+            std::env::var(&name).map_err(|_| {
+                syn::Error::new(
+                    mac.span(),
+                    format!("environment variable `{name}` not defined at compile time"),
+                )
+            })
+        }
+        ("include_str", [path]) => {
+            let path = Path::new(&eval_doc_value(path, dir)?).to_path_buf();
+            let path = match dir {
+                _ if path.is_absolute() => path,
+                Some(dir) => dir.join(path),
+                None => {
+                    return Err(syn::Error::new(
+                        mac.span(),
+                        format!(
+                            "Cannot resolve relative path `{}` outside of a source file; \
+                            use e.g. `concat!(env!(\"CARGO_MANIFEST_DIR\"), \"/{}\")`",
+                            path.display(),
+                            path.display(),
+                        ),
+                    ))
+                }
+            };
+            fs::read_to_string(&path).map_err(|err| {
+                syn::Error::new(
+                    mac.span(),
+                    format!("Failed to include `{}`: {err}", path.display()),
+                )
+            })
+        }
+        _ => Err(syn::Error::new(
+            mac.span(),
+            "Doc comment is not a string literal, or one of `include_str!`, `concat!`, and \
+            `env!`",
+        )),
+    }
+}
+
+/// Like `concat!`, also accept non-string literals.
+fn eval_concat_arg(expr: &Expr, dir: Option<&Path>) -> syn::Result<String> {
+    match expr {
+        Expr::Lit(ExprLit { lit, .. }) => match lit {
+            Lit::Char(c) => Ok(c.value().to_string()),
+            Lit::Int(i) => Ok(i.base10_digits().to_string()),
+            Lit::Float(f) => Ok(f.base10_digits().to_string()),
+            Lit::Bool(b) => Ok(b.value.to_string()),
+            _ => eval_doc_value(expr, dir),
+        },
+        _ => eval_doc_value(expr, dir),
+    }
+}
+
+/// Extract the docs of all documented items in a source file, including those
+/// in inline modules, struct fields, and enum variants.
+///
+/// Relative `include_str!` paths are resolved against the directory of the
+/// file.
+pub fn extract_file(path: impl AsRef<Path>, trim: bool) -> Result<Vec<ItemDocs>, Error> {
+    let path = path.as_ref();
+    let source = fs::read_to_string(path)?;
+    Ok(extract_in(&source, trim, path.parent())?)
+}
+
+/// Extract the docs of all documented items in Rust source code, including
+/// those in inline modules, struct fields, and enum variants.
+///
+/// Items are returned in source order, with fields and variants after their
+/// parent type. Relative `include_str!` paths are resolved like in
+/// [`get_docs`].
+pub fn extract(source: &str, trim: bool) -> syn::Result<Vec<ItemDocs>> {
+    extract_in(source, trim, None)
+}
+
+/// [`extract`], resolving relative `include_str!` paths against `dir` if
+/// given.
+fn extract_in(source: &str, trim: bool, dir: Option<&Path>) -> syn::Result<Vec<ItemDocs>> {
+    let file = syn::parse_file(source)?;
+    let mut items = Vec::new();
+    extract_items(&file.items, &[], trim, dir, &mut items)?;
+    Ok(items)
+}
+
+fn extract_items(
+    source_items: &[Item],
+    parent: &[String],
+    trim: bool,
+    dir: Option<&Path>,
+    items: &mut Vec<ItemDocs>,
+) -> syn::Result<()> {
+    for item in source_items {
+        let (name, attrs) = match item {
+            Item::Const(item) => (&item.ident, &item.attrs),
+            Item::Enum(item) => (&item.ident, &item.attrs),
+            Item::Fn(item) => (&item.sig.ident, &item.attrs),
+            Item::Mod(item) => (&item.ident, &item.attrs),
+            Item::Static(item) => (&item.ident, &item.attrs),
+            Item::Struct(item) => (&item.ident, &item.attrs),
+            Item::Trait(item) => (&item.ident, &item.attrs),
+            Item::Type(item) => (&item.ident, &item.attrs),
+            Item::Union(item) => (&item.ident, &item.attrs),
+            Item::Macro(item) => match &item.ident {
+                Some(ident) => (ident, &item.attrs),
+                None => continue,
+            },
+            _ => continue,
+        };
+        if !cfg_attrs_enabled(attrs)? {
+            continue;
+        }
+        let path = child_path(parent, name.to_string());
+        push_docs(items, path.clone(), attrs, trim, dir)?;
+
+        match item {
+            Item::Struct(item) => extract_fields(&item.fields, &path, trim, dir, items)?,
+            Item::Enum(item) => {
+                for variant in &item.variants {
+                    if !cfg_attrs_enabled(&variant.attrs)? {
+                        continue;
+                    }
+                    let variant_path = child_path(&path, variant.ident.to_string());
+                    push_docs(items, variant_path.clone(), &variant.attrs, trim, dir)?;
+                    extract_fields(&variant.fields, &variant_path, trim, dir, items)?;
+                }
+            }
+            Item::Mod(item) => {
+                if let Some((_, content)) = &item.content {
+                    extract_items(content, &path, trim, dir, items)?;
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Anonymous fields are named by their index, not counting fields that are
+/// not compiled.
+fn extract_fields(
+    fields: &Fields,
+    parent: &[String],
+    trim: bool,
+    dir: Option<&Path>,
+    items: &mut Vec<ItemDocs>,
+) -> syn::Result<()> {
+    let mut index = 0;
+    for field in fields {
+        if !cfg_attrs_enabled(&field.attrs)? {
+            continue;
+        }
+        let name = field
+            .ident
+            .as_ref()
+            .map_or_else(|| index.to_string(), ToString::to_string);
+        push_docs(items, child_path(parent, name), &field.attrs, trim, dir)?;
+        index += 1;
+    }
+    Ok(())
+}
+
+fn child_path(parent: &[String], name: String) -> Vec<String> {
+    let mut path = parent.to_vec();
+    path.push(name);
+    path
+}
+
+/// Add the docs of an item, if it has any.
+fn push_docs(
+    items: &mut Vec<ItemDocs>,
+    path: Vec<String>,
+    attrs: &[Attribute],
+    trim: bool,
+    dir: Option<&Path>,
+) -> syn::Result<()> {
+    if let Some(docs) = get_docs_in(attrs, trim, dir)? {
+        items.push(ItemDocs { path, docs });
+    }
+    Ok(())
+}
+
+/// Render extracted docs as Rust source code, with one `pub const` per item.
+///
+/// Like with `#[docs_const]`, constants are named `<PATH>_DOCS` in
+/// `SCREAMING_SNAKE_CASE`, e.g. `PIECES_PIECE_KNIGHT_DOCS` for
+/// `pieces::Piece::Knight`.
+///
+/// ```rust
+/// let items = documented_build::extract(
+///     "/// Develop your pieces.\nfn opening() {}",
+///     true,
+/// )
+/// .unwrap();
+/// assert_eq!(
+///     documented_build::render_consts(&items),
+///     "pub const OPENING_DOCS: &str = \"Develop your pieces.\";\n"
+/// );
+/// ```
+pub fn render_consts(items: &[ItemDocs]) -> String {
+    items
+        .iter()
+        .map(|ItemDocs { path, docs }| {
+            let name = path
+                .iter()
+                .map(|segment| segment.to_case(Case::ScreamingSnake))
+                .collect::<Vec<_>>()
+                .join("_");
+            format!("pub const {name}_DOCS: &str = {docs:?};\n")
+        })
+        .collect()
+}
+
+/// A feature declared in the `[features]` table of a `Cargo.toml`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FeatureDocs {
+    /// The name of the feature.
+    pub name: String,
+    /// The `## ` doc comments directly above the feature, trimmed, or `None`
+    /// if there are none.
+    pub docs: Option<String>,
+}
+
+/// Extract the features of a `Cargo.toml` file and their docs. See
+/// [`extract_features`].
+pub fn extract_features_file(path: impl AsRef<Path>) -> io::Result<Vec<FeatureDocs>> {
+    let manifest = fs::read_to_string(path)?;
+    Ok(extract_features(&manifest))
+}
+
+/// Extract the features of a `Cargo.toml` manifest in declaration order, with
+/// the `## ` doc comments above each feature.
+///
+/// Like with `document-features`, only comments starting with `##` count as
+/// docs; regular `#` comments are ignored.
+///
+/// This is a line-based scan rather than a full TOML parser, as those discard
+/// comments. It supports the usual layout of a `[features]` table: one
+/// feature per line, with an optionally quoted name, and an array of basic or
+/// literal strings that may span several lines and contain comments. Anything
+/// else is not supported, in particular:
+///
+/// - features declared with dotted keys (e.g. `features.engine = []`) or in
+///   an inline table (e.g. `features = { engine = [] }`), which are skipped;
+/// - multi-line strings (`"""..."""` or `'''...'''`) in the arrays, whose
+///   brackets and `#` throw off the tracking of multi-line arrays.
+///
+/// ```rust
+/// let manifest = "
+///     [features]
+///     default = [\"engine\"]
+///     ### Run a chess engine.
+///     ### Requires a lot of memory.
+///     engine = []
+/// ";
+/// let features = documented_build::extract_features(manifest);
+///
+/// assert_eq!(features[0].name, "default");
+/// assert_eq!(features[0].docs, None);
+/// assert_eq!(features[1].name, "engine");
+/// assert_eq!(
+///     features[1].docs.as_deref(),
+///     Some("Run a chess engine.\nRequires a lot of memory.")
+/// );
+/// ```
+pub fn extract_features(manifest: &str) -> Vec<FeatureDocs> {
+    let mut features = Vec::new();
+    let mut in_features = false;
+    let mut docs = Vec::<&str>::new();
+    // unclosed brackets of a multi-line array
+    let mut depth = 0;
+    for line in manifest.lines() {
+        let line = line.trim();
+        if depth > 0 {
+            depth += bracket_depth(line);
+            continue;
+        }
+        if let Some(doc) = line.strip_prefix("##") {
+            docs.push(doc.trim());
+        } else if line.starts_with('#') || line.is_empty() {
+            continue;
+        } else if line.starts_with('[') {
+            in_features = line.trim_end_matches(|c| c != ']') == "[features]";
+            docs.clear();
+        } else if let Some((name, value)) = line.split_once('=').filter(|_| in_features) {
+            features.push(FeatureDocs {
+                name: name.trim().trim_matches('"').to_string(),
+                docs: (!docs.is_empty()).then(|| docs.join("\n")),
+            });
+            docs.clear();
+            depth = bracket_depth(value);
+        } else {
+            docs.clear();
+        }
+    }
+    features
+}
+
+/// The net number of opened square brackets in a line of TOML, ignoring those
+/// in single-line strings and comments.
+fn bracket_depth(line: &str) -> i32 {
+    let mut depth = 0;
+    // the quote of the string we are in, if any
+    let mut quote = None;
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match (c, quote) {
+            // only basic strings have escapes
+            ('\\', Some('"')) => {
+                chars.next();
+            }
+            (_, Some(q)) if c == q => quote = None,
+            (_, Some(_)) => {}
+            ('"' | '\'', None) => quote = Some(c),
+            ('#', None) => break,
+            ('[', None) => depth += 1,
+            (']', None) => depth -= 1,
+            _ => {}
+        }
+    }
+    depth
+}
@@ -3,13 +3,13 @@
 use convert_case::{Case, Casing};
 use proc_macro2::{Span, TokenStream};
 use quote::quote;
-use syn::{Error, Ident, Item};
+use syn::{Error, Ident, Item, ItemMod};
 
 #[cfg(feature = "customise")]
 use crate::config::attr::AttrCustomisations;
 use crate::{
     config::attr::AttrConfig,
-    util::{get_docs, get_vis_name_attrs},
+    util::{crate_module_path, get_processed_docs, get_vis_name_attrs, track_doc_macros},
 };
 
 pub fn docs_const_impl(
@@ -23,7 +23,12 @@ pub fn docs_const_impl(
 
     let (item_vis, item_name, attrs) = get_vis_name_attrs(&item)?;
 
-    let docs = match (get_docs(attrs, config.trim)?, config.default_value) {
+    let mut docs_opt = get_processed_docs(attrs, &config.docs_options())?;
+    if config.merge_items {
+        docs_opt = merge_items(&item, docs_opt, &config)?;
+    }
+
+    let docs = match (docs_opt, config.default_value.clone()) {
         (Some(docs), _) => Ok(quote! { #docs }),
         (None, Some(default)) => Ok(quote! { #default }),
         (None, None) => Err(Error::new_spanned(&item, "Missing doc comments")),
@@ -35,8 +40,91 @@ pub fn docs_const_impl(
         .unwrap_or_else(|| format!("{}_DOCS", item_name.to_case(Case::ScreamingSnake)));
     let const_ident = Ident::new(&const_name, Span::call_site());
 
+    let const_item = match config.lazy_transform {
+        None => quote! {
+            #const_vis const #const_ident: &'static str = #docs;
+        },
+        Some(transform) => {
+            let documented_module_path = crate_module_path(config.crate_path.as_ref());
+            quote! {
+                #const_vis static #const_ident: #documented_module_path::LazyDocs =
+                    #documented_module_path::LazyDocs::new(#docs, #transform);
+            }
+        }
+    };
+
+    let mut doc_macros = track_doc_macros(attrs);
+    if let Item::Mod(ItemMod { content: Some((_, items)), .. }) = &item {
+        for item in items {
+            if let Ok((_, _, attrs)) = get_vis_name_attrs(item) {
+                doc_macros.extend(track_doc_macros(attrs));
+            }
+        }
+    }
+
+    // unlike doc comments, custom sources are unknown to the compiler
+    let mut item = item;
+    strip_sources(&mut item, &config.sources);
+    if let Item::Mod(ItemMod { content: Some((_, items)), .. }) = &mut item {
+        items
+            .iter_mut()
+            .for_each(|item| strip_sources(item, &config.sources));
+    }
+
     Ok(quote! {
         #item
-        #const_vis const #const_ident: &'static str = #docs;
+        #const_item
+        #doc_macros
     })
 }
+
+/// Remove the attributes named in `sources`, other than `doc`, from an item.
+fn strip_sources(item: &mut Item, sources: &[String]) {
+    let attrs = match item {
+        Item::Const(item) => &mut item.attrs,
+        Item::Enum(item) => &mut item.attrs,
+        Item::ExternCrate(item) => &mut item.attrs,
+        Item::Fn(item) => &mut item.attrs,
+        Item::Mod(item) => &mut item.attrs,
+        Item::Static(item) => &mut item.attrs,
+        Item::Struct(item) => &mut item.attrs,
+        Item::Trait(item) => &mut item.attrs,
+        Item::TraitAlias(item) => &mut item.attrs,
+        Item::Type(item) => &mut item.attrs,
+        Item::Union(item) => &mut item.attrs,
+        Item::Macro(item) => &mut item.attrs,
+        _ => return,
+    };
+    attrs.retain(|attr| {
+        attr.path().is_ident("doc") || !sources.iter().any(|source| attr.path().is_ident(source))
+    });
+}
+
+/// Append the docs of each documented item in a module to the module's own
+/// docs, with the item name as a heading.
+///
+/// Returns `None` if neither the module nor any of its items are documented.
+fn merge_items(
+    item: &Item,
+    module_docs: Option<String>,
+    config: &AttrConfig,
+) -> syn::Result<Option<String>> {
+    let Item::Mod(ItemMod { content: Some((_, items)), .. }) = item else {
+        Err(Error::new_spanned(
+            item,
+            "merge_items is only applicable to modules with inline contents",
+        ))?
+    };
+
+    let mut sections = module_docs.into_iter().collect::<Vec<_>>();
+    for item in items {
+        // items that cannot have docs are skipped
+        let Ok((_, name, attrs)) = get_vis_name_attrs(item) else {
+            continue;
+        };
+        if let Some(docs) = get_processed_docs(attrs, &config.docs_options())? {
+            sections.push(format!("## {name}\n\n{docs}"));
+        }
+    }
+    Ok((!sections.is_empty()).then(|| sections.join("\n\n")))
+}
@@ -2,4 +2,5 @@ pub mod attr;
 #[cfg(feature = "customise")]
 pub mod customise_core;
 pub mod derive;
+pub mod derive_documented;
 pub mod derive_fields;
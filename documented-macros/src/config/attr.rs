@@ -1,4 +1,6 @@
-use syn::{Expr, Visibility};
+use syn::{Expr, Path, Visibility};
+
+use crate::config::derive::{DocsOptions, Normalization};
 
 /// Configurable options for attribute macros via helper attributes.
 ///
@@ -19,6 +21,25 @@ pub struct AttrConfig {
     pub custom_name: Option<String>,
     pub default_value: Option<Expr>,
     pub trim: bool,
+    pub trim_start: Option<bool>,
+    pub trim_end: Option<bool>,
+    pub lazy_transform: Option<Expr>,
+    pub crate_path: Option<Path>,
+    pub normalize: Option<Normalization>,
+    pub merge_items: bool,
+    pub strip_markdown: bool,
+    pub dedent: bool,
+    pub unwrap_lines: bool,
+    pub wrap: Option<usize>,
+    pub collapse_blank_lines: bool,
+    pub keep_blank_lines: bool,
+    pub normalize_newlines: bool,
+    pub expand_tabs: Option<usize>,
+    pub prefix: Option<String>,
+    pub suffix: Option<String>,
+    pub interpolate: bool,
+    pub sources: Vec<String>,
+    pub separator: Option<String>,
 }
 impl Default for AttrConfig {
     fn default() -> Self {
@@ -27,6 +48,50 @@ impl Default for AttrConfig {
             custom_name: None,
             default_value: None,
             trim: true,
+            trim_start: None,
+            trim_end: None,
+            lazy_transform: None,
+            crate_path: None,
+            normalize: None,
+            merge_items: false,
+            strip_markdown: false,
+            dedent: false,
+            unwrap_lines: false,
+            wrap: None,
+            collapse_blank_lines: false,
+            keep_blank_lines: true,
+            normalize_newlines: false,
+            expand_tabs: None,
+            prefix: None,
+            suffix: None,
+            interpolate: false,
+            sources: Vec::new(),
+            separator: None,
+        }
+    }
+}
+
+impl AttrConfig {
+    /// The options for extracting and processing doc comments.
+    pub fn docs_options(&self) -> DocsOptions {
+        DocsOptions {
+            trim_start: self.trim_start.unwrap_or(self.trim),
+            trim_end: self.trim_end.unwrap_or(self.trim),
+            normalize: self.normalize,
+            strip_markdown: self.strip_markdown,
+            dedent: self.dedent,
+            unwrap_lines: self.unwrap_lines,
+            wrap: self.wrap,
+            collapse_blank_lines: self.collapse_blank_lines,
+            keep_blank_lines: self.keep_blank_lines,
+            normalize_newlines: self.normalize_newlines,
+            expand_tabs: self.expand_tabs,
+            prefix: self.prefix.clone(),
+            suffix: self.suffix.clone(),
+            interpolate: self.interpolate,
+            sources: self.sources.clone(),
+            doc: None,
+            separator: self.separator.clone(),
         }
     }
 }
@@ -35,6 +100,7 @@ impl Default for AttrConfig {
 mod customise {
     use syn::{
         parse::{Parse, ParseStream},
+        parse_quote,
         punctuated::Punctuated,
         Token,
     };
@@ -67,7 +133,38 @@ mod customise {
                 // I'd love to macro this if declarative macros can expand to a full match arm,
                 // but no: https://github.com/rust-lang/rfcs/issues/2654
                 match opt.data {
-                    Data::RenameAll(..) => Err(syn::Error::new(
+                    Data::RenameAll(..)
+                    | Data::AlsoRaw(..)
+                    | Data::ListVariants(..)
+                    | Data::FieldVis(..)
+                    | Data::Escape(..)
+                    | Data::Variants(..)
+                    | Data::Nested(..)
+                    | Data::SkipLines(..)
+                    | Data::StripLeadingHeading(..)
+                    | Data::ImplDisplay(..)
+                    | Data::WithTypes(..)
+                    | Data::Help(..)
+                    | Data::Alias(..)
+                    | Data::FieldEnum(..)
+                    | Data::Skip(..)
+                    | Data::Flatten(..)
+                    | Data::CaseInsensitive(..)
+                    | Data::Inherit(..)
+                    | Data::Remote(..)
+                    | Data::Summary(..)
+                    | Data::Lines(..)
+                    | Data::Paragraphs(..)
+                    | Data::Sections(..)
+                    | Data::Examples(..)
+                    | Data::Tags(..)
+                    | Data::Deprecation(..)
+                    | Data::Doc(..)
+                    | Data::Inherent(..)
+                    | Data::RequireAll(..)
+                    | Data::ConstLookup(..)
+                    | Data::Compact(..)
+                    | Data::MarkdownReference(..) => Err(syn::Error::new(
                         opt.span,
                         "This config option is not applicable here",
                     ))?,
@@ -80,9 +177,74 @@ mod customise {
                     Data::Default(expr) => {
                         config.default_value.replace(expr);
                     }
+                    // an explicit default takes precedence, regardless of order
+                    Data::AllowEmpty(allow) if allow.value() => {
+                        config.default_value.get_or_insert(parse_quote!(""));
+                    }
+                    Data::AllowEmpty(_) => {}
                     Data::Trim(trim) => {
                         config.trim.replace(trim.value());
                     }
+                    Data::Lazy(transform) => {
+                        config.lazy_transform.replace(transform);
+                    }
+                    Data::Crate(path) => {
+                        config.crate_path.replace(path.parse()?);
+                    }
+                    Data::Normalize(form) => {
+                        config.normalize.replace(form.value());
+                    }
+                    Data::MergeItems(merge_items) => {
+                        config.merge_items.replace(merge_items.value());
+                    }
+                    Data::StripMarkdown(strip) => {
+                        config.strip_markdown.replace(strip.value());
+                    }
+                    Data::Dedent(dedent) => {
+                        config.dedent.replace(dedent.value());
+                    }
+                    Data::TrimStart(trim) => {
+                        config.trim_start.replace(trim.value());
+                    }
+                    Data::TrimEnd(trim) => {
+                        config.trim_end.replace(trim.value());
+                    }
+                    Data::Separator(separator) => {
+                        config.separator.replace(separator.value());
+                    }
+                    Data::UnwrapLines(unwrap) => {
+                        config.unwrap_lines.replace(unwrap.value());
+                    }
+                    Data::Wrap(columns) => {
+                        config.wrap.replace(columns.base10_parse()?);
+                    }
+                    Data::CollapseBlankLines(collapse) => {
+                        config.collapse_blank_lines.replace(collapse.value());
+                    }
+                    Data::KeepBlankLines(keep) => {
+                        config.keep_blank_lines.replace(keep.value());
+                    }
+                    Data::NormalizeNewlines(normalize) => {
+                        config.normalize_newlines.replace(normalize.value());
+                    }
+                    Data::ExpandTabs(width) => {
+                        config.expand_tabs.replace(width.base10_parse()?);
+                    }
+                    Data::Prefix(prefix) => {
+                        config.prefix.replace(prefix.value());
+                    }
+                    Data::Suffix(suffix) => {
+                        config.suffix.replace(suffix.value());
+                    }
+                    Data::Interpolate(interpolate) => {
+                        config.interpolate.replace(interpolate.value());
+                    }
+                    Data::Source(source) => {
+                        config
+                            .sources
+                            .get_or_insert_with(Vec::new)
+                            .push(source.value());
+                    }
                 }
             }
             Ok(config)
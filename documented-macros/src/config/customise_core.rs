@@ -4,9 +4,11 @@ use syn::{
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
     spanned::Spanned,
-    Attribute, Error, Expr, LitBool, LitStr, Meta, Token, Visibility,
+    Attribute, Error, Expr, LitBool, LitInt, LitStr, Meta, Token, Visibility,
 };
 
+use crate::config::{derive::Normalization, derive_documented::Escape};
+
 mod kw {
     use syn::custom_keyword;
 
@@ -15,6 +17,56 @@ mod kw {
     custom_keyword!(rename);
     custom_keyword!(default);
     custom_keyword!(trim);
+    custom_keyword!(lazy);
+    custom_keyword!(also_raw);
+    custom_keyword!(list_variants);
+    custom_keyword!(field_vis);
+    custom_keyword!(wrap);
+    custom_keyword!(escape);
+    custom_keyword!(variants);
+    custom_keyword!(nested);
+    custom_keyword!(skip_lines);
+    custom_keyword!(strip_leading_heading);
+    custom_keyword!(impl_display);
+    custom_keyword!(with_types);
+    custom_keyword!(help);
+    custom_keyword!(normalize);
+    custom_keyword!(alias);
+    custom_keyword!(merge_items);
+    custom_keyword!(field_enum);
+    custom_keyword!(skip);
+    custom_keyword!(flatten);
+    custom_keyword!(case_insensitive);
+    custom_keyword!(inherit);
+    custom_keyword!(remote);
+    custom_keyword!(summary);
+    custom_keyword!(lines);
+    custom_keyword!(paragraphs);
+    custom_keyword!(sections);
+    custom_keyword!(examples);
+    custom_keyword!(tags);
+    custom_keyword!(strip_markdown);
+    custom_keyword!(dedent);
+    custom_keyword!(trim_start);
+    custom_keyword!(trim_end);
+    custom_keyword!(separator);
+    custom_keyword!(unwrap_lines);
+    custom_keyword!(collapse_blank_lines);
+    custom_keyword!(keep_blank_lines);
+    custom_keyword!(normalize_newlines);
+    custom_keyword!(expand_tabs);
+    custom_keyword!(prefix);
+    custom_keyword!(suffix);
+    custom_keyword!(interpolate);
+    custom_keyword!(deprecation);
+    custom_keyword!(source);
+    custom_keyword!(doc);
+    custom_keyword!(allow_empty);
+    custom_keyword!(inherent);
+    custom_keyword!(require_all);
+    custom_keyword!(const_lookup);
+    custom_keyword!(compact);
+    custom_keyword!(markdown_reference);
 
     // recognised old keywords
     // error when used
@@ -24,7 +76,8 @@ mod kw {
 /// A configuration option that includes the span info. Each kind of
 /// customisation struct may choose to accept or reject any of them.
 ///
-/// Expected parse stream format: `<KW> = <VAL>`.
+/// Expected parse stream format: `<KW> = <VAL>`, or just `<KW>` for boolean
+/// options, which is equivalent to `<KW> = true`.
 #[derive(Clone, Debug)]
 pub struct ConfigOption {
     /// The span over the keyword of the config option.
@@ -41,19 +94,88 @@ impl Parse for ConfigOption {
         let span = input.span();
 
         let kind = input.parse::<ConfigOptionKind>()?;
-        input.parse::<Token![=]>()?;
         let data = match kind {
-            Kind::Vis => Data::Vis(input.parse()?),
-            Kind::RenameAll => Data::RenameAll(input.parse()?),
-            Kind::Rename => Data::Rename(input.parse()?),
-            Kind::Default => Data::Default(input.parse()?),
-            Kind::Trim => Data::Trim(input.parse()?),
+            Kind::Vis => Data::Vis(parse_value(input)?),
+            Kind::RenameAll => Data::RenameAll(parse_value(input)?),
+            Kind::Rename => Data::Rename(parse_value(input)?),
+            Kind::Default => Data::Default(parse_value(input)?),
+            Kind::Trim => Data::Trim(parse_flag(input, span)?),
+            Kind::Lazy => Data::Lazy(parse_value(input)?),
+            Kind::AlsoRaw => Data::AlsoRaw(parse_flag(input, span)?),
+            Kind::ListVariants => Data::ListVariants(parse_flag(input, span)?),
+            Kind::FieldVis => Data::FieldVis(parse_flag(input, span)?),
+            Kind::Wrap => Data::Wrap(parse_value(input)?),
+            Kind::Escape => Data::Escape(parse_value(input)?),
+            Kind::Variants => Data::Variants(parse_flag(input, span)?),
+            Kind::Nested => Data::Nested(parse_flag(input, span)?),
+            Kind::SkipLines => Data::SkipLines(parse_value(input)?),
+            Kind::StripLeadingHeading => Data::StripLeadingHeading(parse_flag(input, span)?),
+            Kind::ImplDisplay => Data::ImplDisplay(parse_flag(input, span)?),
+            Kind::WithTypes => Data::WithTypes(parse_flag(input, span)?),
+            Kind::Help => Data::Help(parse_flag(input, span)?),
+            Kind::Normalize => Data::Normalize(parse_value(input)?),
+            Kind::Alias => Data::Alias(parse_value(input)?),
+            Kind::MergeItems => Data::MergeItems(parse_flag(input, span)?),
+            Kind::FieldEnum => Data::FieldEnum(parse_flag(input, span)?),
+            Kind::Skip => Data::Skip(parse_flag(input, span)?),
+            Kind::Flatten => Data::Flatten(parse_flag(input, span)?),
+            Kind::CaseInsensitive => Data::CaseInsensitive(parse_flag(input, span)?),
+            Kind::Inherit => Data::Inherit(parse_flag(input, span)?),
+            Kind::Remote => Data::Remote(parse_value(input)?),
+            Kind::Summary => Data::Summary(parse_flag(input, span)?),
+            Kind::Lines => Data::Lines(parse_flag(input, span)?),
+            Kind::Paragraphs => Data::Paragraphs(parse_flag(input, span)?),
+            Kind::Sections => Data::Sections(parse_flag(input, span)?),
+            Kind::Examples => Data::Examples(parse_flag(input, span)?),
+            Kind::Tags => Data::Tags(parse_flag(input, span)?),
+            Kind::StripMarkdown => Data::StripMarkdown(parse_flag(input, span)?),
+            Kind::Dedent => Data::Dedent(parse_flag(input, span)?),
+            Kind::TrimStart => Data::TrimStart(parse_flag(input, span)?),
+            Kind::TrimEnd => Data::TrimEnd(parse_flag(input, span)?),
+            Kind::Separator => Data::Separator(parse_value(input)?),
+            Kind::UnwrapLines => Data::UnwrapLines(parse_flag(input, span)?),
+            Kind::CollapseBlankLines => Data::CollapseBlankLines(parse_flag(input, span)?),
+            Kind::KeepBlankLines => Data::KeepBlankLines(parse_flag(input, span)?),
+            Kind::NormalizeNewlines => Data::NormalizeNewlines(parse_flag(input, span)?),
+            Kind::ExpandTabs => Data::ExpandTabs(parse_value(input)?),
+            Kind::Prefix => Data::Prefix(parse_value(input)?),
+            Kind::Suffix => Data::Suffix(parse_value(input)?),
+            Kind::Interpolate => Data::Interpolate(parse_flag(input, span)?),
+            Kind::Deprecation => Data::Deprecation(parse_flag(input, span)?),
+            Kind::Source => Data::Source(parse_value(input)?),
+            Kind::Doc => Data::Doc(parse_value(input)?),
+            Kind::AllowEmpty => Data::AllowEmpty(parse_flag(input, span)?),
+            Kind::Crate => Data::Crate(parse_value(input)?),
+            Kind::Inherent => Data::Inherent(parse_flag(input, span)?),
+            Kind::RequireAll => Data::RequireAll(parse_flag(input, span)?),
+            Kind::ConstLookup => Data::ConstLookup(parse_flag(input, span)?),
+            Kind::Compact => Data::Compact(parse_flag(input, span)?),
+            Kind::MarkdownReference => Data::MarkdownReference(parse_flag(input, span)?),
         };
 
         Ok(Self { span, data })
     }
 }
 
+/// Parse the value of an option.
+///
+/// Expected parse stream format: `= <VAL>`.
+fn parse_value<T: Parse>(input: ParseStream) -> syn::Result<T> {
+    input.parse::<Token![=]>()?;
+    input.parse()
+}
+
+/// Parse the value of a boolean option, which defaults to `true` when omitted.
+///
+/// Expected parse stream format: `= <BOOL>` or nothing.
+fn parse_flag(input: ParseStream, span: Span) -> syn::Result<LitBool> {
+    if input.peek(Token![=]) {
+        parse_value(input)
+    } else {
+        Ok(LitBool::new(true, span))
+    }
+}
+
 /// All supported cases of `rename_all`.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct LitCase(convert_case::Case);
@@ -93,6 +215,71 @@ impl LitCase {
     }
 }
 
+/// All supported formats of `escape`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LitEscape(Escape);
+impl Parse for LitEscape {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        const SUPPORTED_FORMATS: [(&str, Escape); 3] = [
+            ("json", Escape::Json),
+            ("html", Escape::Html),
+            ("c_string", Escape::CString),
+        ];
+
+        let arg = input.parse::<LitStr>()?;
+        let Some(escape) = SUPPORTED_FORMATS
+            .into_iter()
+            .find_map(|(name, escape)| (name == arg.value()).then_some(escape))
+        else {
+            let options = SUPPORTED_FORMATS.map(|(name, _)| name).join(", ");
+            Err(Error::new(
+                arg.span(),
+                format!("Escape format must be one of {options}."),
+            ))?
+        };
+
+        Ok(Self(escape))
+    }
+}
+impl LitEscape {
+    pub fn value(&self) -> Escape {
+        self.0
+    }
+}
+
+/// All supported forms of `normalize`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LitNormalization(Normalization);
+impl Parse for LitNormalization {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        const SUPPORTED_FORMS: [(&str, Normalization); 4] = [
+            ("nfc", Normalization::Nfc),
+            ("nfd", Normalization::Nfd),
+            ("nfkc", Normalization::Nfkc),
+            ("nfkd", Normalization::Nfkd),
+        ];
+
+        let arg = input.parse::<LitStr>()?;
+        let Some(form) = SUPPORTED_FORMS
+            .into_iter()
+            .find_map(|(name, form)| (name == arg.value()).then_some(form))
+        else {
+            let options = SUPPORTED_FORMS.map(|(name, _)| name).join(", ");
+            Err(Error::new(
+                arg.span(),
+                format!("Normalization form must be one of {options}."),
+            ))?
+        };
+
+        Ok(Self(form))
+    }
+}
+impl LitNormalization {
+    pub fn value(&self) -> Normalization {
+        self.0
+    }
+}
+
 /// The data of all known configuration options.
 #[derive(Clone, Debug, PartialEq, Eq, strum::EnumDiscriminants)]
 #[strum_discriminants(
@@ -126,6 +313,270 @@ pub enum ConfigOptionData {
     ///
     /// E.g. `trim = false`.
     Trim(LitBool),
+
+    /// Post-process the docs at runtime, lazily and only once.
+    ///
+    /// E.g. `lazy = render_markdown`, `lazy = |docs| docs.to_uppercase()`.
+    Lazy(Expr),
+
+    /// Also generate a constant with the unprocessed docs.
+    ///
+    /// E.g. `also_raw`, `also_raw = true`.
+    AlsoRaw(LitBool),
+
+    /// Append a list of variants and their summaries to the docs of an enum.
+    ///
+    /// E.g. `list_variants`, `list_variants = true`.
+    ListVariants(LitBool),
+
+    /// Also generate a constant with the declared visibility of each field.
+    ///
+    /// E.g. `field_vis`, `field_vis = true`.
+    FieldVis(LitBool),
+
+    /// Re-wrap the docs to a maximum line width.
+    ///
+    /// E.g. `wrap = 80`.
+    Wrap(LitInt),
+
+    /// Escape the docs for embedding in another format.
+    ///
+    /// E.g. `escape = "json"`, `escape = "html"`, `escape = "c_string"`.
+    Escape(LitEscape),
+
+    /// Allow deriving on enums, documenting their variants.
+    ///
+    /// E.g. `variants`, `variants = false`.
+    Variants(LitBool),
+
+    /// Allow looking up the fields of a field by path.
+    ///
+    /// E.g. `nested`, `nested = true`.
+    Nested(LitBool),
+
+    /// Skip a number of leading lines of the docs.
+    ///
+    /// E.g. `skip_lines = 1`.
+    SkipLines(LitInt),
+
+    /// Remove a leading markdown heading from the docs.
+    ///
+    /// E.g. `strip_leading_heading`, `strip_leading_heading = true`.
+    StripLeadingHeading(LitBool),
+
+    /// Also implement `Display` by writing the docs.
+    ///
+    /// E.g. `impl_display`, `impl_display = true`.
+    ImplDisplay(LitBool),
+
+    /// Append the type of each field to its docs.
+    ///
+    /// E.g. `with_types`, `with_types = true`.
+    WithTypes(LitBool),
+
+    /// Also generate a constant with the docs followed by an aligned list of fields or variants.
+    ///
+    /// E.g. `help`, `help = true`.
+    Help(LitBool),
+
+    /// Apply Unicode normalization to the docs.
+    ///
+    /// E.g. `normalize = "nfc"`, `normalize = "nfkd"`.
+    Normalize(LitNormalization),
+
+    /// Additional key for looking up a field. Can be used multiple times.
+    ///
+    /// E.g. `alias = "max_conns"`.
+    Alias(LitStr),
+
+    /// Append the docs of all documented items of a module to its docs.
+    ///
+    /// E.g. `merge_items`, `merge_items = true`.
+    MergeItems(LitBool),
+
+    /// Also generate an enum of the fields, for typed lookups.
+    ///
+    /// E.g. `field_enum`, `field_enum = true`.
+    FieldEnum(LitBool),
+
+    /// Leave a field out of the generated docs entirely.
+    ///
+    /// E.g. `skip`, `skip = true`.
+    Skip(LitBool),
+
+    /// Also look up unknown names in the docs of a field's type.
+    ///
+    /// E.g. `flatten`, `flatten = true`.
+    Flatten(LitBool),
+
+    /// Look up field docs regardless of case.
+    ///
+    /// E.g. `case_insensitive`, `case_insensitive = true`.
+    CaseInsensitive(LitBool),
+
+    /// Use the docs of the only field's type when doc comments are absent.
+    ///
+    /// E.g. `inherit`, `inherit = true`.
+    Inherit(LitBool),
+
+    /// Check that the fields mirror those of a type from another crate.
+    ///
+    /// E.g. `remote = "other_crate::Config"`.
+    Remote(LitStr),
+
+    /// Also generate the first paragraph of the docs.
+    ///
+    /// E.g. `summary`, `summary = true`.
+    Summary(LitBool),
+
+    /// Also generate the docs split into lines.
+    ///
+    /// E.g. `lines`, `lines = true`.
+    Lines(LitBool),
+
+    /// Also generate the docs split into paragraphs.
+    ///
+    /// E.g. `paragraphs`, `paragraphs = true`.
+    Paragraphs(LitBool),
+
+    /// Also generate the docs split into sections by their markdown headings.
+    ///
+    /// E.g. `sections`, `sections = true`.
+    Sections(LitBool),
+
+    /// Also generate the contents of the fenced code blocks in the docs.
+    ///
+    /// E.g. `examples`, `examples = true`.
+    Examples(LitBool),
+
+    /// Parse `@key: value` lines in the docs into tags.
+    ///
+    /// E.g. `tags`, `tags = true`.
+    Tags(LitBool),
+
+    /// Remove markdown syntax from the docs, keeping only the text.
+    ///
+    /// E.g. `strip_markdown`, `strip_markdown = true`.
+    StripMarkdown(LitBool),
+
+    /// Remove the common indentation of the docs instead of trimming each line.
+    ///
+    /// E.g. `dedent`, `dedent = true`.
+    Dedent(LitBool),
+
+    /// Trim leading whitespace from each line of the docs, overriding `trim`.
+    ///
+    /// E.g. `trim_start`, `trim_start = false`.
+    TrimStart(LitBool),
+
+    /// Trim trailing whitespace from each line of the docs, overriding `trim`.
+    ///
+    /// E.g. `trim_end`, `trim_end = false`.
+    TrimEnd(LitBool),
+
+    /// Join the lines of the docs with a custom separator instead of `\n`.
+    ///
+    /// E.g. `separator = " "`, `separator = "\r\n"`.
+    Separator(LitStr),
+
+    /// Join consecutive non-blank lines of the docs with a space.
+    ///
+    /// E.g. `unwrap_lines`, `unwrap_lines = true`.
+    UnwrapLines(LitBool),
+
+    /// Squash runs of blank lines in the docs into one.
+    ///
+    /// E.g. `collapse_blank_lines`, `collapse_blank_lines = true`.
+    CollapseBlankLines(LitBool),
+
+    /// Keep blank lines in the docs.
+    ///
+    /// E.g. `keep_blank_lines = false`.
+    KeepBlankLines(LitBool),
+
+    /// Convert `\r\n` and lone `\r` line endings in the docs to `\n`.
+    ///
+    /// E.g. `normalize_newlines`, `normalize_newlines = true`.
+    NormalizeNewlines(LitBool),
+
+    /// Replace each tab in the docs with a number of spaces.
+    ///
+    /// E.g. `expand_tabs = 4`.
+    ExpandTabs(LitInt),
+
+    /// Text to prepend to the docs.
+    ///
+    /// E.g. `prefix = "Note: "`.
+    Prefix(LitStr),
+
+    /// Text to append to the docs.
+    ///
+    /// E.g. `suffix = "\n\nSee the manual for details."`.
+    Suffix(LitStr),
+
+    /// Substitute `{NAME}` placeholders in the docs with compile-time
+    /// environment variables.
+    ///
+    /// E.g. `interpolate`, `interpolate = true`.
+    Interpolate(LitBool),
+
+    /// Generate a constant with the note of the `#[deprecated]` attribute.
+    ///
+    /// E.g. `deprecation`, `deprecation = true`.
+    Deprecation(LitBool),
+
+    /// Name of an attribute to take the docs from instead of `doc`. Can be used
+    /// multiple times, including `source = "doc"` to keep the doc comments.
+    ///
+    /// E.g. `source = "describe"`.
+    Source(LitStr),
+
+    /// Docs to use instead of the doc comments of a field.
+    ///
+    /// E.g. `doc = "Maximum number of connections."`.
+    Doc(LitStr),
+
+    /// Use an empty string if there are no doc comments, instead of producing an
+    /// error. Shorthand for `default = ""`.
+    ///
+    /// E.g. `allow_empty`, `allow_empty = true`.
+    AllowEmpty(LitBool),
+
+    /// Path to this crate in the generated code, for when it is re-exported or
+    /// renamed.
+    ///
+    /// E.g. `crate = "my_facade::documented"`.
+    Crate(LitStr),
+
+    /// Also generate the docs as an inherent associated constant, so that they
+    /// are accessible without importing the trait.
+    ///
+    /// E.g. `inherent`, `inherent = true`.
+    Inherent(LitBool),
+
+    /// Reject fields (or variants) without doc comments, even if a default value
+    /// is set.
+    ///
+    /// E.g. `require_all`, `require_all = true`.
+    RequireAll(LitBool),
+
+    /// Look up fields by name with a generated match instead of a perfect hash
+    /// map, and also generate a `const fn` for looking up their docs.
+    ///
+    /// E.g. `const_lookup`, `const_lookup = true`.
+    ConstLookup(LitBool),
+
+    /// Look up the docs and shape of a variant by its index in generated slices,
+    /// instead of generating a match arm per variant for each.
+    ///
+    /// E.g. `compact`, `compact = true`.
+    Compact(LitBool),
+
+    /// Generate a markdown reference of all variants, with their discriminants and
+    /// docs.
+    ///
+    /// E.g. `markdown_reference`, `markdown_reference = true`.
+    MarkdownReference(LitBool),
 }
 
 impl Parse for ConfigOptionKind {
@@ -146,6 +597,159 @@ impl Parse for ConfigOptionKind {
         } else if lookahead.peek(kw::trim) {
             input.parse::<kw::trim>()?;
             Self::Trim
+        } else if lookahead.peek(kw::lazy) {
+            input.parse::<kw::lazy>()?;
+            Self::Lazy
+        } else if lookahead.peek(kw::also_raw) {
+            input.parse::<kw::also_raw>()?;
+            Self::AlsoRaw
+        } else if lookahead.peek(kw::list_variants) {
+            input.parse::<kw::list_variants>()?;
+            Self::ListVariants
+        } else if lookahead.peek(kw::field_vis) {
+            input.parse::<kw::field_vis>()?;
+            Self::FieldVis
+        } else if lookahead.peek(kw::wrap) {
+            input.parse::<kw::wrap>()?;
+            Self::Wrap
+        } else if lookahead.peek(kw::escape) {
+            input.parse::<kw::escape>()?;
+            Self::Escape
+        } else if lookahead.peek(kw::variants) {
+            input.parse::<kw::variants>()?;
+            Self::Variants
+        } else if lookahead.peek(kw::nested) {
+            input.parse::<kw::nested>()?;
+            Self::Nested
+        } else if lookahead.peek(kw::skip_lines) {
+            input.parse::<kw::skip_lines>()?;
+            Self::SkipLines
+        } else if lookahead.peek(kw::strip_leading_heading) {
+            input.parse::<kw::strip_leading_heading>()?;
+            Self::StripLeadingHeading
+        } else if lookahead.peek(kw::impl_display) {
+            input.parse::<kw::impl_display>()?;
+            Self::ImplDisplay
+        } else if lookahead.peek(kw::with_types) {
+            input.parse::<kw::with_types>()?;
+            Self::WithTypes
+        } else if lookahead.peek(kw::help) {
+            input.parse::<kw::help>()?;
+            Self::Help
+        } else if lookahead.peek(kw::normalize) {
+            input.parse::<kw::normalize>()?;
+            Self::Normalize
+        } else if lookahead.peek(kw::alias) {
+            input.parse::<kw::alias>()?;
+            Self::Alias
+        } else if lookahead.peek(kw::merge_items) {
+            input.parse::<kw::merge_items>()?;
+            Self::MergeItems
+        } else if lookahead.peek(kw::field_enum) {
+            input.parse::<kw::field_enum>()?;
+            Self::FieldEnum
+        } else if lookahead.peek(kw::skip) {
+            input.parse::<kw::skip>()?;
+            Self::Skip
+        } else if lookahead.peek(kw::flatten) {
+            input.parse::<kw::flatten>()?;
+            Self::Flatten
+        } else if lookahead.peek(kw::case_insensitive) {
+            input.parse::<kw::case_insensitive>()?;
+            Self::CaseInsensitive
+        } else if lookahead.peek(kw::inherit) {
+            input.parse::<kw::inherit>()?;
+            Self::Inherit
+        } else if lookahead.peek(kw::remote) {
+            input.parse::<kw::remote>()?;
+            Self::Remote
+        } else if lookahead.peek(kw::summary) {
+            input.parse::<kw::summary>()?;
+            Self::Summary
+        } else if lookahead.peek(kw::lines) {
+            input.parse::<kw::lines>()?;
+            Self::Lines
+        } else if lookahead.peek(kw::paragraphs) {
+            input.parse::<kw::paragraphs>()?;
+            Self::Paragraphs
+        } else if lookahead.peek(kw::sections) {
+            input.parse::<kw::sections>()?;
+            Self::Sections
+        } else if lookahead.peek(kw::examples) {
+            input.parse::<kw::examples>()?;
+            Self::Examples
+        } else if lookahead.peek(kw::tags) {
+            input.parse::<kw::tags>()?;
+            Self::Tags
+        } else if lookahead.peek(kw::strip_markdown) {
+            input.parse::<kw::strip_markdown>()?;
+            Self::StripMarkdown
+        } else if lookahead.peek(kw::dedent) {
+            input.parse::<kw::dedent>()?;
+            Self::Dedent
+        } else if lookahead.peek(kw::trim_start) {
+            input.parse::<kw::trim_start>()?;
+            Self::TrimStart
+        } else if lookahead.peek(kw::trim_end) {
+            input.parse::<kw::trim_end>()?;
+            Self::TrimEnd
+        } else if lookahead.peek(kw::separator) {
+            input.parse::<kw::separator>()?;
+            Self::Separator
+        } else if lookahead.peek(kw::unwrap_lines) {
+            input.parse::<kw::unwrap_lines>()?;
+            Self::UnwrapLines
+        } else if lookahead.peek(kw::collapse_blank_lines) {
+            input.parse::<kw::collapse_blank_lines>()?;
+            Self::CollapseBlankLines
+        } else if lookahead.peek(kw::keep_blank_lines) {
+            input.parse::<kw::keep_blank_lines>()?;
+            Self::KeepBlankLines
+        } else if lookahead.peek(kw::normalize_newlines) {
+            input.parse::<kw::normalize_newlines>()?;
+            Self::NormalizeNewlines
+        } else if lookahead.peek(kw::expand_tabs) {
+            input.parse::<kw::expand_tabs>()?;
+            Self::ExpandTabs
+        } else if lookahead.peek(kw::prefix) {
+            input.parse::<kw::prefix>()?;
+            Self::Prefix
+        } else if lookahead.peek(kw::suffix) {
+            input.parse::<kw::suffix>()?;
+            Self::Suffix
+        } else if lookahead.peek(kw::interpolate) {
+            input.parse::<kw::interpolate>()?;
+            Self::Interpolate
+        } else if lookahead.peek(kw::deprecation) {
+            input.parse::<kw::deprecation>()?;
+            Self::Deprecation
+        } else if lookahead.peek(kw::source) {
+            input.parse::<kw::source>()?;
+            Self::Source
+        } else if lookahead.peek(kw::doc) {
+            input.parse::<kw::doc>()?;
+            Self::Doc
+        } else if lookahead.peek(kw::allow_empty) {
+            input.parse::<kw::allow_empty>()?;
+            Self::AllowEmpty
+        } else if lookahead.peek(Token![crate]) {
+            input.parse::<Token![crate]>()?;
+            Self::Crate
+        } else if lookahead.peek(kw::inherent) {
+            input.parse::<kw::inherent>()?;
+            Self::Inherent
+        } else if lookahead.peek(kw::require_all) {
+            input.parse::<kw::require_all>()?;
+            Self::RequireAll
+        } else if lookahead.peek(kw::const_lookup) {
+            input.parse::<kw::const_lookup>()?;
+            Self::ConstLookup
+        } else if lookahead.peek(kw::compact) {
+            input.parse::<kw::compact>()?;
+            Self::Compact
+        } else if lookahead.peek(kw::markdown_reference) {
+            input.parse::<kw::markdown_reference>()?;
+            Self::MarkdownReference
         } else if lookahead.peek(kw::name) {
             Err(Error::new(
                 input.span(),
@@ -158,13 +762,15 @@ impl Parse for ConfigOptionKind {
     }
 }
 
-/// Make sure there are no duplicate options.
+/// Make sure there are no duplicate options, except for those that can be
+/// declared multiple times (i.e. `alias`).
 /// Otherwise produces an error with detailed span info.
 pub fn ensure_unique_options(opts: &[ConfigOption]) -> syn::Result<()> {
     for (kind, opts) in opts
         .iter()
         .into_group_map_by(|opt| ConfigOptionKind::from(&opt.data))
         .into_iter()
+        .filter(|(kind, _)| !matches!(kind, ConfigOptionKind::Alias | ConfigOptionKind::Source))
     {
         match &opts[..] {
             [] => unreachable!(), // guaranteed by `into_group_map_by`
@@ -172,7 +778,7 @@ pub fn ensure_unique_options(opts: &[ConfigOption]) -> syn::Result<()> {
             [first, rest @ ..] => {
                 let initial_error = Error::new(
                     first.span,
-                    format!("Option {kind} can only be declaration once"),
+                    format!("Option {kind} can only be declared once"),
                 );
                 let final_error = rest.iter().fold(initial_error, |mut err, opt| {
                     err.combine(Error::new(opt.span, "Duplicate declaration here"));
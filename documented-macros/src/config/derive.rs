@@ -3,7 +3,44 @@
 //! If a macro needs specialised configuration, this file can be used as a
 //! starting template.
 
-use syn::Expr;
+use syn::{Expr, Path};
+
+/// Unicode normalization forms the docs can be normalized to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum Normalization {
+    /// Canonical composition.
+    Nfc,
+    /// Canonical decomposition.
+    Nfd,
+    /// Compatibility composition.
+    Nfkc,
+    /// Compatibility decomposition.
+    Nfkd,
+}
+
+/// Options for extracting and processing doc comments, shared by all macros.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DocsOptions {
+    pub trim_start: bool,
+    pub trim_end: bool,
+    pub normalize: Option<Normalization>,
+    pub strip_markdown: bool,
+    pub dedent: bool,
+    pub unwrap_lines: bool,
+    pub wrap: Option<usize>,
+    pub collapse_blank_lines: bool,
+    pub keep_blank_lines: bool,
+    pub normalize_newlines: bool,
+    pub expand_tabs: Option<usize>,
+    pub prefix: Option<String>,
+    pub suffix: Option<String>,
+    pub interpolate: bool,
+    pub sources: Vec<String>,
+    /// Used instead of the doc comments, if set.
+    pub doc: Option<String>,
+    pub separator: Option<String>,
+}
 
 /// Configurable options for derive macros via helper attributes.
 ///
@@ -21,16 +58,86 @@ pub struct DeriveConfig {
     // optfield does not rewrap `Option` by default, which is the desired behavior
     // see https://docs.rs/optfield/latest/optfield/#rewrapping-option-fields
     pub default_value: Option<Expr>,
+    pub markdown_reference: bool,
+    pub crate_path: Option<Path>,
+    pub require_all: bool,
+    pub compact: bool,
     pub trim: bool,
+    pub trim_start: Option<bool>,
+    pub trim_end: Option<bool>,
+    pub normalize: Option<Normalization>,
+    pub strip_markdown: bool,
+    pub dedent: bool,
+    pub unwrap_lines: bool,
+    pub wrap: Option<usize>,
+    pub collapse_blank_lines: bool,
+    pub keep_blank_lines: bool,
+    pub normalize_newlines: bool,
+    pub expand_tabs: Option<usize>,
+    pub prefix: Option<String>,
+    pub suffix: Option<String>,
+    pub interpolate: bool,
+    pub sources: Vec<String>,
+    pub separator: Option<String>,
 }
 impl Default for DeriveConfig {
     fn default() -> Self {
-        Self { default_value: None, trim: true }
+        Self {
+            default_value: None,
+            markdown_reference: false,
+            crate_path: None,
+            require_all: false,
+            compact: false,
+            trim: true,
+            trim_start: None,
+            trim_end: None,
+            normalize: None,
+            strip_markdown: false,
+            dedent: false,
+            unwrap_lines: false,
+            wrap: None,
+            collapse_blank_lines: false,
+            keep_blank_lines: true,
+            normalize_newlines: false,
+            expand_tabs: None,
+            prefix: None,
+            suffix: None,
+            interpolate: false,
+            sources: Vec::new(),
+            separator: None,
+        }
+    }
+}
+
+impl DeriveConfig {
+    /// The options for extracting and processing doc comments.
+    pub fn docs_options(&self) -> DocsOptions {
+        DocsOptions {
+            trim_start: self.trim_start.unwrap_or(self.trim),
+            trim_end: self.trim_end.unwrap_or(self.trim),
+            normalize: self.normalize,
+            strip_markdown: self.strip_markdown,
+            dedent: self.dedent,
+            unwrap_lines: self.unwrap_lines,
+            wrap: self.wrap,
+            collapse_blank_lines: self.collapse_blank_lines,
+            keep_blank_lines: self.keep_blank_lines,
+            normalize_newlines: self.normalize_newlines,
+            expand_tabs: self.expand_tabs,
+            prefix: self.prefix.clone(),
+            suffix: self.suffix.clone(),
+            interpolate: self.interpolate,
+            sources: self.sources.clone(),
+            doc: None,
+            separator: self.separator.clone(),
+        }
     }
 }
 
 #[cfg(feature = "customise")]
 mod customise {
+    use syn::parse_quote;
+
     use crate::config::{
         customise_core::{ConfigOption, ConfigOptionData},
         derive::{DeriveConfig, DeriveCustomisations},
@@ -55,15 +162,116 @@ mod customise {
             let mut config = Self::default();
             for opt in opts {
                 match opt.data {
-                    Data::Vis(..) | Data::RenameAll(..) | Data::Rename(..) => Err(
-                        syn::Error::new(opt.span, "This config option is not applicable here"),
-                    )?,
+                    Data::Vis(..)
+                    | Data::RenameAll(..)
+                    | Data::Rename(..)
+                    | Data::Lazy(..)
+                    | Data::AlsoRaw(..)
+                    | Data::ListVariants(..)
+                    | Data::FieldVis(..)
+                    | Data::Escape(..)
+                    | Data::Variants(..)
+                    | Data::Nested(..)
+                    | Data::SkipLines(..)
+                    | Data::StripLeadingHeading(..)
+                    | Data::ImplDisplay(..)
+                    | Data::WithTypes(..)
+                    | Data::Help(..)
+                    | Data::Alias(..)
+                    | Data::MergeItems(..)
+                    | Data::FieldEnum(..)
+                    | Data::Skip(..)
+                    | Data::Flatten(..)
+                    | Data::CaseInsensitive(..)
+                    | Data::Inherit(..)
+                    | Data::Remote(..)
+                    | Data::Summary(..)
+                    | Data::Lines(..)
+                    | Data::Paragraphs(..)
+                    | Data::Sections(..)
+                    | Data::Examples(..)
+                    | Data::Tags(..)
+                    | Data::Deprecation(..)
+                    | Data::Doc(..)
+                    | Data::Inherent(..)
+                    | Data::ConstLookup(..) => Err(syn::Error::new(
+                        opt.span,
+                        "This config option is not applicable here",
+                    ))?,
                     Data::Default(expr) => {
                         config.default_value.replace(expr);
                     }
+                    Data::MarkdownReference(reference) => {
+                        config.markdown_reference.replace(reference.value());
+                    }
+                    Data::Crate(path) => {
+                        config.crate_path.replace(path.parse()?);
+                    }
+                    Data::RequireAll(require) => {
+                        config.require_all.replace(require.value());
+                    }
+                    Data::Compact(compact) => {
+                        config.compact.replace(compact.value());
+                    }
+                    // an explicit default takes precedence, regardless of order
+                    Data::AllowEmpty(allow) if allow.value() => {
+                        config.default_value.get_or_insert(parse_quote!(""));
+                    }
+                    Data::AllowEmpty(_) => {}
                     Data::Trim(trim) => {
                         config.trim.replace(trim.value());
                     }
+                    Data::Normalize(form) => {
+                        config.normalize.replace(form.value());
+                    }
+                    Data::StripMarkdown(strip) => {
+                        config.strip_markdown.replace(strip.value());
+                    }
+                    Data::Dedent(dedent) => {
+                        config.dedent.replace(dedent.value());
+                    }
+                    Data::TrimStart(trim) => {
+                        config.trim_start.replace(trim.value());
+                    }
+                    Data::TrimEnd(trim) => {
+                        config.trim_end.replace(trim.value());
+                    }
+                    Data::Separator(separator) => {
+                        config.separator.replace(separator.value());
+                    }
+                    Data::UnwrapLines(unwrap) => {
+                        config.unwrap_lines.replace(unwrap.value());
+                    }
+                    Data::Wrap(columns) => {
+                        config.wrap.replace(columns.base10_parse()?);
+                    }
+                    Data::CollapseBlankLines(collapse) => {
+                        config.collapse_blank_lines.replace(collapse.value());
+                    }
+                    Data::KeepBlankLines(keep) => {
+                        config.keep_blank_lines.replace(keep.value());
+                    }
+                    Data::NormalizeNewlines(normalize) => {
+                        config.normalize_newlines.replace(normalize.value());
+                    }
+                    Data::ExpandTabs(width) => {
+                        config.expand_tabs.replace(width.base10_parse()?);
+                    }
+                    Data::Prefix(prefix) => {
+                        config.prefix.replace(prefix.value());
+                    }
+                    Data::Suffix(suffix) => {
+                        config.suffix.replace(suffix.value());
+                    }
+                    Data::Interpolate(interpolate) => {
+                        config.interpolate.replace(interpolate.value());
+                    }
+                    Data::Source(source) => {
+                        config
+                            .sources
+                            .get_or_insert_with(Vec::new)
+                            .push(source.value());
+                    }
                 }
             }
             Ok(config)
@@ -0,0 +1,312 @@
+//! Specialised configuration for `Documented` and `DocumentedOpt`.
+
+use syn::{Expr, Path};
+
+use crate::config::derive::{DocsOptions, Normalization};
+
+/// Formats the docs can be escaped for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum Escape {
+    /// The contents of a JSON string, without the surrounding quotes.
+    Json,
+    /// HTML text content or attribute values.
+    Html,
+    /// The contents of a C string literal, without the surrounding quotes.
+    CString,
+}
+
+/// Configurable options for `Documented` and `DocumentedOpt` via helper
+/// attributes.
+///
+/// Initial values are set to default.
+#[cfg_attr(feature = "customise", optfield::optfield(
+    pub DeriveDocumentedCustomisations,
+    attrs = add(derive(Default)),
+    merge_fn = pub apply_customisations,
+    doc = "Parsed user-defined customisations of configurable options.\n\
+    Specialised variant for `Documented` and `DocumentedOpt`.\n\
+    \n\
+    Expected parse stream format: `<KW> = <VAL>, <KW> = <VAL>, ...`"
+))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeriveDocumentedConfig {
+    // optfield does not rewrap `Option` by default, which is the desired behavior
+    // see https://docs.rs/optfield/latest/optfield/#rewrapping-option-fields
+    pub default_value: Option<Expr>,
+    pub trim: bool,
+    pub trim_start: Option<bool>,
+    pub trim_end: Option<bool>,
+    pub skip_lines: usize,
+    pub strip_leading_heading: bool,
+    pub also_raw: bool,
+    pub list_variants: bool,
+    pub wrap: Option<usize>,
+    pub escape: Option<Escape>,
+    pub impl_display: bool,
+    pub inherent: bool,
+    pub help: bool,
+    pub normalize: Option<Normalization>,
+    pub inherit: bool,
+    pub remote: Option<Path>,
+    pub crate_path: Option<Path>,
+    pub summary: bool,
+    pub lines: bool,
+    pub paragraphs: bool,
+    pub sections: bool,
+    pub examples: bool,
+    pub deprecation: bool,
+    pub tags: bool,
+    pub strip_markdown: bool,
+    pub dedent: bool,
+    pub unwrap_lines: bool,
+    pub collapse_blank_lines: bool,
+    pub keep_blank_lines: bool,
+    pub normalize_newlines: bool,
+    pub expand_tabs: Option<usize>,
+    pub prefix: Option<String>,
+    pub suffix: Option<String>,
+    pub interpolate: bool,
+    pub sources: Vec<String>,
+    pub separator: Option<String>,
+}
+impl Default for DeriveDocumentedConfig {
+    fn default() -> Self {
+        Self {
+            default_value: None,
+            trim: true,
+            trim_start: None,
+            trim_end: None,
+            skip_lines: 0,
+            strip_leading_heading: false,
+            also_raw: false,
+            list_variants: false,
+            wrap: None,
+            escape: None,
+            impl_display: false,
+            inherent: false,
+            help: false,
+            normalize: None,
+            inherit: false,
+            remote: None,
+            crate_path: None,
+            summary: false,
+            lines: false,
+            paragraphs: false,
+            sections: false,
+            examples: false,
+            deprecation: false,
+            tags: false,
+            strip_markdown: false,
+            dedent: false,
+            unwrap_lines: false,
+            collapse_blank_lines: false,
+            keep_blank_lines: true,
+            normalize_newlines: false,
+            expand_tabs: None,
+            prefix: None,
+            suffix: None,
+            interpolate: false,
+            sources: Vec::new(),
+            separator: None,
+        }
+    }
+}
+
+impl DeriveDocumentedConfig {
+    /// The options for extracting and processing doc comments.
+    pub fn docs_options(&self) -> DocsOptions {
+        DocsOptions {
+            trim_start: self.trim_start.unwrap_or(self.trim),
+            trim_end: self.trim_end.unwrap_or(self.trim),
+            normalize: self.normalize,
+            strip_markdown: self.strip_markdown,
+            dedent: self.dedent,
+            unwrap_lines: self.unwrap_lines,
+            wrap: self.wrap,
+            collapse_blank_lines: self.collapse_blank_lines,
+            keep_blank_lines: self.keep_blank_lines,
+            normalize_newlines: self.normalize_newlines,
+            expand_tabs: self.expand_tabs,
+            prefix: self.prefix.clone(),
+            suffix: self.suffix.clone(),
+            interpolate: self.interpolate,
+            sources: self.sources.clone(),
+            doc: None,
+            separator: self.separator.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "customise")]
+mod customise {
+    use syn::parse_quote;
+
+    use crate::config::{
+        customise_core::{ConfigOption, ConfigOptionData},
+        derive_documented::{DeriveDocumentedConfig, DeriveDocumentedCustomisations},
+    };
+
+    impl DeriveDocumentedConfig {
+        /// Return a new instance of this config with customisations applied.
+        pub fn with_customisations(&self, customisations: DeriveDocumentedCustomisations) -> Self {
+            let mut new = self.clone();
+            new.apply_customisations(customisations);
+            new
+        }
+    }
+
+    impl TryFrom<Vec<ConfigOption>> for DeriveDocumentedCustomisations {
+        type Error = syn::Error;
+
+        /// Duplicate option rejection should be handled upstream.
+        fn try_from(opts: Vec<ConfigOption>) -> Result<Self, Self::Error> {
+            use ConfigOptionData as Data;
+
+            let mut config = Self::default();
+            for opt in opts {
+                match opt.data {
+                    Data::Vis(..)
+                    | Data::RenameAll(..)
+                    | Data::Rename(..)
+                    | Data::Lazy(..)
+                    | Data::FieldVis(..)
+                    | Data::Variants(..)
+                    | Data::Nested(..)
+                    | Data::WithTypes(..)
+                    | Data::Alias(..)
+                    | Data::MergeItems(..)
+                    | Data::FieldEnum(..)
+                    | Data::Skip(..)
+                    | Data::Flatten(..)
+                    | Data::CaseInsensitive(..)
+                    | Data::Doc(..)
+                    | Data::RequireAll(..)
+                    | Data::ConstLookup(..)
+                    | Data::Compact(..)
+                    | Data::MarkdownReference(..) => Err(syn::Error::new(
+                        opt.span,
+                        "This config option is not applicable here",
+                    ))?,
+                    Data::Default(expr) => {
+                        config.default_value.replace(expr);
+                    }
+                    // an explicit default takes precedence, regardless of order
+                    Data::AllowEmpty(allow) if allow.value() => {
+                        config.default_value.get_or_insert(parse_quote!(""));
+                    }
+                    Data::AllowEmpty(_) => {}
+                    Data::Trim(trim) => {
+                        config.trim.replace(trim.value());
+                    }
+                    Data::AlsoRaw(also_raw) => {
+                        config.also_raw.replace(also_raw.value());
+                    }
+                    Data::ListVariants(list_variants) => {
+                        config.list_variants.replace(list_variants.value());
+                    }
+                    Data::Wrap(columns) => {
+                        config.wrap.replace(columns.base10_parse()?);
+                    }
+                    Data::Escape(escape) => {
+                        config.escape.replace(escape.value());
+                    }
+                    Data::SkipLines(lines) => {
+                        config.skip_lines.replace(lines.base10_parse()?);
+                    }
+                    Data::StripLeadingHeading(strip) => {
+                        config.strip_leading_heading.replace(strip.value());
+                    }
+                    Data::ImplDisplay(impl_display) => {
+                        config.impl_display.replace(impl_display.value());
+                    }
+                    Data::Inherent(inherent) => {
+                        config.inherent.replace(inherent.value());
+                    }
+                    Data::Help(help) => {
+                        config.help.replace(help.value());
+                    }
+                    Data::Normalize(form) => {
+                        config.normalize.replace(form.value());
+                    }
+                    Data::Inherit(inherit) => {
+                        config.inherit.replace(inherit.value());
+                    }
+                    Data::Remote(path) => {
+                        config.remote.replace(path.parse()?);
+                    }
+                    Data::Crate(path) => {
+                        config.crate_path.replace(path.parse()?);
+                    }
+                    Data::Summary(summary) => {
+                        config.summary.replace(summary.value());
+                    }
+                    Data::Lines(lines) => {
+                        config.lines.replace(lines.value());
+                    }
+                    Data::Paragraphs(paragraphs) => {
+                        config.paragraphs.replace(paragraphs.value());
+                    }
+                    Data::Sections(sections) => {
+                        config.sections.replace(sections.value());
+                    }
+                    Data::Examples(examples) => {
+                        config.examples.replace(examples.value());
+                    }
+                    Data::Deprecation(deprecation) => {
+                        config.deprecation.replace(deprecation.value());
+                    }
+                    Data::Tags(tags) => {
+                        config.tags.replace(tags.value());
+                    }
+                    Data::StripMarkdown(strip) => {
+                        config.strip_markdown.replace(strip.value());
+                    }
+                    Data::Dedent(dedent) => {
+                        config.dedent.replace(dedent.value());
+                    }
+                    Data::TrimStart(trim) => {
+                        config.trim_start.replace(trim.value());
+                    }
+                    Data::TrimEnd(trim) => {
+                        config.trim_end.replace(trim.value());
+                    }
+                    Data::Separator(separator) => {
+                        config.separator.replace(separator.value());
+                    }
+                    Data::UnwrapLines(unwrap) => {
+                        config.unwrap_lines.replace(unwrap.value());
+                    }
+                    Data::CollapseBlankLines(collapse) => {
+                        config.collapse_blank_lines.replace(collapse.value());
+                    }
+                    Data::KeepBlankLines(keep) => {
+                        config.keep_blank_lines.replace(keep.value());
+                    }
+                    Data::NormalizeNewlines(normalize) => {
+                        config.normalize_newlines.replace(normalize.value());
+                    }
+                    Data::ExpandTabs(width) => {
+                        config.expand_tabs.replace(width.base10_parse()?);
+                    }
+                    Data::Prefix(prefix) => {
+                        config.prefix.replace(prefix.value());
+                    }
+                    Data::Suffix(suffix) => {
+                        config.suffix.replace(suffix.value());
+                    }
+                    Data::Interpolate(interpolate) => {
+                        config.interpolate.replace(interpolate.value());
+                    }
+                    Data::Source(source) => {
+                        config
+                            .sources
+                            .get_or_insert_with(Vec::new)
+                            .push(source.value());
+                    }
+                }
+            }
+            Ok(config)
+        }
+    }
+}
@@ -1,7 +1,9 @@
 //! Specialised configuration for `DocumentedFields` and `DocumentedFieldsOpt`.
 
 use convert_case::Case;
-use syn::Expr;
+use syn::{Expr, LitStr, Path};
+
+use crate::config::derive::{DocsOptions, Normalization};
 
 /// Defines how to rename a particular field.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -41,6 +43,39 @@ pub struct DeriveFieldsConfig {
     pub rename_mode: Option<RenameMode>,
     pub default_value: Option<Expr>,
     pub trim: bool,
+    pub trim_start: Option<bool>,
+    pub trim_end: Option<bool>,
+    pub field_vis: bool,
+    pub variants: Option<bool>,
+    pub nested: bool,
+    pub with_types: bool,
+    pub normalize: Option<Normalization>,
+    pub aliases: Vec<LitStr>,
+    pub field_enum: bool,
+    pub skip: bool,
+    pub flatten: bool,
+    pub case_insensitive: bool,
+    pub remote: Option<Path>,
+    pub crate_path: Option<Path>,
+    pub require_all: bool,
+    pub const_lookup: bool,
+    pub examples: bool,
+    pub deprecation: bool,
+    pub tags: bool,
+    pub strip_markdown: bool,
+    pub dedent: bool,
+    pub unwrap_lines: bool,
+    pub wrap: Option<usize>,
+    pub collapse_blank_lines: bool,
+    pub keep_blank_lines: bool,
+    pub normalize_newlines: bool,
+    pub expand_tabs: Option<usize>,
+    pub prefix: Option<String>,
+    pub suffix: Option<String>,
+    pub interpolate: bool,
+    pub sources: Vec<String>,
+    pub doc: Option<String>,
+    pub separator: Option<String>,
 }
 impl Default for DeriveFieldsConfig {
     fn default() -> Self {
@@ -48,12 +83,72 @@ impl Default for DeriveFieldsConfig {
             rename_mode: None,
             default_value: None,
             trim: true,
+            trim_start: None,
+            trim_end: None,
+            field_vis: false,
+            variants: None,
+            nested: false,
+            with_types: false,
+            normalize: None,
+            aliases: Vec::new(),
+            field_enum: false,
+            skip: false,
+            flatten: false,
+            case_insensitive: false,
+            remote: None,
+            crate_path: None,
+            require_all: false,
+            const_lookup: false,
+            examples: false,
+            deprecation: false,
+            tags: false,
+            strip_markdown: false,
+            dedent: false,
+            unwrap_lines: false,
+            wrap: None,
+            collapse_blank_lines: false,
+            keep_blank_lines: true,
+            normalize_newlines: false,
+            expand_tabs: None,
+            prefix: None,
+            suffix: None,
+            interpolate: false,
+            sources: Vec::new(),
+            doc: None,
+            separator: None,
+        }
+    }
+}
+
+impl DeriveFieldsConfig {
+    /// The options for extracting and processing doc comments.
+    pub fn docs_options(&self) -> DocsOptions {
+        DocsOptions {
+            trim_start: self.trim_start.unwrap_or(self.trim),
+            trim_end: self.trim_end.unwrap_or(self.trim),
+            normalize: self.normalize,
+            strip_markdown: self.strip_markdown,
+            dedent: self.dedent,
+            unwrap_lines: self.unwrap_lines,
+            wrap: self.wrap,
+            collapse_blank_lines: self.collapse_blank_lines,
+            keep_blank_lines: self.keep_blank_lines,
+            normalize_newlines: self.normalize_newlines,
+            expand_tabs: self.expand_tabs,
+            prefix: self.prefix.clone(),
+            suffix: self.suffix.clone(),
+            interpolate: self.interpolate,
+            sources: self.sources.clone(),
+            doc: self.doc.clone(),
+            separator: self.separator.clone(),
         }
     }
 }
 
 #[cfg(feature = "customise")]
 mod customise {
+    use syn::parse_quote;
+
     use crate::config::{
         customise_core::{ConfigOption, ConfigOptionData},
         derive_fields::{
@@ -94,7 +189,30 @@ mod customise {
             let mut config = Self::default();
             for opt in opts {
                 match opt.data {
-                    Data::Vis(..) | Data::Rename(..) => Err(syn::Error::new(
+                    Data::Vis(..)
+                    | Data::Rename(..)
+                    | Data::Lazy(..)
+                    | Data::AlsoRaw(..)
+                    | Data::ListVariants(..)
+                    | Data::Escape(..)
+                    | Data::Nested(..)
+                    | Data::SkipLines(..)
+                    | Data::StripLeadingHeading(..)
+                    | Data::ImplDisplay(..)
+                    | Data::Help(..)
+                    | Data::Alias(..)
+                    | Data::MergeItems(..)
+                    | Data::Skip(..)
+                    | Data::Flatten(..)
+                    | Data::Inherit(..)
+                    | Data::Summary(..)
+                    | Data::Lines(..)
+                    | Data::Paragraphs(..)
+                    | Data::Sections(..)
+                    | Data::Doc(..)
+                    | Data::Inherent(..)
+                    | Data::Compact(..)
+                    | Data::MarkdownReference(..) => Err(syn::Error::new(
                         opt.span,
                         "This config option is not applicable here",
                     ))?,
@@ -104,9 +222,101 @@ mod customise {
                     Data::Default(expr) => {
                         config.default_value.replace(expr);
                     }
+                    // an explicit default takes precedence, regardless of order
+                    Data::AllowEmpty(allow) if allow.value() => {
+                        config.default_value.get_or_insert(parse_quote!(""));
+                    }
+                    Data::AllowEmpty(_) => {}
                     Data::Trim(trim) => {
                         config.trim.replace(trim.value());
                     }
+                    Data::FieldVis(field_vis) => {
+                        config.field_vis.replace(field_vis.value());
+                    }
+                    Data::Variants(variants) => {
+                        config.variants.replace(variants.value());
+                    }
+                    Data::WithTypes(with_types) => {
+                        config.with_types.replace(with_types.value());
+                    }
+                    Data::Normalize(form) => {
+                        config.normalize.replace(form.value());
+                    }
+                    Data::FieldEnum(field_enum) => {
+                        config.field_enum.replace(field_enum.value());
+                    }
+                    Data::CaseInsensitive(case_insensitive) => {
+                        config.case_insensitive.replace(case_insensitive.value());
+                    }
+                    Data::Remote(path) => {
+                        config.remote.replace(path.parse()?);
+                    }
+                    Data::Crate(path) => {
+                        config.crate_path.replace(path.parse()?);
+                    }
+                    Data::RequireAll(require) => {
+                        config.require_all.replace(require.value());
+                    }
+                    Data::ConstLookup(const_lookup) => {
+                        config.const_lookup.replace(const_lookup.value());
+                    }
+                    Data::Examples(examples) => {
+                        config.examples.replace(examples.value());
+                    }
+                    Data::Deprecation(deprecation) => {
+                        config.deprecation.replace(deprecation.value());
+                    }
+                    Data::Tags(tags) => {
+                        config.tags.replace(tags.value());
+                    }
+                    Data::StripMarkdown(strip) => {
+                        config.strip_markdown.replace(strip.value());
+                    }
+                    Data::Dedent(dedent) => {
+                        config.dedent.replace(dedent.value());
+                    }
+                    Data::TrimStart(trim) => {
+                        config.trim_start.replace(trim.value());
+                    }
+                    Data::TrimEnd(trim) => {
+                        config.trim_end.replace(trim.value());
+                    }
+                    Data::Separator(separator) => {
+                        config.separator.replace(separator.value());
+                    }
+                    Data::UnwrapLines(unwrap) => {
+                        config.unwrap_lines.replace(unwrap.value());
+                    }
+                    Data::Wrap(columns) => {
+                        config.wrap.replace(columns.base10_parse()?);
+                    }
+                    Data::CollapseBlankLines(collapse) => {
+                        config.collapse_blank_lines.replace(collapse.value());
+                    }
+                    Data::KeepBlankLines(keep) => {
+                        config.keep_blank_lines.replace(keep.value());
+                    }
+                    Data::NormalizeNewlines(normalize) => {
+                        config.normalize_newlines.replace(normalize.value());
+                    }
+                    Data::ExpandTabs(width) => {
+                        config.expand_tabs.replace(width.base10_parse()?);
+                    }
+                    Data::Prefix(prefix) => {
+                        config.prefix.replace(prefix.value());
+                    }
+                    Data::Suffix(suffix) => {
+                        config.suffix.replace(suffix.value());
+                    }
+                    Data::Interpolate(interpolate) => {
+                        config.interpolate.replace(interpolate.value());
+                    }
+                    Data::Source(source) => {
+                        config
+                            .sources
+                            .get_or_insert_with(Vec::new)
+                            .push(source.value());
+                    }
                 }
             }
             Ok(config)
@@ -123,7 +333,35 @@ mod customise {
             let mut config = Self::default();
             for opt in opts {
                 match opt.data {
-                    Data::Vis(..) => Err(syn::Error::new(
+                    Data::Vis(..)
+                    | Data::Lazy(..)
+                    | Data::AlsoRaw(..)
+                    | Data::ListVariants(..)
+                    | Data::FieldVis(..)
+                    | Data::Escape(..)
+                    | Data::Variants(..)
+                    | Data::SkipLines(..)
+                    | Data::StripLeadingHeading(..)
+                    | Data::ImplDisplay(..)
+                    | Data::Help(..)
+                    | Data::MergeItems(..)
+                    | Data::FieldEnum(..)
+                    | Data::CaseInsensitive(..)
+                    | Data::Inherit(..)
+                    | Data::Remote(..)
+                    | Data::Summary(..)
+                    | Data::Lines(..)
+                    | Data::Paragraphs(..)
+                    | Data::Sections(..)
+                    | Data::Examples(..)
+                    | Data::Tags(..)
+                    | Data::Deprecation(..)
+                    | Data::Crate(..)
+                    | Data::Inherent(..)
+                    | Data::RequireAll(..)
+                    | Data::ConstLookup(..)
+                    | Data::Compact(..)
+                    | Data::MarkdownReference(..) => Err(syn::Error::new(
                         opt.span,
                         "This config option is not applicable here",
                     ))?,
@@ -139,9 +377,83 @@ mod customise {
                     Data::Default(expr) => {
                         config.default_value.replace(expr);
                     }
+                    // an explicit default takes precedence, regardless of order
+                    Data::AllowEmpty(allow) if allow.value() => {
+                        config.default_value.get_or_insert(parse_quote!(""));
+                    }
+                    Data::AllowEmpty(_) => {}
                     Data::Trim(trim) => {
                         config.trim.replace(trim.value());
                     }
+                    Data::Nested(nested) => {
+                        config.nested.replace(nested.value());
+                    }
+                    Data::WithTypes(with_types) => {
+                        config.with_types.replace(with_types.value());
+                    }
+                    Data::Normalize(form) => {
+                        config.normalize.replace(form.value());
+                    }
+                    Data::Alias(alias) => {
+                        config.aliases.get_or_insert_with(Vec::new).push(alias);
+                    }
+                    Data::Skip(skip) => {
+                        config.skip.replace(skip.value());
+                    }
+                    Data::Flatten(flatten) => {
+                        config.flatten.replace(flatten.value());
+                    }
+                    Data::StripMarkdown(strip) => {
+                        config.strip_markdown.replace(strip.value());
+                    }
+                    Data::Dedent(dedent) => {
+                        config.dedent.replace(dedent.value());
+                    }
+                    Data::TrimStart(trim) => {
+                        config.trim_start.replace(trim.value());
+                    }
+                    Data::TrimEnd(trim) => {
+                        config.trim_end.replace(trim.value());
+                    }
+                    Data::Separator(separator) => {
+                        config.separator.replace(separator.value());
+                    }
+                    Data::UnwrapLines(unwrap) => {
+                        config.unwrap_lines.replace(unwrap.value());
+                    }
+                    Data::Wrap(columns) => {
+                        config.wrap.replace(columns.base10_parse()?);
+                    }
+                    Data::CollapseBlankLines(collapse) => {
+                        config.collapse_blank_lines.replace(collapse.value());
+                    }
+                    Data::KeepBlankLines(keep) => {
+                        config.keep_blank_lines.replace(keep.value());
+                    }
+                    Data::NormalizeNewlines(normalize) => {
+                        config.normalize_newlines.replace(normalize.value());
+                    }
+                    Data::ExpandTabs(width) => {
+                        config.expand_tabs.replace(width.base10_parse()?);
+                    }
+                    Data::Prefix(prefix) => {
+                        config.prefix.replace(prefix.value());
+                    }
+                    Data::Suffix(suffix) => {
+                        config.suffix.replace(suffix.value());
+                    }
+                    Data::Interpolate(interpolate) => {
+                        config.interpolate.replace(interpolate.value());
+                    }
+                    Data::Source(source) => {
+                        config
+                            .sources
+                            .get_or_insert_with(Vec::new)
+                            .push(source.value());
+                    }
+                    Data::Doc(doc) => {
+                        config.doc.replace(doc.value());
+                    }
                 }
             }
             Ok(config)
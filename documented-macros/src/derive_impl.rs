@@ -3,22 +3,33 @@
 //! All functions in this module use the dependency injection pattern to
 //! generate the correct trait implementation for both macro variants.
 
-use convert_case::Casing;
+use std::collections::HashSet;
+
+use convert_case::{Case, Casing};
+use documented_text::wrap;
 use proc_macro2::{Span, TokenStream};
-use quote::{quote, ToTokens, TokenStreamExt};
+use quote::{format_ident, quote, quote_spanned, ToTokens, TokenStreamExt};
 use syn::{
-    spanned::Spanned, Data, DataEnum, DataStruct, DataUnion, DeriveInput, Error, Expr, Fields,
-    Ident,
+    ext::IdentExt, parse_quote, spanned::Spanned, Attribute, Data, DataEnum, DataStruct, DataUnion,
+    DeriveInput, Error, Expr, ExprLit, Fields, Ident, LitByteStr, Path, Type, UnOp, Variant,
+    VisRestricted, Visibility, WherePredicate,
 };
 
 #[cfg(feature = "customise")]
 use crate::config::customise_core::get_customisations_from_attrs;
+#[cfg(feature = "serde-interop")]
+use crate::serde_interop::SerdeNames;
 use crate::{
     config::{
-        derive::DeriveConfig,
+        derive::{DeriveConfig, DocsOptions},
+        derive_documented::DeriveDocumentedConfig,
         derive_fields::{DeriveFieldsConfig, RenameMode},
     },
-    util::{crate_module_path, get_docs},
+    util::{
+        code_blocks, crate_module_path, escape, extract_includes, extract_tags, first_paragraph,
+        get_deprecation, get_doc_aliases, get_processed_docs, get_source_docs, include_expr,
+        join_lines, paragraphs, sections, skip_leading, track_input_doc_macros,
+    },
 };
 
 /// The type of the doc comment.
@@ -33,7 +44,7 @@ impl ToTokens for DocType {
     fn to_tokens(&self, ts: &mut TokenStream) {
         let tokens = match self {
             Self::Str => quote! { &'static str },
-            Self::OptStr => quote! { Option<&'static str> },
+            Self::OptStr => quote! { ::core::option::Option<&'static str> },
         };
         ts.append_all([tokens]);
     }
@@ -64,15 +75,23 @@ impl DocType {
             ),
             Self::OptStr => Box::new(|docs_opt, default_opt, _span| {
                 let tokens = match (docs_opt, default_opt) {
-                    (Some(docs), _) => quote! { Some(#docs) },
+                    (Some(docs), _) => quote! { ::core::option::Option::Some(#docs) },
                     (None, Some(default)) => quote! { #default },
-                    (None, None) => quote! { None },
+                    (None, None) => quote! { ::core::option::Option::None },
                 };
                 Ok(tokens)
             }),
         }
     }
 
+    /// Wrap an expression of type `&'static str` into this type.
+    fn wrap_expr(&self, expr: TokenStream) -> TokenStream {
+        match self {
+            Self::Str => expr,
+            Self::OptStr => quote! { ::core::option::Option::Some(#expr) },
+        }
+    }
+
     /// Get the trait identifier, given a prefix.
     fn trait_ident_for(&self, prefix: &str) -> Ident {
         let name = match self {
@@ -83,33 +102,538 @@ impl DocType {
     }
 }
 
+/// Reject missing docs on a field or variant if `require_all` is set.
+fn require_docs<S>(
+    docs_opt: Option<String>,
+    require_all: bool,
+    span: S,
+) -> syn::Result<Option<String>>
+where
+    S: ToTokens,
+{
+    match docs_opt {
+        None if require_all => Err(Error::new_spanned(
+            span,
+            "Missing doc comments, which are required by `require_all`",
+        )),
+        docs_opt => Ok(docs_opt),
+    }
+}
+
 /// Shared implementation of `Documented` & `DocumentedOpt`.
 pub fn documented_impl(input: DeriveInput, docs_ty: DocType) -> syn::Result<TokenStream> {
     let trait_ident = docs_ty.trait_ident_for("Documented");
     let ident = &input.ident;
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let doc_macros = track_input_doc_macros(&input);
 
     #[cfg(not(feature = "customise"))]
-    let config = DeriveConfig::default();
+    let config = DeriveDocumentedConfig::default();
     #[cfg(feature = "customise")]
     let config = get_customisations_from_attrs(&input.attrs, "documented")
-        .map(|c| DeriveConfig::default().with_customisations(c))?;
+        .map(|c| DeriveDocumentedConfig::default().with_customisations(c))?;
+    let documented_module_path = crate_module_path(config.crate_path.as_ref());
+
+    // wrapping and the separator are applied later, after the steps specific
+    // to this macro
+    let docs_options = DocsOptions {
+        wrap: None,
+        separator: None,
+        ..config.docs_options()
+    };
+    let mut docs_opt = get_processed_docs(&input.attrs, &docs_options)?;
+    let mut tags = None;
+    if config.tags {
+        let (docs, found) = docs_opt.as_deref().map_or((None, Vec::new()), extract_tags);
+        docs_opt = docs;
+        tags = Some(found);
+    }
+    if config.skip_lines > 0 || config.strip_leading_heading {
+        docs_opt = docs_opt
+            .map(|docs| skip_leading(&docs, config.skip_lines, config.strip_leading_heading));
+    }
+    let mut includes = Vec::new();
+    if let Some(docs) = docs_opt {
+        let (docs, paths) = extract_includes(&docs, &input.attrs)?;
+        docs_opt = Some(docs);
+        includes = paths;
+    }
+    if let Some(columns) = config.wrap {
+        docs_opt = docs_opt.map(|docs| wrap(&docs, columns));
+    }
+    let mut summary_opt = if config.summary {
+        Some(docs_opt.as_deref().map(first_paragraph))
+    } else {
+        None
+    };
+    let help = if config.help {
+        Some(help(&input, docs_opt.as_deref(), &config.sources)?)
+    } else {
+        None
+    };
+    if config.list_variants {
+        let variants_list = list_variants(&input, &config.sources)?;
+        docs_opt = docs_opt.map(|docs| format!("{docs}\n\n{variants_list}"));
+    }
+    if (config.lines || config.paragraphs || config.sections) && !includes.is_empty() {
+        Err(Error::new(
+            input.ident.span(),
+            "`lines`, `paragraphs`, and `sections` cannot be used with include placeholders",
+        ))?
+    }
+    let mut lines = config.lines.then(|| {
+        docs_opt
+            .as_deref()
+            .map_or_else(Vec::new, |docs| docs.lines().map(str::to_owned).collect())
+    });
+    let mut paragraphs = config
+        .paragraphs
+        .then(|| docs_opt.as_deref().map_or_else(Vec::new, paragraphs));
+    let mut examples = if config.examples {
+        let raw_docs = get_source_docs(&input.attrs, &config.sources, false)?;
+        Some(raw_docs.as_deref().map_or_else(Vec::new, code_blocks))
+    } else {
+        None
+    };
+    let mut sections = config
+        .sections
+        .then(|| docs_opt.as_deref().map_or_else(Vec::new, sections));
+    if let Some(separator) = &config.separator {
+        docs_opt = docs_opt.map(|docs| join_lines(&docs, separator));
+        summary_opt = summary_opt.map(|summary| summary.map(|s| join_lines(&s, separator)));
+        for paragraph in paragraphs.iter_mut().flatten() {
+            *paragraph = join_lines(paragraph, separator);
+        }
+        for (_, body) in sections.iter_mut().flatten() {
+            *body = join_lines(body, separator);
+        }
+    }
+    if let Some(format) = config.escape {
+        docs_opt = docs_opt.map(|docs| escape(&docs, format));
+        summary_opt = summary_opt.map(|summary| summary.map(|s| escape(&s, format)));
+        for parts in lines
+            .iter_mut()
+            .chain(paragraphs.iter_mut())
+            .chain(examples.iter_mut())
+        {
+            for part in parts {
+                *part = escape(part, format);
+            }
+        }
+        for (key, value) in sections.iter_mut().chain(tags.iter_mut()).flatten() {
+            *key = escape(key, format);
+            *value = escape(value, format);
+        }
+    }
+    let inherited_ty = if config.inherit {
+        Some(inherited_ty(&input, &config)?)
+    } else {
+        None
+    };
+
+    let mut generics = input.generics.clone();
+    let docs = match (docs_opt, inherited_ty) {
+        (Some(docs), _) if !includes.is_empty() => {
+            docs_ty.wrap_expr(include_expr(&docs, &includes, &documented_module_path))
+        }
+        (None, Some(ty)) => {
+            // the inner type must be documented too, which is not a given
+            // for generic ones
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote! { #ty: #documented_module_path::#trait_ident });
+            quote! { <#ty as #documented_module_path::#trait_ident>::DOCS }
+        }
+        (docs_opt, _) => {
+            docs_ty.docs_handler_opt()(docs_opt, config.default_value.clone(), &input)?
+        }
+    };
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let raw_docs_impl = if config.also_raw {
+        let vis = &input.vis;
+        let raw_docs =
+            get_source_docs(&input.attrs, &config.sources, false).and_then(|docs_opt| {
+                docs_ty.docs_handler_opt()(docs_opt, config.default_value.clone(), &input)
+            })?;
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// The static doc comments on this type, exactly as written.
+                #[allow(dead_code)]
+                #vis const DOCS_RAW: #docs_ty = #raw_docs;
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let summary_impl = match summary_opt {
+        Some(summary_opt) => {
+            let vis = &input.vis;
+            let summary = match summary_opt {
+                Some(summary) if !includes.is_empty() => {
+                    docs_ty.wrap_expr(include_expr(&summary, &includes, &documented_module_path))
+                }
+                summary_opt => {
+                    docs_ty.docs_handler_opt()(summary_opt, config.default_value.clone(), &input)?
+                }
+            };
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics #ident #ty_generics #where_clause {
+                    /// The first paragraph of the docs of this type.
+                    #[allow(dead_code)]
+                    #vis const DOCS_SUMMARY: #docs_ty = #summary;
+                }
+            }
+        }
+        None => quote! {},
+    };
+
+    let parts_impl = |parts: Option<Vec<String>>, name: &str, doc: &str| match parts {
+        Some(parts) => {
+            let vis = &input.vis;
+            let name = Ident::new(name, Span::call_site());
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics #ident #ty_generics #where_clause {
+                    #[doc = #doc]
+                    #[allow(dead_code)]
+                    #vis const #name: &'static [&'static str] = &[#(#parts),*];
+                }
+            }
+        }
+        None => quote! {},
+    };
+    let lines_impl = parts_impl(lines, "DOCS_LINES", "The lines of the docs of this type.");
+    let paragraphs_impl = parts_impl(
+        paragraphs,
+        "DOCS_PARAGRAPHS",
+        "The paragraphs of the docs of this type.",
+    );
+    let tags_impl = match tags {
+        Some(tags) => {
+            let vis = &input.vis;
+            let (names, values): (Vec<_>, Vec<_>) = tags.into_iter().unzip();
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics #ident #ty_generics #where_clause {
+                    /// The tags in the docs of this type, as name-value pairs.
+                    #[allow(dead_code)]
+                    #vis const DOCS_TAGS: &'static [(&'static str, &'static str)] =
+                        &[#((#names, #values)),*];
+                }
+            }
+        }
+        None => quote! {},
+    };
+    let examples_impl = parts_impl(
+        examples,
+        "DOCS_EXAMPLES",
+        "The contents of the fenced code blocks in the docs of this type.",
+    );
+    let sections_impl = match sections {
+        Some(sections) => {
+            let vis = &input.vis;
+            let (headings, bodies): (Vec<_>, Vec<_>) = sections.into_iter().unzip();
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics #ident #ty_generics #where_clause {
+                    /// The sections of the docs of this type, keyed by their
+                    /// headings.
+                    #[allow(dead_code)]
+                    #vis const DOCS_SECTIONS: &'static [(&'static str, &'static str)] =
+                        &[#((#headings, #bodies)),*];
+                }
+            }
+        }
+        None => quote! {},
+    };
+
+    let help_impl = match help {
+        Some(help) => {
+            let vis = &input.vis;
+            let help = if includes.is_empty() {
+                quote! { #help }
+            } else {
+                include_expr(&help, &includes, &documented_module_path)
+            };
+            quote! {
+                #[automatically_derived]
+                impl #impl_generics #ident #ty_generics #where_clause {
+                    /// The docs of this type, followed by an aligned list of
+                    /// its fields or variants with their summaries.
+                    #[allow(dead_code)]
+                    #vis const HELP: &'static str = #help;
+                }
+            }
+        }
+        None => quote! {},
+    };
 
-    let docs = get_docs(&input.attrs, config.trim)
-        .and_then(|docs_opt| docs_ty.docs_handler_opt()(docs_opt, config.default_value, &input))?;
+    let display_impl = match docs_ty {
+        _ if !config.impl_display => quote! {},
+        DocType::Str => quote! {
+            #[automatically_derived]
+            impl #impl_generics ::core::fmt::Display for #ident #ty_generics #where_clause {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.pad(<Self as #documented_module_path::#trait_ident>::DOCS)
+                }
+            }
+        },
+        DocType::OptStr => Err(Error::new(
+            ident.span(),
+            "impl_display is only applicable to Documented, whose docs are always present",
+        ))?,
+    };
+
+    let inherent_impl = if config.inherent {
+        let vis = &input.vis;
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// The static doc comments on this type, accessible without
+                /// importing the trait.
+                #[allow(dead_code)]
+                #vis const DOCS: #docs_ty =
+                    <Self as #documented_module_path::#trait_ident>::DOCS;
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let deprecation_impl = if config.deprecation {
+        let vis = &input.vis;
+        let deprecation = match get_deprecation(&input.attrs)? {
+            Some(note) => quote! { ::core::option::Option::Some(#note) },
+            None => quote! { ::core::option::Option::None },
+        };
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// The note of the `#[deprecated]` attribute of this type,
+                /// which is empty if there is none, or `None` if this type is
+                /// not deprecated.
+                #[allow(dead_code)]
+                #vis const DEPRECATION: ::core::option::Option<&'static str> = #deprecation;
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let item_name = ident.unraw().to_string();
+    let remote_check = remote_check(&input, config.remote.as_ref());
 
     Ok(quote! {
         #[automatically_derived]
-        impl #impl_generics documented::#trait_ident for #ident #ty_generics #where_clause {
+        impl #impl_generics #documented_module_path::#trait_ident for #ident #ty_generics #where_clause {
             const DOCS: #docs_ty = #docs;
+            const ITEM_NAME: &'static str = #item_name;
+            const MODULE_PATH: &'static str = ::core::module_path!();
         }
+        #inherent_impl
+        #raw_docs_impl
+        #summary_impl
+        #lines_impl
+        #paragraphs_impl
+        #sections_impl
+        #examples_impl
+        #tags_impl
+        #deprecation_impl
+        #help_impl
+        #display_impl
+        #remote_check
+        #doc_macros
     })
 }
 
+/// Generate a check that a remote type has all the fields (or variants) of
+/// its local mirror.
+fn remote_check(input: &DeriveInput, remote: Option<&Path>) -> TokenStream {
+    let Some(remote) = remote else {
+        return quote! {};
+    };
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let body = match &input.data {
+        Data::Struct(DataStruct { fields, .. }) => {
+            let members = fields.members();
+            quote! { #(let _ = &remote.#members;)* }
+        }
+        Data::Union(DataUnion { fields, .. }) => {
+            let members = fields.named.iter().map(|f| &f.ident);
+            quote! { #(let _ = unsafe { &remote.#members };)* }
+        }
+        Data::Enum(DataEnum { variants, .. }) => {
+            let arms = variants.iter().map(|v| {
+                let variant = &v.ident;
+                match v.fields {
+                    Fields::Named(_) => quote! { #remote::#variant { .. } => {} },
+                    Fields::Unnamed(_) => quote! { #remote::#variant(..) => {} },
+                    Fields::Unit => quote! { #remote::#variant => {} },
+                }
+            });
+            // the remote type may have more variants, e.g. if non-exhaustive
+            quote! {
+                match remote {
+                    #(#arms)*
+                    _ => {}
+                }
+            }
+        }
+    };
+    quote! {
+        const _: () = {
+            #[allow(dead_code, unreachable_patterns)]
+            fn check_remote #impl_generics (remote: &#remote #ty_generics) #where_clause {
+                #body
+            }
+        };
+    }
+}
+
+/// Get the type of the only field of a newtype, whose docs are inherited.
+fn inherited_ty(input: &DeriveInput, config: &DeriveDocumentedConfig) -> syn::Result<Type> {
+    if config.default_value.is_some() {
+        Err(Error::new(
+            input.ident.span(),
+            "inherit and default cannot be used together",
+        ))?
+    }
+    match &input.data {
+        Data::Struct(DataStruct { fields, .. }) if fields.len() == 1 => {
+            Ok(fields.iter().next().unwrap().ty.clone())
+        }
+        _ => Err(Error::new(
+            input.ident.span(),
+            "inherit is only applicable to structs with exactly one field",
+        )),
+    }
+}
+
+/// Render a list of the variants of an enum, each with the first line of its
+/// docs (if any).
+fn list_variants(input: &DeriveInput, sources: &[String]) -> syn::Result<String> {
+    let variants = match &input.data {
+        Data::Enum(DataEnum { variants, .. }) => Ok(variants),
+        Data::Struct(DataStruct { struct_token, .. }) => Err(struct_token.span()),
+        Data::Union(DataUnion { union_token, .. }) => Err(union_token.span()),
+    }
+    .map_err(|span| Error::new(span, "`list_variants` can only be used on enums"))?;
+
+    let mut list = String::from("Variants:");
+    for v in variants {
+        let summary = get_source_docs(&v.attrs, sources, true)?.and_then(|docs| {
+            docs.lines()
+                .find(|line| !line.is_empty())
+                .map(str::to_owned)
+        });
+        match summary {
+            Some(summary) => list.push_str(&format!("\n- {}: {summary}", v.ident)),
+            None => list.push_str(&format!("\n- {}", v.ident)),
+        }
+    }
+    Ok(list)
+}
+
+/// Render the docs of a type followed by an aligned list of its fields or
+/// variants, each with the first line of its docs (if any).
+///
+/// Anonymous fields are listed by their index.
+fn help(input: &DeriveInput, docs: Option<&str>, sources: &[String]) -> syn::Result<String> {
+    let (heading, members) = match &input.data {
+        Data::Enum(DataEnum { variants, .. }) => (
+            "Variants:",
+            variants
+                .iter()
+                .map(|v| (v.ident.to_string(), &v.attrs))
+                .collect::<Vec<_>>(),
+        ),
+        Data::Struct(DataStruct { fields, .. }) => (
+            "Fields:",
+            fields
+                .iter()
+                .enumerate()
+                .map(|(i, f)| {
+                    let name = f
+                        .ident
+                        .as_ref()
+                        .map_or_else(|| i.to_string(), Ident::to_string);
+                    (name, &f.attrs)
+                })
+                .collect(),
+        ),
+        Data::Union(DataUnion { fields, .. }) => (
+            "Fields:",
+            fields
+                .named
+                .iter()
+                .map(|f| (f.ident.as_ref().unwrap().to_string(), &f.attrs))
+                .collect(),
+        ),
+    };
+
+    let mut help = docs.map_or_else(String::new, |docs| format!("{docs}\n\n"));
+    if members.is_empty() {
+        return Ok(help.trim_end().to_string());
+    }
+    help.push_str(heading);
+    let width = members
+        .iter()
+        .map(|(name, _)| name.chars().count())
+        .max()
+        .unwrap_or(0);
+    for (name, attrs) in members {
+        let summary = get_source_docs(attrs, sources, true)?.and_then(|docs| {
+            docs.lines()
+                .find(|line| !line.is_empty())
+                .map(str::to_owned)
+        });
+        match summary {
+            Some(summary) => help.push_str(&format!("\n  {name:width$}  {summary}")),
+            None => help.push_str(&format!("\n  {name}")),
+        }
+    }
+    Ok(help)
+}
+
+/// Render the entry of a variant in the markdown reference of its enum: a
+/// heading with its name, followed by its discriminant and docs (if any).
+fn reference_entry(variant: &Variant, docs: Option<&str>) -> String {
+    let mut entry = format!("## `{}`", variant.ident.unraw());
+    if let Some((_, discriminant)) = &variant.discriminant {
+        // spell out (negative) literals as written, rather than as tokens
+        let code = match discriminant {
+            Expr::Lit(ExprLit { lit, .. }) => lit.to_token_stream().to_string(),
+            Expr::Unary(unary) if matches!(unary.op, UnOp::Neg(_)) => {
+                format!("-{}", unary.expr.to_token_stream())
+            }
+            expr => expr.to_token_stream().to_string(),
+        };
+        entry.push_str(&format!("\n\nCode: `{code}`"));
+    }
+    if let Some(docs) = docs.filter(|docs| !docs.is_empty()) {
+        entry.push_str(&format!("\n\n{docs}"));
+    }
+    entry
+}
+
+/// Get the name of a field or variant, with renaming applied.
+fn field_name(ident: Option<Ident>, rename_mode: Option<RenameMode>) -> Option<String> {
+    match rename_mode {
+        None => ident.map(|ident| ident.to_string()),
+        Some(RenameMode::ToCase(case)) => ident.map(|ident| ident.to_string().to_case(case)),
+        Some(RenameMode::Custom(name)) => Some(name),
+    }
+}
+
 /// Shared implementation of `DocumentedFields` & `DocumentedFieldsOpt`.
 pub fn documented_fields_impl(input: DeriveInput, docs_ty: DocType) -> syn::Result<TokenStream> {
     let trait_ident = docs_ty.trait_ident_for("DocumentedFields");
     let ident = &input.ident;
+    let doc_macros = track_input_doc_macros(&input);
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
     // `#[documented_fields(...)]` on container type
@@ -118,39 +642,246 @@ pub fn documented_fields_impl(input: DeriveInput, docs_ty: DocType) -> syn::Resu
     #[cfg(feature = "customise")]
     let base_config = get_customisations_from_attrs(&input.attrs, "documented_fields")
         .map(|c| DeriveFieldsConfig::default().with_base_customisations(c))?;
+    let documented_module_path = crate_module_path(base_config.crate_path.as_ref());
+
+    let enum_warning = match (&input.data, base_config.variants) {
+        (Data::Enum(DataEnum { enum_token, .. }), None) => {
+            quote_spanned! { enum_token.span() =>
+                const _: () = {
+                    #[deprecated(
+                        note = "deriving DocumentedFields on an enum documents its variants; \
+                        opt in with `#[documented_fields(variants)]` (which needs the `customise` \
+                        feature), or use DocumentedVariants instead"
+                    )]
+                    struct DocumentedFieldsOnEnum;
+                    let _ = DocumentedFieldsOnEnum;
+                };
+            }
+        }
+        (Data::Enum(DataEnum { enum_token, .. }), Some(false)) => Err(Error::new(
+            enum_token.span(),
+            "DocumentedFields on enums is disabled by `variants = false`.\n\
+            Use DocumentedVariants instead.",
+        ))?,
+        (Data::Struct(_) | Data::Union(_), Some(_)) => Err(Error::new(
+            input.ident.span(),
+            "variants is only applicable to enums",
+        ))?,
+        _ => quote! {},
+    };
 
     let fields_attrs: Vec<_> = match input.data.clone() {
         Data::Enum(DataEnum { variants, .. }) => variants
             .into_iter()
-            .map(|v| (v.to_token_stream(), Some(v.ident), v.attrs))
+            .map(|v| (v.to_token_stream(), Some(v.ident), v.attrs, None, None))
             .collect(),
         Data::Struct(DataStruct { fields, .. }) => fields
             .into_iter()
-            .map(|f| (f.to_token_stream(), f.ident, f.attrs))
+            .map(|f| {
+                (
+                    f.to_token_stream(),
+                    f.ident,
+                    f.attrs,
+                    Some(f.vis),
+                    Some(f.ty),
+                )
+            })
             .collect(),
         Data::Union(DataUnion { fields, .. }) => fields
             .named
             .into_iter()
-            .map(|f| (f.to_token_stream(), f.ident, f.attrs))
+            .map(|f| {
+                (
+                    f.to_token_stream(),
+                    f.ident,
+                    f.attrs,
+                    Some(f.vis),
+                    Some(f.ty),
+                )
+            })
             .collect(),
     };
 
+    // skipped fields are left out entirely, so indices only count the rest
+    #[cfg(feature = "customise")]
+    let fields_attrs = fields_attrs
+        .into_iter()
+        .filter_map(|field| {
+            get_customisations_from_attrs(&field.2, "documented_fields")
+                .map(|c| base_config.with_field_customisations(c).skip)
+                .map(|skip| (!skip).then_some(field))
+                .transpose()
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let field_vis_impl = if base_config.field_vis {
+        let field_vis = fields_attrs
+            .iter()
+            .map(|(_, _, _, vis, _)| vis.as_ref().map(vis_to_string))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| {
+                Error::new(
+                    input.ident.span(),
+                    "field_vis is not applicable to enums, whose variants have no visibility",
+                )
+            })?;
+        let vis = &input.vis;
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// The declared visibility of each field of this type, indexed
+                /// by field order. Empty for private fields.
+                #[allow(dead_code)]
+                #vis const FIELD_VIS: &'static [&'static str] = &[#(#field_vis),*];
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let field_deprecations_impl = if base_config.deprecation {
+        let field_deprecations = fields_attrs
+            .iter()
+            .map(|(_, _, attrs, _, _)| get_deprecation(attrs))
+            .collect::<syn::Result<Vec<_>>>()?;
+        let field_deprecations = field_deprecations.iter().map(|note| match note {
+            Some(note) => quote! { ::core::option::Option::Some(#note) },
+            None => quote! { ::core::option::Option::None },
+        });
+        let vis = &input.vis;
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// The note of the `#[deprecated]` attribute of each field of
+                /// this type, indexed by field order. Empty if there is no
+                /// note, or `None` if the field is not deprecated.
+                #[allow(dead_code)]
+                #vis const FIELD_DEPRECATIONS: &'static [::core::option::Option<&'static str>] =
+                    &[#(#field_deprecations),*];
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let field_examples_impl = if base_config.examples {
+        let field_examples = fields_attrs
+            .iter()
+            .map(|(_, _, attrs, _, _)| get_source_docs(attrs, &base_config.sources, false))
+            .map(|docs| docs.map(|docs| docs.as_deref().map_or_else(Vec::new, code_blocks)))
+            .collect::<syn::Result<Vec<_>>>()?;
+        let field_examples = field_examples
+            .iter()
+            .map(|examples| quote! { &[#(#examples),*] });
+        let vis = &input.vis;
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// The contents of the fenced code blocks in the docs of each
+                /// field of this type, indexed by field order.
+                #[allow(dead_code)]
+                #vis const FIELD_EXAMPLES: &'static [&'static [&'static str]] =
+                    &[#(#field_examples),*];
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let field_enum_impl = if base_config.field_enum {
+        let field_idents = fields_attrs
+            .iter()
+            .map(|(_, ident, _, _, _)| ident.as_ref())
+            .collect::<Vec<_>>();
+        field_enum(
+            &input,
+            &docs_ty,
+            &trait_ident,
+            &field_idents,
+            &documented_module_path,
+        )
+    } else {
+        quote! {}
+    };
+
+    let mut nested_match_arms = Vec::new();
+    let mut nested_tys = Vec::new();
+    let mut flattened_tys = Vec::new();
+    let mut aliases = Vec::new();
+    let mut doc_aliases = Vec::new();
+    let mut anonymous_indices = Vec::new();
+    let mut field_tags = Vec::new();
+    #[cfg(feature = "serde-interop")]
+    let serde_names = SerdeNames::from_container_attrs(&input.attrs)?;
+    #[cfg(feature = "serde-interop")]
+    let mut serde_aliases = Vec::new();
+    let mut field_spans = Vec::new();
     let (field_names, field_docs) = fields_attrs
         .into_iter()
-        .map(|(span, ident, attrs)| {
-            #[cfg(not(feature = "customise"))]
-            let config = base_config.clone();
-            #[cfg(feature = "customise")]
-            let config = get_customisations_from_attrs(&attrs, "documented_fields")
-                .map(|c| base_config.with_field_customisations(c))?;
-            let name = match config.rename_mode {
-                None => ident.map(|ident| ident.to_string()),
-                Some(RenameMode::ToCase(case)) => {
-                    ident.map(|ident| ident.to_string().to_case(case))
-                }
-                Some(RenameMode::Custom(name)) => Some(name),
-            };
-            get_docs(&attrs, config.trim)
+        .enumerate()
+        .map(|(i, (span, ident, attrs, _, ty))| {
+            field_spans.push(span.clone());
+            let config = field_config(&base_config, &attrs, "documented_fields")?;
+            let docs_options = config.docs_options();
+            if config.nested {
+                let Some(ty) = ty.clone() else {
+                    Err(Error::new_spanned(
+                        &span,
+                        "nested is not applicable to enum variants",
+                    ))?
+                };
+                nested_match_arms.push(quote! {
+                    #i => ::core::option::Option::Some(
+                        <#ty as #documented_module_path::#trait_ident>::get_field_docs_by_path(path),
+                    ),
+                });
+                nested_tys.push(ty);
+            }
+            if config.flatten {
+                let Some(ty) = ty.clone() else {
+                    Err(Error::new_spanned(
+                        &span,
+                        "flatten is not applicable to enum variants",
+                    ))?
+                };
+                nested_tys.push(ty.clone());
+                flattened_tys.push(ty);
+            }
+            aliases.extend(config.aliases.into_iter().map(|alias| (i, alias)));
+            doc_aliases.extend(get_doc_aliases(&attrs)?.into_iter().map(|alias| (i, alias)));
+            #[cfg(feature = "serde-interop")]
+            serde_aliases.extend(
+                serde_names
+                    .names_of(ident.as_ref(), &attrs, ty.is_none())?
+                    .into_iter()
+                    .map(|name| (i, name)),
+            );
+            if ident.is_none() {
+                anonymous_indices.push(i);
+            }
+            let name = field_name(ident, config.rename_mode);
+            get_processed_docs(&attrs, &docs_options)
+                .map(|docs_opt| match docs_opt {
+                    Some(docs) if base_config.tags => {
+                        let (docs_opt, tags) = extract_tags(&docs);
+                        field_tags.push(tags);
+                        docs_opt
+                    }
+                    docs_opt => {
+                        field_tags.push(Vec::new());
+                        docs_opt
+                    }
+                })
+                .and_then(|docs_opt| match (&ty, config.with_types) {
+                    (_, false) => Ok(docs_opt),
+                    (Some(ty), true) => Ok(docs_opt
+                        .map(|docs| format!("{docs} (type: {})", type_to_string(ty)))),
+                    (None, true) => Err(Error::new_spanned(
+                        &span,
+                        "with_types is not applicable to enum variants",
+                    )),
+                })
+                .and_then(|docs_opt| require_docs(docs_opt, config.require_all, &span))
                 .and_then(|docs_opt| {
                     docs_ty.docs_handler_opt()(docs_opt, config.default_value, span)
                 })
@@ -160,30 +891,702 @@ pub fn documented_fields_impl(input: DeriveInput, docs_ty: DocType) -> syn::Resu
         .into_iter()
         .unzip::<_, _, Vec<_>, Vec<_>>();
 
-    let (field_names, phf_match_arms) = field_names
+    let field_tags_impl = if base_config.tags {
+        let field_tags = field_tags.iter().map(|tags| {
+            let (names, values): (Vec<_>, Vec<_>) = tags.iter().cloned().unzip();
+            quote! { &[#((#names, #values)),*] }
+        });
+        let vis = &input.vis;
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// The tags in the docs of each field of this type, as
+                /// name-value pairs, indexed by field order.
+                #[allow(dead_code)]
+                #vis const FIELD_TAGS: &'static [&'static [(&'static str, &'static str)]] =
+                    &[#(#field_tags),*];
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // nested and flattened field types must be documented too, which is not a
+    // given for generic ones
+    let mut trait_generics = input.generics.clone();
+    if !nested_tys.is_empty() {
+        trait_generics
+            .make_where_clause()
+            .predicates
+            .extend(nested_tys.iter().map(|ty| -> WherePredicate {
+                parse_quote! { #ty: #documented_module_path::#trait_ident }
+            }));
+    }
+    let (trait_impl_generics, _, trait_where_clause) = trait_generics.split_for_impl();
+
+    let nested_impl = if nested_match_arms.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn __documented_get_nested_field_docs(
+                index: usize,
+                path: &[&str],
+            ) -> ::core::option::Option<::core::result::Result<&'static str, #documented_module_path::Error>> {
+                match index {
+                    #(#nested_match_arms)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+    };
+
+    let flattened_impl = if flattened_tys.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn __documented_get_flattened_field_docs(
+                path: &[&str],
+            ) -> ::core::option::Option<::core::result::Result<&'static str, #documented_module_path::Error>> {
+                #(
+                    match <#flattened_tys as #documented_module_path::#trait_ident>::get_field_docs_by_path(path.iter().copied()) {
+                        ::core::result::Result::Err(#documented_module_path::Error::NoSuchField { .. }) => {}
+                        docs => return ::core::option::Option::Some(docs),
+                    }
+                )*
+                ::core::option::Option::None
+            }
+        }
+    };
+
+    let remote_check = remote_check(&input, base_config.remote.as_ref());
+    let case_insensitive = base_config.case_insensitive;
+    let key_of = |name: &str| match case_insensitive {
+        true => name.to_lowercase(),
+        false => name.to_owned(),
+    };
+
+    let field_names = field_names
         .into_iter()
         .enumerate()
-        .filter_map(|(i, field)| field.map(|field| (i, field.as_str().to_owned())))
-        .map(|(i, name)| (name.clone(), quote! { #name => #i, }))
-        .unzip::<_, _, Vec<_>, Vec<_>>();
+        .filter_map(|(i, field)| field.map(|field| (i, field)))
+        .collect::<Vec<_>>();
+    let mut keys = HashSet::new();
+    let mut lookup_keys = Vec::new();
+    for (i, name) in &field_names {
+        let key = key_of(name);
+        if !keys.insert(key.clone()) {
+            Err(Error::new_spanned(
+                &field_spans[*i],
+                format!(r#"Multiple fields are named "{key}""#),
+            ))?
+        }
+        lookup_keys.push((key, *i));
+    }
+    let field_names = field_names.into_iter().map(|(_, name)| name);
 
-    let documented_module_path = crate_module_path();
+    // anonymous fields can always be looked up by index, unless it is taken
+    for i in anonymous_indices {
+        let name = i.to_string();
+        if keys.insert(name.clone()) {
+            lookup_keys.push((name, i));
+        }
+    }
+    for (i, alias) in aliases {
+        let key = key_of(&alias.value());
+        if !keys.insert(key.clone()) {
+            Err(Error::new(
+                alias.span(),
+                "This alias is already the name or alias of a field",
+            ))?
+        }
+        lookup_keys.push((key, i));
+    }
+    // rustdoc search aliases are not meant to be unique, so duplicates are fine
+    for (i, alias) in doc_aliases {
+        let key = key_of(&alias.value());
+        if keys.insert(key.clone()) {
+            lookup_keys.push((key, i));
+        }
+    }
+    // serde names usually coincide with field names, so duplicates are fine
+    #[cfg(feature = "serde-interop")]
+    for (i, name) in serde_aliases {
+        let key = key_of(&name);
+        if keys.insert(key.clone()) {
+            lookup_keys.push((key, i));
+        }
+    }
+
+    if base_config.const_lookup && case_insensitive {
+        Err(Error::new(
+            input.ident.span(),
+            "const_lookup and case_insensitive cannot be used together",
+        ))?
+    }
+    let get_index_body = if base_config.const_lookup {
+        quote! { Self::__documented_get_index_const(field_name.as_ref()) }
+    } else {
+        let lookup_key = match case_insensitive {
+            true => quote! { field_name.as_ref().to_lowercase().as_str() },
+            false => quote! { field_name.as_ref() },
+        };
+        index_lookup(&lookup_keys, lookup_key, &documented_module_path)
+    };
+    let const_lookup_impl = if base_config.const_lookup {
+        let vis = &input.vis;
+        let match_arms = lookup_keys.iter().map(|(key, i)| {
+            let key = LitByteStr::new(key.as_bytes(), Span::call_site());
+            quote! { #key => ::core::option::Option::Some(#i), }
+        });
+        let docs = match docs_ty {
+            DocType::Str => quote! { ::core::option::Option::Some(docs[index]) },
+            DocType::OptStr => quote! { docs[index] },
+        };
+        quote! {
+            #[automatically_derived]
+            impl #trait_impl_generics #ident #ty_generics #trait_where_clause {
+                /// Method internally used by `documented`.
+                #[doc(hidden)]
+                const fn __documented_get_index_const(field_name: &str) -> ::core::option::Option<usize> {
+                    match field_name.as_bytes() {
+                        #(#match_arms)*
+                        _ => ::core::option::Option::None,
+                    }
+                }
+
+                /// Get a field's documentation using its name, in const
+                /// contexts. `None` if no field has this name, or if it has no
+                /// doc comments.
+                #[allow(dead_code)]
+                #vis const fn get_field_docs_const(field_name: &str) -> ::core::option::Option<&'static str> {
+                    let docs = <Self as #documented_module_path::#trait_ident>::FIELD_DOCS;
+                    match Self::__documented_get_index_const(field_name) {
+                        ::core::option::Option::Some(index) => #docs,
+                        ::core::option::Option::None => ::core::option::Option::None,
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
 
     Ok(quote! {
         #[automatically_derived]
-        impl #impl_generics documented::#trait_ident for #ident #ty_generics #where_clause {
+        impl #trait_impl_generics #documented_module_path::#trait_ident for #ident #ty_generics #trait_where_clause {
             const FIELD_NAMES: &'static [&'static str] = &[#(#field_names),*];
             const FIELD_DOCS: &'static [#docs_ty] = &[#(#field_docs),*];
 
-            fn __documented_get_index<__Documented_T: AsRef<str>>(field_name: __Documented_T) -> Option<usize> {
-                use #documented_module_path::_private_phf_reexport_for_macro as phf;
+            fn __documented_get_index<__Documented_T: ::core::convert::AsRef<str>>(field_name: __Documented_T) -> ::core::option::Option<usize> {
+                #get_index_body
+            }
 
-                static PHF: phf::Map<&'static str, usize> = phf::phf_map! {
-                    #(#phf_match_arms)*
-                };
-                PHF.get(field_name.as_ref()).copied()
+            #nested_impl
+            #flattened_impl
+        }
+        #const_lookup_impl
+        #field_vis_impl
+        #field_examples_impl
+        #field_deprecations_impl
+        #field_tags_impl
+        #field_enum_impl
+        #remote_check
+        #enum_warning
+        #doc_macros
+    })
+}
+
+/// Generate a lookup of the index for `lookup_key` among `keys`, using a
+/// perfect hash map if the `phf` feature is enabled, or a plain `match`.
+#[cfg_attr(not(feature = "phf"), allow(unused_variables))]
+fn index_lookup(
+    keys: &[(String, usize)],
+    lookup_key: TokenStream,
+    documented_module_path: &Path,
+) -> TokenStream {
+    let (keys, indices): (Vec<_>, Vec<_>) = keys.iter().cloned().unzip();
+    #[cfg(feature = "phf")]
+    let lookup = quote! {
+        use #documented_module_path::_private_phf_reexport_for_macro as phf;
+
+        static PHF: phf::Map<&'static str, usize> = phf::phf_map! {
+            #(#keys => #indices,)*
+        };
+        PHF.get(#lookup_key).copied()
+    };
+    #[cfg(not(feature = "phf"))]
+    let lookup = quote! {
+        match #lookup_key {
+            #(#keys => ::core::option::Option::Some(#indices),)*
+            _ => ::core::option::Option::None,
+        }
+    };
+    lookup
+}
+
+/// Generate an enum with a variant for each field, and an inherent method to
+/// get the docs of a field by variant.
+///
+/// Variants are named after the fields in `PascalCase`, and anonymous fields
+/// after their index, e.g. `Field0`.
+fn field_enum(
+    input: &DeriveInput,
+    docs_ty: &DocType,
+    trait_ident: &Ident,
+    field_idents: &[Option<&Ident>],
+    documented_module_path: &Path,
+) -> TokenStream {
+    let ident = &input.ident;
+    let vis = &input.vis;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let enum_ident = format_ident!("{ident}Field");
+
+    let variants = field_idents
+        .iter()
+        .enumerate()
+        .map(|(i, field_ident)| match field_ident {
+            Some(field_ident) => format_ident!(
+                "{}",
+                field_ident.unraw().to_string().to_case(Case::Pascal),
+                span = field_ident.span()
+            ),
+            None => format_ident!("Field{i}"),
+        })
+        .collect::<Vec<_>>();
+    let indices = 0..variants.len();
+    let enum_doc = format!("The fields of [`{ident}`], for looking up their docs with [`{ident}::get_field_docs_typed`].");
+
+    quote! {
+        #[doc = #enum_doc]
+        #[allow(dead_code)]
+        #[derive(::core::clone::Clone, ::core::marker::Copy, ::core::fmt::Debug, ::core::cmp::PartialEq, ::core::cmp::Eq, ::core::hash::Hash)]
+        #vis enum #enum_ident {
+            #(#variants),*
+        }
+
+        #[automatically_derived]
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Get the docs of a field, checked at compile time.
+            #[allow(dead_code)]
+            #vis const fn get_field_docs_typed(field: #enum_ident) -> #docs_ty {
+                match field {
+                    #(#enum_ident::#variants => <Self as #documented_module_path::#trait_ident>::FIELD_DOCS[#indices],)*
+                }
+            }
+        }
+    }
+}
+
+/// Render a type as it would usually be written, e.g. `Vec<&'a str>` rather
+/// than the token stream's `Vec < & 'a str >`.
+fn type_to_string(ty: &Type) -> String {
+    const REPLACEMENTS: [(&str, &str); 12] = [
+        (" :: ", "::"),
+        (":: ", "::"),
+        (" <", "<"),
+        ("< ", "<"),
+        (" >", ">"),
+        (" ,", ","),
+        (" ;", ";"),
+        ("& ", "&"),
+        ("( ", "("),
+        (" )", ")"),
+        ("[ ", "["),
+        (" ]", "]"),
+    ];
+    let mut rendered = ty.to_token_stream().to_string();
+    for (from, to) in REPLACEMENTS {
+        rendered = rendered.replace(from, to);
+    }
+    rendered
+}
+
+/// Render a visibility as written, e.g. `pub(crate)`. Private is empty.
+fn vis_to_string(vis: &Visibility) -> String {
+    match vis {
+        Visibility::Public(_) => "pub".into(),
+        Visibility::Restricted(VisRestricted { in_token, path, .. }) => {
+            let path = path
+                .segments
+                .iter()
+                .map(|segment| segment.ident.to_string())
+                .collect::<Vec<_>>()
+                .join("::");
+            let path = if path.is_empty() { "crate".into() } else { path };
+            let in_prefix = if in_token.is_some() { "in " } else { "" };
+            format!("pub({in_prefix}{path})")
+        }
+        Visibility::Inherited => String::new(),
+    }
+}
+
+/// Implementation of `DocumentedVariantFields`.
+pub fn documented_variant_fields_impl(input: DeriveInput) -> syn::Result<TokenStream> {
+    let docs_ty = DocType::OptStr;
+    let ident = &input.ident;
+    let doc_macros = track_input_doc_macros(&input);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // `#[documented_variant_fields(...)]` on container type
+    #[cfg(not(feature = "customise"))]
+    let base_config = DeriveConfig::default();
+    #[cfg(feature = "customise")]
+    let base_config = get_customisations_from_attrs(&input.attrs, "documented_variant_fields")
+        .map(|c| DeriveConfig::default().with_customisations(c))?;
+    let documented_module_path = crate_module_path(base_config.crate_path.as_ref());
+
+    if base_config.compact || base_config.markdown_reference {
+        Err(Error::new(
+            input.ident.span(),
+            "compact and markdown_reference are not applicable to DocumentedVariantFields",
+        ))?
+    }
+
+    let variants = match input.data {
+        Data::Enum(DataEnum { variants, .. }) => Ok(variants),
+        Data::Struct(DataStruct { struct_token, .. }) => Err(struct_token.span()),
+        Data::Union(DataUnion { union_token, .. }) => Err(union_token.span()),
+    }
+    .map_err(|span| {
+        Error::new(
+            span,
+            "DocumentedVariantFields can only be used on enums.\n\
+            For structs and unions, use DocumentedFields instead.",
+        )
+    })?;
+
+    let variant_field_docs = variants
+        .into_iter()
+        .map(|v| {
+            #[cfg(not(feature = "customise"))]
+            let variant_config = base_config.clone();
+            #[cfg(feature = "customise")]
+            let variant_config =
+                get_customisations_from_attrs(&v.attrs, "documented_variant_fields")
+                    .map(|c| base_config.with_customisations(c))?;
+
+            let field_docs = v
+                .fields
+                .into_iter()
+                .enumerate()
+                .map(|(i, f)| {
+                    #[cfg(not(feature = "customise"))]
+                    let config = variant_config.clone();
+                    #[cfg(feature = "customise")]
+                    let config =
+                        get_customisations_from_attrs(&f.attrs, "documented_variant_fields")
+                            .map(|c| variant_config.with_customisations(c))?;
+                    let name = f
+                        .ident
+                        .as_ref()
+                        .map_or_else(|| i.to_string(), Ident::to_string);
+                    get_processed_docs(&f.attrs, &config.docs_options())
+                        .and_then(|docs_opt| require_docs(docs_opt, config.require_all, &f))
+                        .and_then(|docs_opt| {
+                            docs_ty.docs_handler_opt()(docs_opt, config.default_value, &f)
+                        })
+                        .map(|docs| quote! { (#name, #docs) })
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+
+            let variant_name = v.ident.to_string();
+            Ok(quote! { (#variant_name, &[#(#field_docs),*]) })
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #documented_module_path::DocumentedVariantFields for #ident #ty_generics #where_clause {
+            const VARIANT_FIELD_DOCS: &'static [(&'static str, &'static [(&'static str, ::core::option::Option<&'static str>)])] = &[
+                #(#variant_field_docs),*
+            ];
+        }
+        #doc_macros
+    })
+}
+
+/// The config of a field or variant, i.e. `base_config` with the
+/// customisations in the `attr_name` helper attributes among `attrs` applied.
+#[cfg_attr(not(feature = "customise"), allow(unused_variables))]
+fn field_config(
+    base_config: &DeriveFieldsConfig,
+    attrs: &[Attribute],
+    attr_name: &str,
+) -> syn::Result<DeriveFieldsConfig> {
+    #[cfg(not(feature = "customise"))]
+    let config = base_config.clone();
+    #[cfg(feature = "customise")]
+    let config = get_customisations_from_attrs(attrs, attr_name)
+        .map(|c| base_config.with_field_customisations(c))?;
+    Ok(config)
+}
+
+/// Implementation of `DocumentedSchema`.
+pub fn documented_schema_impl(input: DeriveInput) -> syn::Result<TokenStream> {
+    let docs_ty = DocType::OptStr;
+    let ident = &input.ident;
+    let doc_macros = track_input_doc_macros(&input);
+    let title = ident.to_string();
+
+    // `#[documented_schema(...)]` on container type
+    #[cfg(not(feature = "customise"))]
+    let base_config = DeriveFieldsConfig::default();
+    #[cfg(feature = "customise")]
+    let base_config = get_customisations_from_attrs(&input.attrs, "documented_schema")
+        .map(|c| DeriveFieldsConfig::default().with_base_customisations(c))?;
+    let documented_module_path = crate_module_path(base_config.crate_path.as_ref());
+    let schema_mod = quote! { #documented_module_path::json_schema };
+
+    let mut generics = input.generics.clone();
+    for param in generics.type_params_mut() {
+        param
+            .bounds
+            .push(parse_quote! { #schema_mod::DocumentedSchema });
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    if base_config.field_vis
+        || base_config.variants.is_some()
+        || base_config.with_types
+        || base_config.field_enum
+        || base_config.examples
+        || base_config.tags
+        || base_config.deprecation
+        || base_config.case_insensitive
+        || base_config.remote.is_some()
+        || base_config.require_all
+        || base_config.const_lookup
+    {
+        Err(Error::new(
+            input.ident.span(),
+            "field_vis, variants, with_types, field_enum, examples, tags, deprecation, \
+            case_insensitive, remote, require_all, and const_lookup are not applicable to \
+            DocumentedSchema",
+        ))?
+    }
+
+    let docs = get_processed_docs(&input.attrs, &base_config.docs_options())
+        .and_then(|docs_opt| docs_ty.docs_handler_opt()(docs_opt, None, &input))?;
+
+    let field_config = |attrs: &[Attribute], span: Span| -> syn::Result<DeriveFieldsConfig> {
+        let config = field_config(&base_config, attrs, "documented_schema")?;
+        if config.nested || config.flatten || config.with_types || !config.aliases.is_empty() {
+            Err(Error::new(
+                span,
+                "nested, flatten, with_types, and alias are not applicable to DocumentedSchema",
+            ))?
+        }
+        Ok(config)
+    };
+
+    let schema = match input.data {
+        Data::Struct(DataStruct { fields: Fields::Named(fields), .. }) => {
+            let properties = fields
+                .named
+                .into_iter()
+                .map(|f| {
+                    let config = field_config(&f.attrs, f.span())?;
+                    if config.skip {
+                        return Ok(None);
+                    }
+                    let docs = get_processed_docs(&f.attrs, &config.docs_options()).and_then(|docs_opt| {
+                        docs_ty.docs_handler_opt()(docs_opt, config.default_value, &f)
+                    })?;
+                    let name = field_name(f.ident, config.rename_mode);
+                    let ty = f.ty;
+                    Ok(Some(quote! {
+                        (
+                            #name,
+                            <#ty as #schema_mod::DocumentedSchema>::json_schema_in(#docs, definitions),
+                            <#ty as #schema_mod::DocumentedSchema>::REQUIRED,
+                        )
+                    }))
+                })
+                .filter_map(syn::Result::transpose)
+                .collect::<syn::Result<Vec<_>>>()?;
+            quote! { #schema_mod::object_schema(#title, description, &[#(#properties),*]) }
+        }
+        Data::Enum(DataEnum { variants, .. }) => {
+            let variants = variants
+                .into_iter()
+                .map(|v| {
+                    let config = field_config(&v.attrs, v.ident.span())?;
+                    if config.skip {
+                        return Ok(None);
+                    }
+                    if !matches!(v.fields, Fields::Unit) {
+                        Err(Error::new_spanned(
+                            &v.fields,
+                            "DocumentedSchema only supports unit variants",
+                        ))?
+                    }
+                    let docs = get_processed_docs(&v.attrs, &config.docs_options()).and_then(
+                        |docs_opt| docs_ty.docs_handler_opt()(docs_opt, config.default_value, &v),
+                    )?;
+                    let name = field_name(Some(v.ident), config.rename_mode);
+                    Ok(Some(quote! { (#name, #docs) }))
+                })
+                .filter_map(syn::Result::transpose)
+                .collect::<syn::Result<Vec<_>>>()?;
+            quote! { #schema_mod::enum_schema(#title, description, &[#(#variants),*]) }
+        }
+        Data::Struct(DataStruct { fields, .. }) => Err(Error::new_spanned(
+            fields,
+            "DocumentedSchema only supports structs with named fields",
+        ))?,
+        Data::Union(DataUnion { union_token, .. }) => Err(Error::new(
+            union_token.span(),
+            "DocumentedSchema cannot be used on unions",
+        ))?,
+    };
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #schema_mod::DocumentedSchema for #ident #ty_generics #where_clause {
+            fn json_schema_with_description(
+                description: ::core::option::Option<&str>,
+            ) -> ::std::string::String {
+                let mut definitions = #schema_mod::Definitions::root::<Self>();
+                let schema = <Self as #schema_mod::DocumentedSchema>::json_schema_in(
+                    description,
+                    &mut definitions,
+                );
+                definitions.complete(schema)
+            }
+
+            fn json_schema_in(
+                description: ::core::option::Option<&str>,
+                definitions: &mut #schema_mod::Definitions,
+            ) -> ::std::string::String {
+                definitions.schema::<Self>(#title, description, |definitions, description| {
+                    let description = description.or(#docs);
+                    #schema
+                })
             }
         }
+        #doc_macros
+    })
+}
+
+/// Implementation of `DocumentedTree`.
+pub fn documented_tree_impl(input: DeriveInput) -> syn::Result<TokenStream> {
+    let docs_ty = DocType::OptStr;
+    let ident = &input.ident;
+    let doc_macros = track_input_doc_macros(&input);
+    let name = ident.unraw().to_string();
+
+    // `#[documented_tree(...)]` on container type
+    #[cfg(not(feature = "customise"))]
+    let base_config = DeriveFieldsConfig::default();
+    #[cfg(feature = "customise")]
+    let base_config = get_customisations_from_attrs(&input.attrs, "documented_tree")
+        .map(|c| DeriveFieldsConfig::default().with_base_customisations(c))?;
+    let documented_module_path = crate_module_path(base_config.crate_path.as_ref());
+    let tree_mod = quote! { #documented_module_path::tree };
+
+    if base_config.field_vis
+        || base_config.variants.is_some()
+        || base_config.with_types
+        || base_config.field_enum
+        || base_config.examples
+        || base_config.tags
+        || base_config.deprecation
+        || base_config.require_all
+        || base_config.const_lookup
+    {
+        Err(Error::new(
+            input.ident.span(),
+            "field_vis, variants, with_types, field_enum, examples, tags, deprecation, \
+            require_all, and const_lookup are not applicable to DocumentedTree",
+        ))?
+    }
+
+    let docs = get_processed_docs(&input.attrs, &base_config.docs_options())
+        .and_then(|docs_opt| docs_ty.docs_handler_opt()(docs_opt, None, &input))?;
+
+    let field_config = |attrs: &[Attribute]| field_config(&base_config, attrs, "documented_tree");
+
+    let mut nested_tys = Vec::new();
+    // a node for each field, or `None` if skipped
+    let mut field_nodes = |fields: Fields| -> syn::Result<Vec<TokenStream>> {
+        let mut nodes = Vec::new();
+        for (i, f) in fields.into_iter().enumerate() {
+            let config = field_config(&f.attrs)?;
+            if config.skip {
+                continue;
+            }
+            let docs =
+                get_processed_docs(&f.attrs, &config.docs_options()).and_then(|docs_opt| {
+                    docs_ty.docs_handler_opt()(docs_opt, config.default_value, &f)
+                })?;
+            let name = field_name(f.ident, config.rename_mode).unwrap_or_else(|| i.to_string());
+            let children = if config.nested {
+                let ty = f.ty;
+                nested_tys.push(ty.clone());
+                quote! { <#ty as #tree_mod::DocumentedTree>::DOC_TREE.children }
+            } else {
+                quote! { &[] }
+            };
+            nodes.push(quote! {
+                #tree_mod::DocNode { name: #name, docs: #docs, children: #children }
+            });
+        }
+        Ok(nodes)
+    };
+
+    let children = match input.data {
+        Data::Struct(DataStruct { fields, .. }) => field_nodes(fields)?,
+        Data::Union(DataUnion { fields, .. }) => field_nodes(Fields::Named(fields))?,
+        Data::Enum(DataEnum { variants, .. }) => {
+            let mut nodes = Vec::new();
+            for v in variants {
+                let config = field_config(&v.attrs)?;
+                if config.skip {
+                    continue;
+                }
+                if config.nested {
+                    Err(Error::new_spanned(
+                        &v.ident,
+                        "nested is not applicable to enum variants, only to their fields",
+                    ))?
+                }
+                let docs =
+                    get_processed_docs(&v.attrs, &config.docs_options()).and_then(|docs_opt| {
+                        docs_ty.docs_handler_opt()(docs_opt, config.default_value, &v.ident)
+                    })?;
+                let name = field_name(Some(v.ident), config.rename_mode);
+                let children = field_nodes(v.fields)?;
+                nodes.push(quote! {
+                    #tree_mod::DocNode { name: #name, docs: #docs, children: &[#(#children),*] }
+                });
+            }
+            nodes
+        }
+    };
+
+    // nested field types must implement the trait too, which is not a given
+    // for generic ones
+    let mut generics = input.generics.clone();
+    if !nested_tys.is_empty() {
+        generics
+            .make_where_clause()
+            .predicates
+            .extend(nested_tys.iter().map(|ty| -> WherePredicate {
+                parse_quote! { #ty: #tree_mod::DocumentedTree }
+            }));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #tree_mod::DocumentedTree for #ident #ty_generics #where_clause {
+            const DOC_TREE: #tree_mod::DocNode = #tree_mod::DocNode {
+                name: #name,
+                docs: #docs,
+                children: &[#(#children),*],
+            };
+        }
+        #doc_macros
     })
 }
 
@@ -191,6 +1594,7 @@ pub fn documented_fields_impl(input: DeriveInput, docs_ty: DocType) -> syn::Resu
 pub fn documented_variants_impl(input: DeriveInput, docs_ty: DocType) -> syn::Result<TokenStream> {
     let trait_ident = docs_ty.trait_ident_for("DocumentedVariants");
     let ident = &input.ident;
+    let doc_macros = track_input_doc_macros(&input);
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
     // `#[documented_variants(...)]` on container type
@@ -199,6 +1603,7 @@ pub fn documented_variants_impl(input: DeriveInput, docs_ty: DocType) -> syn::Re
     #[cfg(feature = "customise")]
     let base_config = get_customisations_from_attrs(&input.attrs, "documented_variants")
         .map(|c| DeriveConfig::default().with_customisations(c))?;
+    let documented_module_path = crate_module_path(base_config.crate_path.as_ref());
 
     let variants = match input.data {
         Data::Enum(DataEnum { variants, .. }) => Ok(variants),
@@ -221,21 +1626,74 @@ pub fn documented_variants_impl(input: DeriveInput, docs_ty: DocType) -> syn::Re
             #[cfg(feature = "customise")]
             let config = get_customisations_from_attrs(&v.attrs, "documented_variants")
                 .map(|c| base_config.with_customisations(c))?;
-            get_docs(&v.attrs, config.trim)
-                .and_then(|docs_opt| docs_ty.docs_handler_opt()(docs_opt, config.default_value, &v))
-                .map(|docs| (v.ident, v.fields, docs))
+            let docs_opt = get_processed_docs(&v.attrs, &config.docs_options())
+                .and_then(|docs_opt| require_docs(docs_opt, config.require_all, &v))?;
+            let entry = reference_entry(&v, docs_opt.as_deref());
+            let docs = docs_ty.docs_handler_opt()(docs_opt, config.default_value, &v)?;
+            Ok((v.ident, v.fields, docs, entry))
         })
         .collect::<syn::Result<Vec<_>>>()?;
 
-    let match_arms = variants_docs
+    let reference_impl = if base_config.markdown_reference {
+        let vis = &input.vis;
+        let reference = variants_docs
+            .iter()
+            .map(|(_, _, _, entry)| entry.as_str())
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        quote! {
+            #[automatically_derived]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// A markdown reference of the variants of this type, with
+                /// their discriminants and docs.
+                #[allow(dead_code)]
+                #vis const MARKDOWN_REFERENCE: &'static str = #reference;
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let (variant_docs, lookup_keys) = variants_docs
+        .iter()
+        .enumerate()
+        .map(|(i, (ident, _, docs, _))| {
+            let name = ident.unraw().to_string();
+            (quote! { (#name, #docs) }, (name, i))
+        })
+        .unzip::<_, _, Vec<_>, Vec<_>>();
+    let get_index_body = index_lookup(
+        &lookup_keys,
+        quote! { variant_name.as_ref() },
+        &documented_module_path,
+    );
+
+    let variant_arms = variants_docs
         .into_iter()
-        .map(|(ident, fields, docs)| {
-            let pat = match fields {
-                Fields::Unit => quote! { Self::#ident },
-                Fields::Unnamed(_) => quote! { Self::#ident(..) },
-                Fields::Named(_) => quote! { Self::#ident{..} },
+        .map(|(ident, fields, _, _)| {
+            let shape_path = quote! { #documented_module_path::VariantShape };
+            let (pat, shape) = match fields {
+                Fields::Unit => (quote! { Self::#ident }, quote! { #shape_path::Unit }),
+                Fields::Unnamed(fields) => {
+                    let types = fields.unnamed.iter().map(|f| type_to_string(&f.ty));
+                    (
+                        quote! { Self::#ident(..) },
+                        quote! { #shape_path::Tuple(&[#(#types),*]) },
+                    )
+                }
+                Fields::Named(fields) => {
+                    let fields = fields.named.iter().map(|f| {
+                        let name = f.ident.as_ref().map(Ident::to_string);
+                        let ty = type_to_string(&f.ty);
+                        quote! { (#name, #ty) }
+                    });
+                    (
+                        quote! { Self::#ident{..} },
+                        quote! { #shape_path::Struct(&[#(#fields),*]) },
+                    )
+                }
             };
-            quote! { #pat => #docs, }
+            (pat, shape)
         })
         .collect::<Vec<_>>();
 
@@ -243,14 +1701,74 @@ pub fn documented_variants_impl(input: DeriveInput, docs_ty: DocType) -> syn::Re
     // because there isn't a way to get an enum's discriminant at compile time
     // if this becomes possible in the future, or alternatively you have a good workaround,
     // improvement suggestions are more than welcomed
-    Ok(quote! {
-        #[automatically_derived]
-        impl #impl_generics documented::#trait_ident for #ident #ty_generics #where_clause {
+    let (variant_methods_impl, index_impl) = if base_config.compact {
+        let index_match_arms = variant_arms
+            .iter()
+            .enumerate()
+            .map(|(i, (pat, _))| quote! { #pat => #i, });
+        let shapes = variant_arms.iter().map(|(_, shape)| shape);
+        // named after the trait, so that deriving both traits does not clash
+        let index_fn = format_ident!("__{}_index", trait_ident.to_string().to_case(Case::Snake));
+        let methods = quote! {
+            fn get_variant_docs(&self) -> #docs_ty {
+                <Self as #documented_module_path::#trait_ident>::VARIANT_DOCS[self.#index_fn()].1
+            }
+
+            fn get_variant_shape(&self) -> #documented_module_path::VariantShape {
+                const SHAPES: &[#documented_module_path::VariantShape] = &[#(#shapes),*];
+                SHAPES[self.#index_fn()]
+            }
+        };
+        let index_impl = quote! {
+            #[automatically_derived]
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// The index of this variant, in declaration order.
+                #[doc(hidden)]
+                fn #index_fn(&self) -> usize {
+                    match self {
+                        #(#index_match_arms)*
+                    }
+                }
+            }
+        };
+        (methods, index_impl)
+    } else {
+        // the docs are read from `VARIANT_DOCS`, so that they are only emitted once
+        let match_arms = variant_arms.iter().enumerate().map(|(i, (pat, _))| {
+            quote! { #pat => <Self as #documented_module_path::#trait_ident>::VARIANT_DOCS[#i].1, }
+        });
+        let shape_match_arms = variant_arms
+            .iter()
+            .map(|(pat, shape)| quote! { #pat => #shape, });
+        let methods = quote! {
             fn get_variant_docs(&self) -> #docs_ty {
                 match self {
                     #(#match_arms)*
                 }
             }
+
+            fn get_variant_shape(&self) -> #documented_module_path::VariantShape {
+                match self {
+                    #(#shape_match_arms)*
+                }
+            }
+        };
+        (methods, quote! {})
+    };
+
+    Ok(quote! {
+        #[automatically_derived]
+        impl #impl_generics #documented_module_path::#trait_ident for #ident #ty_generics #where_clause {
+            const VARIANT_DOCS: &'static [(&'static str, #docs_ty)] = &[#(#variant_docs),*];
+
+            fn __documented_get_variant_index<__Documented_T: ::core::convert::AsRef<str>>(variant_name: __Documented_T) -> ::core::option::Option<usize> {
+                #get_index_body
+            }
+
+            #variant_methods_impl
         }
+        #index_impl
+        #reference_impl
+        #doc_macros
     })
 }
@@ -0,0 +1,48 @@
+//! Implementation of the function-like macros.
+
+use std::{env, path::Path};
+
+use documented_build::{extract_features_file, FeatureDocs};
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::Error;
+
+pub fn cargo_features_docs_impl(input: TokenStream) -> syn::Result<TokenStream> {
+    if !input.is_empty() {
+        Err(Error::new_spanned(
+            input,
+            "cargo_features_docs! does not take any arguments",
+        ))?
+    }
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").map_err(|_| {
+        Error::new(
+            Span::call_site(),
+            "CARGO_MANIFEST_DIR is not set; cargo_features_docs! must be built by cargo",
+        )
+    })?;
+    let manifest_path = Path::new(&manifest_dir).join("Cargo.toml");
+    let features = extract_features_file(&manifest_path).map_err(|err| {
+        Error::new(
+            Span::call_site(),
+            format!("Failed to read {}: {err}", manifest_path.display()),
+        )
+    })?;
+
+    // reading the manifest ourselves hides it from the compiler, so include it
+    // too, to rebuild when it changes
+    let manifest_path_str = manifest_path.to_string_lossy();
+    let entries = features.into_iter().map(|FeatureDocs { name, docs }| {
+        let docs = match docs {
+            Some(docs) => quote! { ::core::option::Option::Some(#docs) },
+            None => quote! { ::core::option::Option::None },
+        };
+        quote! { (#name, #docs) }
+    });
+
+    Ok(quote! {{
+        const _: &str = ::core::include_str!(#manifest_path_str);
+        const FEATURES: &[(&str, ::core::option::Option<&str>)] = &[#(#entries),*];
+        FEATURES
+    }})
+}
@@ -1,6 +1,9 @@
 mod attr_impl;
 mod config;
 mod derive_impl;
+mod function_impl;
+#[cfg(feature = "serde-interop")]
+mod serde_interop;
 pub(crate) mod util;
 
 use proc_macro::TokenStream;
@@ -8,7 +11,11 @@ use syn::{parse_macro_input, Error};
 
 use crate::{
     attr_impl::docs_const_impl,
-    derive_impl::{documented_fields_impl, documented_impl, documented_variants_impl, DocType},
+    derive_impl::{
+        documented_fields_impl, documented_impl, documented_schema_impl, documented_tree_impl,
+        documented_variant_fields_impl, documented_variants_impl, DocType,
+    },
+    function_impl::cargo_features_docs_impl,
 };
 
 /// Derive proc-macro for `Documented` trait.
@@ -40,6 +47,45 @@ use crate::{
 /// assert_eq!(BornIn69::DOCS, doc_str);
 /// ```
 ///
+/// The docs of other types implementing `Documented` can be included with
+/// `{{include: path::to::Type}}` placeholders. They are expanded at compile
+/// time, but note that rustdoc still shows the placeholder as-is:
+///
+/// ```rust
+/// # use documented::Documented;
+/// /// Touch-move applies.
+/// #[derive(Documented)]
+/// struct OverTheBoard;
+///
+/// /// Blitz, 3+2.
+/// ///
+/// /// {{include: OverTheBoard}}
+/// #[derive(Documented)]
+/// struct Blitz;
+///
+/// assert_eq!(Blitz::DOCS, "Blitz, 3+2.\n\nTouch-move applies.");
+/// ```
+///
+/// Included docs are not processed any further, e.g. by `wrap` or `escape`
+/// (see below). Placeholders may also come from a file included with
+/// `#[doc = include_str!(...)]`; like the included types, the file is tracked
+/// by the compiler, so editing it triggers a rebuild.
+///
+/// The name and module path of the type are also available, e.g. for pairing
+/// the docs with the type's name at runtime:
+///
+/// ```rust
+/// # use documented::Documented;
+/// mod engines {
+///     /// Strong.
+///     #[derive(documented::Documented)]
+///     pub struct Stockfish<const THREADS: usize>;
+/// }
+///
+/// assert_eq!(engines::Stockfish::<4>::ITEM_NAME, "Stockfish");
+/// assert!(engines::Stockfish::<4>::MODULE_PATH.ends_with("::engines"));
+/// ```
+///
 /// # Configuration
 ///
 /// With the `customise` feature enabled, you can customise this macro's
@@ -62,6 +108,9 @@ use crate::{
 /// [`DocumentedVariants`], so it's probably not very useful here. But it could
 /// conceivably come in handy in some niche meta-programming contexts.
 ///
+/// `allow_empty` is a shorthand for `default = ""`, e.g. for adopting this
+/// macro incrementally. An explicit `default` takes precedence.
+///
 /// ## 2. disable line-trimming like so:
 ///
 /// ```rust
@@ -74,6 +123,620 @@ use crate::{
 /// assert_eq!(Frankly::DOCS, "     Terrible.");
 /// ```
 ///
+/// The start and end of each line can also be trimmed independently with
+/// `trim_start` and `trim_end`, which default to the value of `trim`:
+///
+/// ```rust
+/// # use documented::Documented;
+/// #[doc = "    Terrible.   "]
+/// #[derive(Documented)]
+/// #[documented(trim_start = false)]
+/// struct Frankly;
+///
+/// assert_eq!(Frankly::DOCS, "    Terrible.");
+/// ```
+///
+/// Like `trim`, these options are also available for the other derive macros
+/// (on the type and on each field or variant) and for
+/// [`docs_const`](macro@docs_const).
+///
+/// ## 3. also generate the unprocessed docs like so:
+///
+/// ```rust
+/// # use documented::Documented;
+/// ///     Only
+/// ///     moves.
+/// #[derive(Documented)]
+/// #[documented(also_raw)]
+/// struct Forced;
+///
+/// assert_eq!(Forced::DOCS, "Only\nmoves.");
+/// assert_eq!(Forced::DOCS_RAW, "     Only\n     moves.");
+/// ```
+///
+/// `DOCS_RAW` is an inherent associated constant with the same visibility as
+/// the type, containing the doc comments exactly as written (i.e. with none of
+/// the processing options applied).
+///
+/// ## 4. append a list of variants to the docs of an enum like so:
+///
+/// ```rust
+/// # use documented::Documented;
+/// /// Which side to play.
+/// #[derive(Documented)]
+/// #[documented(list_variants)]
+/// enum Side {
+///     /// Moves first.
+///     /// Slight advantage.
+///     White,
+///     /// Moves second.
+///     Black,
+///     Random,
+/// }
+///
+/// assert_eq!(
+///     Side::DOCS,
+///     "Which side to play.\n\n\
+///     Variants:\n\
+///     - White: Moves first.\n\
+///     - Black: Moves second.\n\
+///     - Random"
+/// );
+/// ```
+///
+/// Each variant is listed with the first line of its doc comments. The list is
+/// not appended to a default value set with `default`.
+///
+/// ## 5. re-wrap the docs to a fixed width like so:
+///
+/// ```rust
+/// # use documented::Documented;
+/// /// Castle early,
+/// /// castle often.
+/// ///
+/// /// Unless you are Magnus.
+/// #[derive(Documented)]
+/// #[documented(wrap = 16)]
+/// struct KingSafety;
+///
+/// assert_eq!(
+///     KingSafety::DOCS,
+///     "Castle early,\ncastle often.\n\nUnless you are\nMagnus."
+/// );
+/// ```
+///
+/// Each paragraph and list item is reflowed, i.e. its lines are joined before
+/// wrapping, while code blocks are kept as they are. Words longer than the
+/// width are kept on their own line. The list of variants
+/// from `list_variants` is not wrapped. This option is also available for the
+/// other derive macros (on the type and on each field or variant) and for
+/// [`docs_const`](macro@docs_const).
+///
+/// ## 6. escape the docs for embedding in another format like so:
+///
+/// ```rust
+/// # use documented::Documented;
+/// /// Play <b>e4</b> & say "best by test".
+/// #[derive(Documented)]
+/// #[documented(escape = "html")]
+/// struct Fischer;
+///
+/// assert_eq!(
+///     Fischer::DOCS,
+///     "Play &lt;b&gt;e4&lt;/b&gt; &amp; say &quot;best by test&quot;."
+/// );
+/// ```
+///
+/// Supported formats are `json` and `c_string`, which escape the docs for use
+/// inside a (quoted) string of the respective language, and `html`. A default
+/// value set with `default` is not escaped.
+///
+/// ## 7. skip leading lines or a leading heading like so:
+///
+/// ```rust
+/// # use documented::Documented;
+/// /// # Najdorf
+/// ///
+/// /// The Cadillac of openings.
+/// #[derive(Documented)]
+/// #[documented(strip_leading_heading)]
+/// struct Najdorf;
+///
+/// /// Opening: Dragon
+/// /// Sharp, and not for the faint-hearted.
+/// #[derive(Documented)]
+/// #[documented(skip_lines = 1)]
+/// struct Dragon;
+///
+/// assert_eq!(Najdorf::DOCS, "The Cadillac of openings.");
+/// assert_eq!(Dragon::DOCS, "Sharp, and not for the faint-hearted.");
+/// ```
+///
+/// Blank lines left at the start are removed as well. `skip_lines` is applied
+/// first, so both can be combined to strip a heading after some preamble.
+///
+/// ## 8. also implement `Display` with the docs like so:
+///
+/// ```rust
+/// # use documented::Documented;
+/// /// Never resign.
+/// #[derive(Documented)]
+/// #[documented(impl_display)]
+/// struct Advice;
+///
+/// assert_eq!(Advice.to_string(), "Never resign.");
+/// ```
+///
+/// Width and alignment flags are honoured, e.g. `{:>20}`. This option is only
+/// available for `Documented`, not `DocumentedOpt`.
+///
+/// ## 9. also generate a help text with a list of fields or variants like so:
+///
+/// ```rust
+/// # use documented::Documented;
+/// /// Start a new game.
+/// #[derive(Documented)]
+/// #[documented(help)]
+/// struct NewGame {
+///     /// Time per player.
+///     /// In minutes.
+///     time: u32,
+///     /// Whether to play as white.
+///     white: bool,
+///     variant: String,
+/// }
+///
+/// assert_eq!(
+///     NewGame::HELP,
+///     "Start a new game.\n\
+///     \n\
+///     Fields:\n  \
+///     time     Time per player.\n  \
+///     white    Whether to play as white.\n  \
+///     variant"
+/// );
+/// ```
+///
+/// The generated `HELP` constant has the same visibility as your type. Only the
+/// first line of the docs of each field or variant is included.
+///
+/// ## 10. apply Unicode normalization to the docs like so:
+///
+/// ```rust
+/// # use documented::Documented;
+/// #[doc = "Re\u{301}ti opening."]
+/// #[derive(Documented)]
+/// #[documented(normalize = "nfc")]
+/// struct Reti;
+///
+/// assert_eq!(Reti::DOCS, "R\u{e9}ti opening.");
+/// ```
+///
+/// Supported forms are `nfc`, `nfd`, `nfkc`, and `nfkd`. This option is also
+/// available for the other derive macros (on the type and on each field or
+/// variant) and for [`docs_const`](macro@docs_const).
+///
+/// ## 11. inherit the docs of the wrapped type of a newtype like so:
+///
+/// ```rust
+/// # use documented::Documented;
+/// /// A square on the board, e.g. `e4`.
+/// #[derive(Documented)]
+/// struct Square(String);
+///
+/// #[derive(Documented)]
+/// #[documented(inherit)]
+/// struct Target(Square);
+///
+/// assert_eq!(Target::DOCS, "A square on the board, e.g. `e4`.");
+/// ```
+///
+/// The wrapped type must implement the same trait (i.e. `Documented` or
+/// `DocumentedOpt`). Doc comments on the newtype itself still take priority.
+/// This option cannot be combined with `default`.
+///
+/// ## 12. document a type from another crate via a local mirror like so:
+///
+/// ```rust
+/// # use documented::Documented;
+/// mod engine_crate {
+///     pub struct Limits {
+///         pub depth: u8,
+///     }
+/// }
+///
+/// /// Limits on how long the engine may search.
+/// #[derive(Documented)]
+/// #[documented(remote = "engine_crate::Limits")]
+/// struct Limits {
+///     depth: u8,
+/// }
+///
+/// assert_eq!(Limits::DOCS, "Limits on how long the engine may search.");
+/// ```
+///
+/// Because of the orphan rule, the trait is implemented on the mirror, not on
+/// the remote type. The option only checks at compile time that the remote
+/// type exists and has all the fields (or variants) of the mirror. If the
+/// mirror is generic, the remote path must be given without generics.
+///
+/// ## 13. also generate the first paragraph of the docs like so:
+///
+/// ```rust
+/// # use documented::Documented;
+/// /// Play the best move in the position.
+/// ///
+/// /// Searches until the time limit is reached, then plays the move with the
+/// /// highest evaluation.
+/// #[derive(Documented)]
+/// #[documented(summary)]
+/// struct BestMove;
+///
+/// assert_eq!(BestMove::DOCS_SUMMARY, "Play the best move in the position.");
+/// ```
+///
+/// This generates an inherent constant `DOCS_SUMMARY`, e.g. for tooltips. It is
+/// taken after skipping leading lines and re-wrapping, but before appending a
+/// list of variants.
+///
+/// ## 14. also generate the docs split into lines or paragraphs like so:
+///
+/// ```rust
+/// # use documented::Documented;
+/// /// Play the best move in the position.
+/// ///
+/// /// Searches until the time limit is reached,
+/// /// then plays the move with the highest evaluation.
+/// #[derive(Documented)]
+/// #[documented(lines, paragraphs)]
+/// struct BestMove;
+///
+/// assert_eq!(BestMove::DOCS_LINES.len(), 4);
+/// assert_eq!(
+///     BestMove::DOCS_PARAGRAPHS,
+///     [
+///         "Play the best move in the position.",
+///         "Searches until the time limit is reached,\nthen plays the move with the highest evaluation.",
+///     ]
+/// );
+/// ```
+///
+/// This generates the inherent constants `DOCS_LINES` and `DOCS_PARAGRAPHS`
+/// respectively, of type `&'static [&'static str]`. Both are taken from the
+/// final docs, and are empty if there are none. Lines include blank lines,
+/// paragraphs do not.
+///
+/// ## 15. also generate the docs split into sections like so:
+///
+/// ```rust
+/// # use documented::Documented;
+/// /// Resign the game.
+/// ///
+/// /// # Safety
+/// ///
+/// /// Cannot be undone.
+/// #[derive(Documented)]
+/// #[documented(sections)]
+/// struct Resign;
+///
+/// assert_eq!(
+///     Resign::DOCS_SECTIONS,
+///     [("", "Resign the game."), ("Safety", "Cannot be undone.")]
+/// );
+/// ```
+///
+/// This generates an inherent constant `DOCS_SECTIONS`, of type
+/// `&'static [(&'static str, &'static str)]`. Each markdown heading (of any
+/// level) starts a new section, keyed by the text of the heading. Text before
+/// the first heading is keyed by an empty string. Lines starting with `#` in
+/// fenced code blocks are not considered headings.
+///
+/// ## 16. also generate the code blocks in the docs like so:
+///
+/// ````rust
+/// # use documented::Documented;
+/// /// Set up a position from FEN.
+/// ///
+/// /// ```text
+/// /// position fen 8/8/8/8/8/8/8/K1k5 w - - 0 1
+/// /// ```
+/// #[derive(Documented)]
+/// #[documented(examples)]
+/// struct Position;
+///
+/// assert_eq!(
+///     Position::DOCS_EXAMPLES,
+///     ["position fen 8/8/8/8/8/8/8/K1k5 w - - 0 1"]
+/// );
+/// ````
+///
+/// This generates an inherent constant `DOCS_EXAMPLES`, of type
+/// `&'static [&'static str]`, with the contents of each fenced code block. They
+/// are taken from the docs as written, so they keep their indentation
+/// regardless of `trim`.
+///
+/// ## 17. parse tags out of the docs like so:
+///
+/// ```rust
+/// # use documented::Documented;
+/// /// Time left on the clock.
+/// ///
+/// /// @units: milliseconds
+/// /// @since: 0.3
+/// #[derive(Documented)]
+/// #[documented(tags)]
+/// struct TimeLeft;
+///
+/// assert_eq!(TimeLeft::DOCS, "Time left on the clock.");
+/// assert_eq!(
+///     TimeLeft::DOCS_TAGS,
+///     [("units", "milliseconds"), ("since", "0.3")]
+/// );
+/// ```
+///
+/// Lines of the form `@name: value` are removed from the docs, and collected
+/// into an inherent constant `DOCS_TAGS`, of type
+/// `&'static [(&'static str, &'static str)]`. Tag names may contain
+/// alphanumeric characters, `_`, `-`, and `.`.
+///
+/// ## 18. strip markdown syntax from the docs like so:
+///
+/// ```rust
+/// # use documented::Documented;
+/// /// Play **the** [`Caro-Kann`](crate::CaroKann) if you want to be _solid_.
+/// #[derive(Documented)]
+/// #[documented(strip_markdown)]
+/// struct Advice;
+///
+/// assert_eq!(Advice::DOCS, "Play the Caro-Kann if you want to be solid.");
+/// ```
+///
+/// This removes headings, emphasis, inline code backticks, code fences, and
+/// links (including intra-doc links and link reference definitions), keeping
+/// only their text, like `process::Docs::strip_markdown` does at runtime.
+/// This option is also available for the other derive macros
+/// (on the type and on each field or variant) and for
+/// [`docs_const`](macro@docs_const).
+///
+/// ## 19. remove the common indentation instead of trimming each line like so:
+///
+/// ```rust
+/// # use documented::Documented;
+/// /// Moves, in order of preference:
+/// ///     1. Captures
+/// ///         (most valuable victim first)
+/// ///     2. Checks
+/// #[derive(Documented)]
+/// #[documented(dedent)]
+/// struct MoveOrder;
+///
+/// assert_eq!(
+///     MoveOrder::DOCS,
+///     "Moves, in order of preference:\n    1. Captures\n        (most valuable victim first)\n    2. Checks"
+/// );
+/// ```
+///
+/// This keeps nested indentation, e.g. in code blocks, that trimming would
+/// remove. It replaces trimming the start of each line, but the end is still
+/// trimmed unless `trim_end` (or `trim`) is disabled. This option is also
+/// available for the other derive macros (on the type and on each field or
+/// variant) and for [`docs_const`](macro@docs_const).
+///
+/// ## 20. join the lines of the docs with a custom separator like so:
+///
+/// ```rust
+/// # use documented::Documented;
+/// /// Offer a draw
+/// /// to your opponent.
+/// #[derive(Documented)]
+/// #[documented(separator = "\r\n")]
+/// struct OfferDraw;
+///
+/// assert_eq!(OfferDraw::DOCS, "Offer a draw\r\nto your opponent.");
+/// ```
+///
+/// Blank lines are kept as well, so a separator of `" "` does not preserve
+/// paragraph breaks (see `unwrap_lines` for that). For `Documented`, the
+/// separator is applied after all other processing except `escape`, and also
+/// to `DOCS_SUMMARY`, `DOCS_PARAGRAPHS`, and `DOCS_SECTIONS`. This option is
+/// also available for the other derive macros (on the type and on each field
+/// or variant) and for [`docs_const`](macro@docs_const).
+///
+/// ## 21. join soft-wrapped lines into paragraphs like so:
+///
+/// ```rust
+/// # use documented::Documented;
+/// /// Offer a draw
+/// /// to your opponent.
+/// ///
+/// /// They may decline.
+/// #[derive(Documented)]
+/// #[documented(unwrap_lines)]
+/// struct OfferDraw;
+///
+/// assert_eq!(
+///     OfferDraw::DOCS,
+///     "Offer a draw to your opponent.\n\nThey may decline."
+/// );
+/// ```
+///
+/// List items are joined separately, and code blocks (fenced or indented) are
+/// kept as they are, like with `wrap`. This option is also available for the
+/// other derive macros (on the type and on each field or variant) and for
+/// [`docs_const`](macro@docs_const).
+///
+/// ## 22. collapse or remove blank lines like so:
+///
+/// ```rust
+/// # use documented::Documented;
+/// /// Offer a draw.
+/// ///
+/// ///
+/// /// They may decline.
+/// #[derive(Documented)]
+/// #[documented(collapse_blank_lines)]
+/// struct OfferDraw;
+///
+/// /// Resign.
+/// ///
+/// /// This cannot be undone.
+/// #[derive(Documented)]
+/// #[documented(keep_blank_lines = false)]
+/// struct Resign;
+///
+/// assert_eq!(OfferDraw::DOCS, "Offer a draw.\n\nThey may decline.");
+/// assert_eq!(Resign::DOCS, "Resign.\nThis cannot be undone.");
+/// ```
+///
+/// Both options also remove blank lines at the start and end of the docs, and
+/// are also available for the other derive macros (on the type and on each
+/// field or variant) and for [`docs_const`](macro@docs_const).
+///
+/// ## 23. normalise line endings and expand tabs like so:
+///
+/// ```rust
+/// # use documented::Documented;
+/// #[doc = "Sicilian Defence\r\n\t1. e4 c5\r\n"]
+/// #[derive(Documented)]
+/// #[documented(trim = false, normalize_newlines, expand_tabs = 2)]
+/// struct Sicilian;
+///
+/// assert_eq!(Sicilian::DOCS, "Sicilian Defence\n  1. e4 c5\n");
+/// ```
+///
+/// `normalize_newlines` converts both `\r\n` and lone `\r` to `\n`, which is
+/// useful for docs included from files with Windows line endings. Each tab is
+/// replaced with exactly `expand_tabs` spaces, before any other processing.
+/// These options are also available for the other derive macros (on the type
+/// and on each field or variant) and for [`docs_const`](macro@docs_const).
+///
+/// ## 24. add a prefix or suffix like so:
+///
+/// ```rust
+/// # use documented::Documented;
+/// /// The first move.
+/// #[derive(Documented)]
+/// #[documented(prefix = "Opening: ", suffix = "\n\nSee the manual for details.")]
+/// struct KingsPawn;
+///
+/// assert_eq!(
+///     KingsPawn::DOCS,
+///     "Opening: The first move.\n\nSee the manual for details."
+/// );
+/// ```
+///
+/// The prefix and suffix are added after all other processing except
+/// `separator`, and only if the item has docs. They are also available for
+/// the other derive macros (on the type and on each field or variant) and for
+/// [`docs_const`](macro@docs_const).
+///
+/// ## 25. interpolate environment variables like so:
+///
+/// ```rust
+/// # use documented::Documented;
+/// /// Engine {CARGO_PKG_NAME} v{CARGO_PKG_VERSION}, configured with `{{NAME}}`.
+/// #[derive(Documented)]
+/// #[documented(interpolate)]
+/// struct About;
+///
+/// assert_eq!(
+///     About::DOCS,
+///     concat!(
+///         "Engine ",
+///         env!("CARGO_PKG_NAME"),
+///         " v",
+///         env!("CARGO_PKG_VERSION"),
+///         ", configured with `{NAME}`."
+///     )
+/// );
+/// ```
+///
+/// Placeholders are names made of uppercase ASCII letters, digits, and
+/// underscores, and are substituted when the macro is expanded, before any
+/// other processing. It is a compile error for such a variable to be
+/// undefined. Other braces are kept as they are, and `{{NAME}}` is kept as
+/// `{NAME}`. Note that only changes to variables set by Cargo, like
+/// `CARGO_PKG_VERSION`, reliably cause the docs to be regenerated.
+///
+/// This option is also available for the other derive macros (on the type and
+/// on each field or variant) and for [`docs_const`](macro@docs_const).
+///
+/// ## 26. also generate the deprecation note like so:
+///
+/// ```rust
+/// # use documented::Documented;
+/// /// The Grob opening.
+/// #[derive(Documented)]
+/// #[documented(deprecation)]
+/// #[deprecated(note = "Nobody plays 1. g4 seriously")]
+/// struct Grob;
+///
+/// /// The Ruy Lopez.
+/// #[derive(Documented)]
+/// #[documented(deprecation)]
+/// struct RuyLopez;
+///
+/// # #[allow(deprecated)]
+/// assert_eq!(Grob::DEPRECATION, Some("Nobody plays 1. g4 seriously"));
+/// assert_eq!(RuyLopez::DEPRECATION, None);
+/// ```
+///
+/// This generates an inherent constant `DEPRECATION` with the note of the
+/// `#[deprecated]` attribute, which is empty if there is no note, or `None`
+/// if the type is not deprecated.
+///
+/// ## 27. take the docs from another attribute
+///
+/// With `source = "describe"`, the docs are taken from `#[describe = "..."]`
+/// attributes instead of the doc comments. See
+/// [`docs_const`](macro@docs_const#7-take-the-docs-from-another-attribute-like-so)
+/// for details. Note that derive macros cannot remove attributes, so the
+/// attributes must be accepted by another macro on the type, e.g. as a helper
+/// attribute of your own derive.
+///
+/// ## 28. set the path to this crate when it is re-exported like so:
+///
+/// ```rust
+/// mod chess_engine {
+///     pub use documented;
+/// }
+///
+/// use chess_engine::documented::Documented;
+///
+/// /// The Sicilian Defence.
+/// #[derive(Documented)]
+/// #[documented(crate = "chess_engine::documented")]
+/// struct Sicilian;
+///
+/// assert_eq!(Sicilian::DOCS, "The Sicilian Defence.");
+/// ```
+///
+/// By default, the generated code refers to this crate as `::documented`, which
+/// does not resolve when it is only available through a re-export from another
+/// crate. This option is also available for the other derive macros (on the
+/// type only).
+///
+/// ## 29. also generate the docs as an inherent constant like so:
+///
+/// ```rust
+/// mod openings {
+///     use documented::Documented;
+///
+///     /// The Queen's Gambit.
+///     #[derive(Documented)]
+///     #[documented(inherent)]
+///     pub struct QueensGambit;
+/// }
+///
+/// // no need to import `Documented`
+/// assert_eq!(openings::QueensGambit::DOCS, "The Queen's Gambit.");
+/// ```
+///
+/// This generates an inherent constant `DOCS` with the same value as the trait
+/// constant, so that the docs can be accessed without the trait in scope. It
+/// cannot be used when both `Documented` and `DocumentedOpt` are derived on the
+/// same type, as their constants would clash.
+///
 /// If there are other configuration options you wish to have, please submit an
 /// issue or a PR.
 #[cfg_attr(not(feature = "customise"), proc_macro_derive(Documented))]
@@ -143,17 +806,45 @@ pub fn documented_opt(input: TokenStream) -> TokenStream {
 /// assert_eq!(BornIn69::get_field_docs("explosive"), Ok("Before what?"));
 /// assert_eq!(
 ///     BornIn69::get_field_docs("gotcha"),
-///     Err(Error::NoSuchField("gotcha".to_string()))
+///     Err(Error::NoSuchField {
+///         name: "gotcha".to_string(),
+///         suggestion: None,
+///     })
 /// );
 /// ```
 ///
+/// With the `serde-interop` feature enabled, the names serde uses for each
+/// field (according to `#[serde(rename)]`, `#[serde(rename_all)]`, and
+/// `#[serde(alias)]`) are accepted by `get_field_docs` too, in addition to
+/// the names in `FIELD_NAMES`. This only applies to types or fields with
+/// `#[serde(...)]` attributes, as the macro cannot tell whether serde is
+/// derived otherwise.
+///
+/// Search aliases for rustdoc (i.e. `#[doc(alias = "...")]`) are accepted by
+/// `get_field_docs` too, unless another field already has that name or alias:
+///
+/// ```rust
+/// # use documented::DocumentedFields;
+/// #[derive(DocumentedFields)]
+/// struct Clock {
+///     /// Seconds added per move.
+///     #[doc(alias = "inc", alias("bonus"))]
+///     increment: u32,
+/// }
+///
+/// assert_eq!(Clock::get_field_docs("inc"), Ok("Seconds added per move."));
+/// assert_eq!(Clock::get_field_docs("bonus"), Ok("Seconds added per move."));
+/// ```
+///
 /// # Configuration
 ///
 /// With the `customise` feature enabled, you can customise this macro's
 /// behaviour using the `#[documented_fields(...)]` attribute. Note that this
 /// attribute works on both the container and each individual field, with the
 /// per-field configurations overriding container configurations, which
-/// override the default.
+/// override the default. It can also be applied conditionally with
+/// `cfg_attr`, e.g. `#[cfg_attr(feature = "kebab", documented_fields(rename_all
+/// = "kebab-case"))]`.
 ///
 /// Currently, you can:
 ///
@@ -248,9 +939,407 @@ pub fn documented_opt(input: TokenStream) -> TokenStream {
 ///     fried_liver: bool,
 /// }
 ///
-/// assert_eq!(Frankly::FIELD_DOCS, ["     Delicious.", "I'm vegan."]);
+/// assert_eq!(Frankly::FIELD_DOCS, ["     Delicious.", "I'm vegan."]);
+/// ```
+///
+/// ## 5. also generate the declared visibility of each field like so:
+///
+/// ```rust
+/// # use documented::DocumentedFields;
+/// #[derive(DocumentedFields)]
+/// #[documented_fields(field_vis)]
+/// pub struct Engine {
+///     /// Public.
+///     pub depth: u8,
+///     /// Crate-visible.
+///     pub(crate) threads: u8,
+///     /// Private.
+///     hash: u32,
+/// }
+///
+/// assert_eq!(Engine::FIELD_VIS, ["pub", "pub(crate)", ""]);
+/// ```
+///
+/// `FIELD_VIS` is an inherent associated constant with the same visibility as
+/// the type. This option is only applicable to structs and unions, since enum
+/// variants have no visibility of their own.
+///
+/// ## 6. opt in to documenting the variants of an enum like so:
+///
+/// ```rust
+/// # use documented::DocumentedFields;
+/// #[derive(DocumentedFields)]
+/// #[documented_fields(variants)]
+/// enum Result {
+///     /// 1-0.
+///     WhiteWins,
+///     /// 0-1.
+///     BlackWins,
+/// }
+///
+/// assert_eq!(Result::FIELD_DOCS, ["1-0.", "0-1."]);
+/// ```
+///
+/// Without this option, deriving on an enum still works, but emits a
+/// deprecation warning, since [`DocumentedVariants`] is usually what you want.
+/// The warning is emitted even without the `customise` feature, in which case
+/// switching to `DocumentedVariants` is the way to avoid it. Setting
+/// `variants = false` turns it into an error instead.
+///
+/// ## 7. allow looking up nested fields by path like so:
+///
+/// ```rust
+/// # use documented::DocumentedFields;
+/// #[derive(DocumentedFields)]
+/// struct Game {
+///     /// The clock.
+///     #[documented_fields(nested)]
+///     clock: Clock,
+/// }
+///
+/// #[derive(DocumentedFields)]
+/// struct Clock {
+///     /// Seconds added per move.
+///     increment: u32,
+/// }
+///
+/// assert_eq!(
+///     Game::get_field_docs_by_path(["clock", "increment"]),
+///     Ok("Seconds added per move.")
+/// );
+/// assert_eq!(
+///     Game::get_field_docs_by_path("clock".split('.')),
+///     Ok("The clock.")
+/// );
+/// ```
+///
+/// The type of a nested field must implement the same trait (i.e.
+/// `DocumentedFields` or `DocumentedFieldsOpt`).
+///
+/// ## 8. append the type of each field to its docs like so:
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use documented::DocumentedFields;
+/// #[derive(DocumentedFields)]
+/// #[documented_fields(with_types)]
+/// struct TimeControl {
+///     /// Time per player.
+///     base: Duration,
+///     /// Seconds added per move.
+///     #[documented_fields(with_types = false)]
+///     increment: u32,
+/// }
+///
+/// assert_eq!(
+///     TimeControl::get_field_docs("base"),
+///     Ok("Time per player. (type: Duration)")
+/// );
+/// assert_eq!(
+///     TimeControl::get_field_docs("increment"),
+///     Ok("Seconds added per move.")
+/// );
+/// ```
+///
+/// Types are written as they appear in the source. Default values set with
+/// `default` are not suffixed, and enum variants have no type to append.
+///
+/// ## 9. accept additional names when looking up a field like so:
+///
+/// ```rust
+/// # use documented::DocumentedFields;
+/// #[derive(DocumentedFields)]
+/// struct Clock {
+///     /// Seconds added per move.
+///     #[documented_fields(alias = "inc", alias = "bonus")]
+///     increment: u32,
+/// }
+///
+/// assert_eq!(Clock::get_field_docs("inc"), Ok("Seconds added per move."));
+/// assert_eq!(Clock::get_field_docs("bonus"), Ok("Seconds added per move."));
+/// assert_eq!(Clock::FIELD_NAMES, ["increment"]);
+/// ```
+///
+/// Aliases are used exactly as written, regardless of `rename_all`, and are
+/// not included in `FIELD_NAMES`.
+///
+/// ## 10. generate an enum of the fields for compile-time checked lookups like so:
+///
+/// ```rust
+/// # use documented::DocumentedFields;
+/// #[derive(DocumentedFields)]
+/// #[documented_fields(field_enum)]
+/// struct Opening {
+///     /// The first move.
+///     first_move: &'static str,
+///     /// The usual reply.
+///     reply: &'static str,
+/// }
+///
+/// assert_eq!(
+///     Opening::get_field_docs_typed(OpeningField::FirstMove),
+///     "The first move."
+/// );
+/// assert_eq!(
+///     Opening::get_field_docs_typed(OpeningField::Reply),
+///     "The usual reply."
+/// );
+/// ```
+///
+/// The enum is named after the type with a `Field` suffix and has the same
+/// visibility. Its variants are the field names in `PascalCase`, or `Field0`,
+/// `Field1`, ... for anonymous fields. As the enum is generated alongside the
+/// type, enable this option on only one of `DocumentedFields` and
+/// `DocumentedFieldsOpt`.
+///
+/// ## 11. leave out a field entirely like so:
+///
+/// ```rust
+/// # use documented::DocumentedFields;
+/// #[derive(DocumentedFields)]
+/// struct Account {
+///     /// Your public handle.
+///     handle: String,
+///     /// Never shown to anyone.
+///     #[documented_fields(skip)]
+///     password_hash: String,
+/// }
+///
+/// assert_eq!(Account::FIELD_NAMES, ["handle"]);
+/// assert_eq!(Account::FIELD_DOCS, ["Your public handle."]);
+/// assert!(Account::get_field_docs("password_hash").is_err());
+/// ```
+///
+/// Skipped fields do not count towards the indices of `FIELD_DOCS` and the
+/// other generated per-field constants.
+///
+/// ## 12. look up unknown names in the fields of a field's type like so:
+///
+/// ```rust
+/// # use documented::DocumentedFields;
+/// #[derive(DocumentedFields)]
+/// struct Tournament {
+///     /// Number of rounds.
+///     rounds: u8,
+///     /// Settings of every game.
+///     #[documented_fields(flatten)]
+///     game: Game,
+/// }
+///
+/// #[derive(DocumentedFields)]
+/// struct Game {
+///     /// Seconds added per move.
+///     increment: u32,
+/// }
+///
+/// assert_eq!(
+///     Tournament::get_field_docs("increment"),
+///     Ok("Seconds added per move.")
+/// );
+/// assert_eq!(Tournament::get_field_docs("game"), Ok("Settings of every game."));
+/// assert_eq!(Tournament::FIELD_NAMES, ["rounds", "game"]);
+/// ```
+///
+/// Like with `nested`, the type of a flattened field must implement the same
+/// trait. Names of its fields are only used if the container has no field or
+/// alias by the same name, and are not included in `FIELD_NAMES`. Paths passed
+/// to `get_field_docs_by_path` are resolved the same way.
+///
+/// ## 13. look up fields regardless of case like so:
+///
+/// ```rust
+/// # use documented::DocumentedFields;
+/// #[derive(DocumentedFields)]
+/// #[documented_fields(case_insensitive)]
+/// struct Engine {
+///     /// Number of search threads.
+///     threads: u16,
+/// }
+///
+/// assert_eq!(
+///     Engine::get_field_docs("THREADS"),
+///     Ok("Number of search threads.")
+/// );
+/// assert_eq!(Engine::FIELD_NAMES, ["threads"]);
+/// ```
+///
+/// Names and aliases are compared after converting them to lowercase, so they
+/// must still be unique when doing so. This option is only available on the
+/// container, and does not affect the lookups of `nested` or `flatten` fields.
+///
+/// ## 14. document the fields of a type from another crate like so:
+///
+/// ```rust
+/// # use documented::DocumentedFields;
+/// mod engine_crate {
+///     pub struct Limits {
+///         pub depth: u8,
+///         pub nodes: u64,
+///     }
+/// }
+///
+/// #[derive(DocumentedFields)]
+/// #[documented_fields(remote = "engine_crate::Limits")]
+/// struct Limits {
+///     /// Maximum search depth in plies.
+///     depth: u8,
+///     /// Maximum number of nodes to search.
+///     nodes: u64,
+/// }
+///
+/// assert_eq!(
+///     Limits::get_field_docs("depth"),
+///     Ok("Maximum search depth in plies.")
+/// );
+/// ```
+///
+/// Like with `Documented`, the trait is implemented on the mirror, and the
+/// remote type is checked to have all of its fields, including skipped ones.
+/// For enums, each variant of the mirror must exist in the remote type.
+///
+/// ## 15. also generate the code blocks in the docs of each field like so:
+///
+/// ````rust
+/// # use documented::DocumentedFields;
+/// #[derive(DocumentedFields)]
+/// #[documented_fields(examples)]
+/// struct Options {
+///     /// Size of the hash table in MB.
+///     ///
+///     /// ```text
+///     /// setoption name Hash value 256
+///     /// ```
+///     hash: u32,
+///     /// Number of search threads.
+///     threads: u16,
+/// }
+///
+/// assert_eq!(
+///     Options::FIELD_EXAMPLES,
+///     [&["setoption name Hash value 256"][..], &[]]
+/// );
+/// ````
+///
+/// This generates an inherent constant `FIELD_EXAMPLES`, of type
+/// `&'static [&'static [&'static str]]`, indexed by field order. This option is
+/// only available on the container.
+///
+/// ## 16. parse tags out of the docs of each field like so:
+///
+/// ```rust
+/// # use documented::DocumentedFields;
+/// #[derive(DocumentedFields)]
+/// #[documented_fields(tags)]
+/// struct Options {
+///     /// Size of the hash table.
+///     /// @units: MB
+///     /// @default: 16
+///     hash: u32,
+///     /// Number of search threads.
+///     threads: u16,
+/// }
+///
+/// assert_eq!(Options::get_field_docs("hash"), Ok("Size of the hash table."));
+/// assert_eq!(
+///     Options::FIELD_TAGS,
+///     [&[("units", "MB"), ("default", "16")][..], &[]]
+/// );
+/// ```
+///
+/// Like with `Documented`, tag lines are removed from the docs. This
+/// generates an inherent constant `FIELD_TAGS`, indexed by field order. This
+/// option is only available on the container.
+///
+/// ## 17. also generate the deprecation note of each field like so:
+///
+/// ```rust
+/// # use documented::DocumentedFields;
+/// #[derive(DocumentedFields)]
+/// #[documented_fields(deprecation)]
+/// #[allow(deprecated)]
+/// struct Options {
+///     /// Size of the hash table.
+///     hash: u32,
+///     /// Use the opening book.
+///     #[deprecated(since = "2.0.0", note = "Use `book_file` instead")]
+///     own_book: bool,
+///     /// Ponder on the opponent's time.
+///     #[deprecated]
+///     ponder: bool,
+/// }
+///
+/// assert_eq!(
+///     Options::FIELD_DEPRECATIONS,
+///     [None, Some("Use `book_file` instead"), Some("")]
+/// );
+/// ```
+///
+/// This generates an inherent constant `FIELD_DEPRECATIONS`, indexed by field
+/// order. Fields with `#[deprecated]` but without a note have an empty note.
+/// This option is only available on the container.
+///
+/// ## 18. use different docs for a field like so:
+///
+/// ```rust
+/// # use documented::DocumentedFields;
+/// #[derive(DocumentedFields)]
+/// struct Options {
+///     /// Size of the transposition table, see `TTable::resize`.
+///     #[documented_fields(doc = "Memory used for the engine's hash table, in MB.")]
+///     hash: u32,
+/// }
+///
+/// assert_eq!(
+///     Options::get_field_docs("hash"),
+///     Ok("Memory used for the engine's hash table, in MB.")
+/// );
+/// ```
+///
+/// The docs are processed like doc comments would be, e.g. trimmed. This
+/// option is only available on fields and variants.
+///
+/// ## 19. require every field to be documented like so:
+///
+/// ```rust,compile_fail
+/// # use documented::DocumentedFieldsOpt;
+/// #[derive(DocumentedFieldsOpt)]
+/// #[documented_fields(require_all, default = Some("TODO"))]
+/// struct Clock {
+///     /// Time left for white, in milliseconds.
+///     wtime: u64,
+///     btime: u64, // error: Missing doc comments
+/// }
+/// ```
+///
+/// A field without doc comments is then a compile error pointing at that
+/// field, even with `DocumentedFieldsOpt` or a default value. This option is
+/// only available on the container.
+///
+/// ## 20. look up the docs of a field in const contexts like so:
+///
+/// ```rust
+/// # use documented::DocumentedFields;
+/// #[derive(DocumentedFields)]
+/// #[documented_fields(const_lookup)]
+/// struct Limits {
+///     /// Search this many plies deep.
+///     depth: u8,
+///     /// Search this many positions.
+///     nodes: u64,
+/// }
+///
+/// const DEPTH_DOCS: Option<&str> = Limits::get_field_docs_const("depth");
+/// assert_eq!(DEPTH_DOCS, Some("Search this many plies deep."));
+/// assert_eq!(Limits::get_field_docs_const("mate"), None);
 /// ```
 ///
+/// This generates an inherent `const fn get_field_docs_const`, which returns
+/// `None` if no field has the name or if it has no doc comments. It accepts
+/// the same names as `get_field_docs`, except for those of flattened fields.
+/// `get_field_docs` then also uses a generated `match` instead of a perfect
+/// hash map. This option is only available on the container, and cannot be
+/// used with `case_insensitive`.
+///
 /// If there are other configuration options you wish to have, please
 /// submit an issue or a PR.
 #[cfg_attr(not(feature = "customise"), proc_macro_derive(DocumentedFields))]
@@ -297,6 +1386,55 @@ pub fn documented_fields_opt(input: TokenStream) -> TokenStream {
 /// assert_eq!(NeverPlay::F6.get_variant_docs(), "I fell out of my chair.");
 /// ```
 ///
+/// The shape of each variant is also available, e.g. for rendering variant
+/// signatures next to their docs:
+///
+/// ```rust
+/// use documented::{DocumentedVariants, VariantShape};
+///
+/// #[derive(DocumentedVariants)]
+/// enum Move {
+///     /// A normal move.
+///     Normal(u8, u8),
+///     /// A promotion.
+///     Promote { to: char },
+///     /// Resignation.
+///     Resign,
+/// }
+///
+/// let shape = Move::Normal(12, 28).get_variant_shape();
+/// assert_eq!(shape, VariantShape::Tuple(&["u8", "u8"]));
+/// assert_eq!(shape.signature("Normal"), "Normal(u8, u8)");
+/// assert_eq!(
+///     Move::Promote { to: 'Q' }.get_variant_shape().signature("Promote"),
+///     "Promote { to: char }"
+/// );
+/// assert_eq!(Move::Resign.get_variant_shape().arity(), 0);
+/// ```
+///
+/// The docs of all variants can be enumerated, or looked up by name, without
+/// an instance of each:
+///
+/// ```rust
+/// # use documented::DocumentedVariants;
+/// #[derive(DocumentedVariants)]
+/// enum Castle {
+///     /// Short castling.
+///     Kingside,
+///     /// Long castling.
+///     Queenside(bool),
+/// }
+///
+/// assert_eq!(
+///     Castle::VARIANT_DOCS,
+///     [("Kingside", "Short castling."), ("Queenside", "Long castling.")]
+/// );
+/// assert_eq!(
+///     Castle::get_variant_docs_by_name("Queenside"),
+///     Ok("Long castling.")
+/// );
+/// ```
+///
 /// # Configuration
 ///
 /// With the `customise` feature enabled, you can customise this macro's
@@ -328,6 +1466,9 @@ pub fn documented_fields_opt(input: TokenStream) -> TokenStream {
 /// );
 /// ```
 ///
+/// `allow_empty` is a shorthand for `default = ""`, e.g. for adopting this
+/// macro incrementally. An explicit `default` takes precedence.
+///
 /// ## 2. (selectively) disable line-trimming like so:
 ///
 /// ```rust
@@ -348,6 +1489,81 @@ pub fn documented_fields_opt(input: TokenStream) -> TokenStream {
 /// assert_eq!(Always::Retreat.get_variant_docs(), "Like a Frenchman.");
 /// ```
 ///
+/// ## 3. also generate a markdown reference of the variants like so:
+///
+/// ```rust
+/// # use documented::DocumentedVariants;
+/// #[derive(DocumentedVariants)]
+/// #[documented_variants(markdown_reference)]
+/// enum IllegalMove {
+///     /// The piece does not move like that.
+///     WrongShape = 1,
+///     /// The king would be in check.
+///     ///
+///     /// This includes discovered checks.
+///     SelfCheck = 2,
+/// }
+///
+/// assert_eq!(
+///     IllegalMove::MARKDOWN_REFERENCE,
+///     "## `WrongShape`\n\nCode: `1`\n\n\
+///     The piece does not move like that.\n\n## `SelfCheck`\n\n\
+///     Code: `2`\n\n\
+///     The king would be in check.\n\n\
+///     This includes discovered checks."
+/// );
+/// ```
+///
+/// The generated `MARKDOWN_REFERENCE` constant has the same visibility as your
+/// type. Each variant gets a heading, followed by its explicit discriminant
+/// (if any) and its full docs. This option is only available on the
+/// container.
+///
+/// ## 4. require every variant to be documented like so:
+///
+/// ```rust,compile_fail
+/// # use documented::DocumentedVariantsOpt;
+/// #[derive(DocumentedVariantsOpt)]
+/// #[documented_variants(require_all)]
+/// enum Outcome {
+///     /// 1-0
+///     WhiteWins,
+///     BlackWins, // error: Missing doc comments
+/// }
+/// ```
+///
+/// A variant without doc comments is then a compile error pointing at that
+/// variant, even with `DocumentedVariantsOpt` or a default value. This option
+/// is also available for `DocumentedVariantFields`, where it applies to the
+/// fields of each variant.
+///
+/// ## 5. look up the docs and shape of a variant by its index like so:
+///
+/// ```rust
+/// # use documented::{DocumentedVariants, VariantShape};
+/// #[derive(DocumentedVariants)]
+/// #[documented_variants(compact)]
+/// enum Piece {
+///     /// Moves one square in any direction.
+///     King,
+///     /// Moves any number of squares in any direction.
+///     Queen,
+///     /// Moves in an L shape.
+///     Knight { jumps: bool },
+/// }
+///
+/// assert_eq!(Piece::Queen.get_variant_docs(), "Moves any number of squares in any direction.");
+/// assert_eq!(
+///     Piece::Knight { jumps: true }.get_variant_shape(),
+///     VariantShape::Struct(&[("jumps", "bool")])
+/// );
+/// ```
+///
+/// This generates a single match from each variant to its index, in a hidden
+/// inherent method, and looks up the docs and shape of a variant by that index
+/// in a slice. Without this option, there is one match with the docs and one
+/// with the shape. This option is only available on the container.
+///
 /// If there are other configuration options you wish to have, please
 /// submit an issue or a PR.
 #[cfg_attr(not(feature = "customise"), proc_macro_derive(DocumentedVariants))]
@@ -375,6 +1591,337 @@ pub fn documented_variants_opt(input: TokenStream) -> TokenStream {
         .into()
 }
 
+/// Derive proc-macro for `DocumentedVariantFields` trait.
+///
+/// # Example
+///
+/// ```rust
+/// use documented::DocumentedVariantFields;
+///
+/// #[derive(DocumentedVariantFields)]
+/// enum Result {
+///     Win {
+///         /// By how much.
+///         margin: u8,
+///     },
+///     Draw(
+///         /// Agreed or not.
+///         bool,
+///         u8,
+///     ),
+///     Loss,
+/// }
+///
+/// assert_eq!(
+///     Result::VARIANT_FIELD_DOCS,
+///     [
+///         ("Win", &[("margin", Some("By how much."))][..]),
+///         ("Draw", &[("0", Some("Agreed or not.")), ("1", None)]),
+///         ("Loss", &[]),
+///     ]
+/// );
+/// ```
+///
+/// # Configuration
+///
+/// With the `customise` feature enabled, you can customise this macro's
+/// behaviour using the `#[documented_variant_fields(...)]` attribute. Note that
+/// this attribute works on the container, each individual variant, and each
+/// individual field, with the more specific configurations overriding the less
+/// specific ones, which override the default.
+///
+/// Currently, you can:
+///
+/// ## 1. set a default value when doc comments are absent like so:
+///
+/// ```rust
+/// # use documented::DocumentedVariantFields;
+/// #[derive(DocumentedVariantFields)]
+/// #[documented_variant_fields(default = Some("No comment."))]
+/// enum Interview {
+///     Question(String),
+///     #[documented_variant_fields(default = None)]
+///     Answer(String),
+/// }
+///
+/// assert_eq!(
+///     Interview::VARIANT_FIELD_DOCS,
+///     [
+///         ("Question", &[("0", Some("No comment."))][..]),
+///         ("Answer", &[("0", None)]),
+///     ]
+/// );
+/// ```
+///
+/// ## 2. (selectively) disable line-trimming like so:
+///
+/// ```rust
+/// # use documented::DocumentedVariantFields;
+/// #[derive(DocumentedVariantFields)]
+/// #[documented_variant_fields(trim = false)]
+/// enum Move {
+///     Castle {
+///         ///     Short or long.
+///         short: bool,
+///     },
+/// }
+///
+/// assert_eq!(
+///     Move::VARIANT_FIELD_DOCS,
+///     [("Castle", &[("short", Some("     Short or long."))][..])]
+/// );
+/// ```
+///
+/// If there are other configuration options you wish to have, please
+/// submit an issue or a PR.
+#[cfg_attr(not(feature = "customise"), proc_macro_derive(DocumentedVariantFields))]
+#[cfg_attr(
+    feature = "customise",
+    proc_macro_derive(DocumentedVariantFields, attributes(documented_variant_fields))
+)]
+pub fn documented_variant_fields(input: TokenStream) -> TokenStream {
+    documented_variant_fields_impl(parse_macro_input!(input))
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derive proc-macro for `DocumentedSchema` trait.
+///
+/// Generates a basic [JSON Schema](https://json-schema.org/) of the type, with
+/// its doc comments as `description`s. Supported are structs with named
+/// fields, whose field types must also implement `DocumentedSchema`, and enums
+/// with only unit variants. `Option` fields are not required.
+///
+/// Field types that derive `DocumentedSchema` themselves are defined once
+/// under `$defs` and referenced with `$ref`, with the field docs as the
+/// `description` of the reference. This makes recursive types work, with
+/// references to the type itself pointing to `#`.
+///
+/// # Example
+///
+/// ```rust
+/// use documented::DocumentedSchema;
+///
+/// /// A standard time control.
+/// #[derive(DocumentedSchema)]
+/// struct TimeControl {
+///     /// Base time in minutes.
+///     base: u32,
+///     /// Increment in seconds.
+///     increment: Option<u32>,
+/// }
+///
+/// assert_eq!(
+///     TimeControl::json_schema(),
+///     r#"{"description":"A standard time control.","title":"TimeControl","type":"object","#.to_owned()
+///         + r#""properties":{"base":{"description":"Base time in minutes.","type":"integer"},"#
+///         + r#""increment":{"description":"Increment in seconds.","type":"integer"}},"#
+///         + r#""required":["base"]}"#
+/// );
+/// ```
+///
+/// # Configuration
+///
+/// With the `customise` feature enabled, you can customise this macro's
+/// behaviour using the `#[documented_schema(...)]` attribute. Note that this
+/// attribute works on both the container and each individual field or
+/// variant, with the per-field configurations overriding container
+/// configurations, which override the default.
+///
+/// Currently, you can:
+///
+/// ## 1. set a different case convention for property names like so:
+///
+/// ```rust
+/// # use documented::DocumentedSchema;
+/// #[derive(DocumentedSchema)]
+/// #[documented_schema(rename_all = "lowercase")]
+/// enum Colour {
+///     White,
+///     #[documented_schema(rename = "b")]
+///     Black,
+/// }
+///
+/// assert_eq!(
+///     Colour::json_schema(),
+///     r#"{"title":"Colour","oneOf":[{"const":"white"},{"const":"b"}]}"#
+/// );
+/// ```
+///
+/// ## 2. set a default value when doc comments on fields or variants are absent
+/// like so:
+///
+/// ```rust
+/// # use documented::DocumentedSchema;
+/// #[derive(DocumentedSchema)]
+/// #[documented_schema(default = Some("Not documented."))]
+/// struct Player {
+///     rating: u16,
+/// }
+///
+/// assert_eq!(
+///     Player::json_schema(),
+///     r#"{"title":"Player","type":"object","#.to_owned()
+///         + r#""properties":{"rating":{"description":"Not documented.","type":"integer"}},"#
+///         + r#""required":["rating"]}"#
+/// );
+/// ```
+///
+/// ## 3. (selectively) disable line-trimming like so:
+///
+/// ```rust
+/// # use documented::DocumentedSchema;
+/// ///     Hanging indent.
+/// #[derive(DocumentedSchema)]
+/// #[documented_schema(trim = false)]
+/// enum Nothing {}
+///
+/// assert_eq!(
+///     Nothing::json_schema(),
+///     r#"{"description":"     Hanging indent.","title":"Nothing","oneOf":[]}"#
+/// );
+/// ```
+///
+/// ## 4. leave out a field or variant like so:
+///
+/// ```rust
+/// # use documented::DocumentedSchema;
+/// #[derive(DocumentedSchema)]
+/// struct Game {
+///     #[documented_schema(skip)]
+///     cache: Vec<u8>,
+/// }
+///
+/// assert_eq!(
+///     Game::json_schema(),
+///     r#"{"title":"Game","type":"object","properties":{},"required":[]}"#
+/// );
+/// ```
+///
+/// Options of the other derives that have no effect on the schema (e.g.
+/// `alias` or `nested`) are rejected:
+///
+/// ```rust,compile_fail
+/// # use documented::DocumentedSchema;
+/// #[derive(DocumentedSchema)]
+/// struct Game {
+///     #[documented_schema(alias = "pgn")]
+///     moves: String,
+/// }
+/// ```
+///
+/// If there are other configuration options you wish to have, please
+/// submit an issue or a PR.
+#[cfg_attr(not(feature = "customise"), proc_macro_derive(DocumentedSchema))]
+#[cfg_attr(
+    feature = "customise",
+    proc_macro_derive(DocumentedSchema, attributes(documented_schema))
+)]
+pub fn documented_schema(input: TokenStream) -> TokenStream {
+    documented_schema_impl(parse_macro_input!(input))
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Derive proc-macro for `DocumentedTree` trait.
+///
+/// The tree has a child for each field of a struct or union, or for each
+/// variant of an enum, which in turn has a child for each of its fields.
+/// Anonymous fields are named by their index. Missing docs are `None`.
+///
+/// # Example
+///
+/// ```rust
+/// use documented::{tree::DocNode, DocumentedTree};
+///
+/// /// Engine settings.
+/// #[derive(DocumentedTree)]
+/// struct Engine {
+///     /// Number of search threads.
+///     threads: u16,
+/// }
+///
+/// assert_eq!(
+///     Engine::DOC_TREE,
+///     DocNode {
+///         name: "Engine",
+///         docs: Some("Engine settings."),
+///         children: &[DocNode {
+///             name: "threads",
+///             docs: Some("Number of search threads."),
+///             children: &[],
+///         }],
+///     }
+/// );
+/// ```
+///
+/// # Configuration
+///
+/// With the `customise` feature enabled, you can customise this macro's
+/// behaviour using the `#[documented_tree(...)]` attribute. Note that this
+/// attribute works on both the container and each individual field or
+/// variant, with the per-field configurations overriding container
+/// configurations, which override the default.
+///
+/// The naming (`rename_all`, `rename`) and processing (`default`, `trim`,
+/// `normalize`) options work as for [`DocumentedFields`]. Additionally, you
+/// can:
+///
+/// ## 1. recurse into the type of a field like so:
+///
+/// ```rust
+/// # use documented::DocumentedTree;
+/// #[derive(DocumentedTree)]
+/// struct Settings {
+///     /// The engine.
+///     #[documented_tree(nested)]
+///     engine: Engine,
+/// }
+///
+/// #[derive(DocumentedTree)]
+/// struct Engine {
+///     /// Number of search threads.
+///     threads: u16,
+/// }
+///
+/// let threads = Settings::DOC_TREE.descendant(["engine", "threads"]).unwrap();
+/// assert_eq!(threads.docs, Some("Number of search threads."));
+///
+/// let mut reference = String::new();
+/// Settings::DOC_TREE.walk(|depth, node| {
+///     reference += &format!("{}{}\n", "  ".repeat(depth), node.name);
+/// });
+/// assert_eq!(reference, "Settings\n  engine\n    threads\n");
+/// ```
+///
+/// The type of a nested field must implement `DocumentedTree` too.
+///
+/// ## 2. leave out a field or variant like so:
+///
+/// ```rust
+/// # use documented::DocumentedTree;
+/// #[derive(DocumentedTree)]
+/// struct Settings {
+///     #[documented_tree(skip)]
+///     cache: Vec<u8>,
+/// }
+///
+/// assert!(Settings::DOC_TREE.children.is_empty());
+/// ```
+///
+/// If there are other configuration options you wish to have, please
+/// submit an issue or a PR.
+#[cfg_attr(not(feature = "customise"), proc_macro_derive(DocumentedTree))]
+#[cfg_attr(
+    feature = "customise",
+    proc_macro_derive(DocumentedTree, attributes(documented_tree))
+)]
+pub fn documented_tree(input: TokenStream) -> TokenStream {
+    documented_tree_impl(parse_macro_input!(input))
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
 /// Macro to extract the documentation on any item that accepts doc comments
 /// and store it in a const variable.
 ///
@@ -443,6 +1990,9 @@ pub fn documented_variants_opt(input: TokenStream) -> TokenStream {
 /// [`DocumentedVariants`], so it's probably not very useful here. But it could
 /// conceivably come in handy in some niche meta-programming contexts.
 ///
+/// `allow_empty` is a shorthand for `default = ""`, e.g. for adopting this
+/// macro incrementally. An explicit `default` takes precedence.
+///
 /// ## 4. disable line-trimming like so:
 ///
 /// ```rust
@@ -454,6 +2004,97 @@ pub fn documented_variants_opt(input: TokenStream) -> TokenStream {
 /// assert_eq!(TEST_CONST_DOCS, "     This is a test constant");
 /// ```
 ///
+/// ## 5. lazily post-process the docs at runtime like so:
+///
+/// ```rust
+/// # use documented::docs_const;
+/// /// Best by test.
+/// #[docs_const(lazy = |docs: &str| docs.to_uppercase())]
+/// fn best_by_test() {}
+///
+/// assert_eq!(*BEST_BY_TEST_DOCS, "BEST BY TEST.");
+/// ```
+///
+/// With this option, the macro emits a `static` of type `documented::LazyDocs`
+/// instead of a `&'static str` constant. It calls the given function once with
+/// the docs on first access and derefs to the resulting `String`, which is
+/// useful for processing that cannot be done in a const context (e.g.
+/// markdown rendering). The function must not capture any variables.
+///
+/// `LazyDocs` is referred to as `::documented::LazyDocs`. If this crate is only
+/// available through a re-export, set its path with `crate`, like for the
+/// derive macros:
+///
+/// ```rust
+/// mod chess_engine {
+///     pub use documented;
+/// }
+///
+/// use chess_engine::documented::docs_const;
+///
+/// /// Best by test.
+/// #[docs_const(lazy = |docs: &str| docs.to_uppercase(), crate = "chess_engine::documented")]
+/// fn best_by_test() {}
+///
+/// assert_eq!(*BEST_BY_TEST_DOCS, "BEST BY TEST.");
+/// ```
+///
+/// ## 6. merge the docs of all items in a module like so:
+///
+/// ```rust
+/// # use documented::docs_const;
+/// #[docs_const(merge_items)]
+/// mod endgame {
+///     //! Endgame techniques.
+///
+///     /// Build a bridge for your king.
+///     pub fn lucena() {}
+///
+///     /// Keep your king in front of the pawn.
+///     pub fn philidor() {}
+///
+///     pub fn undocumented() {}
+/// }
+///
+/// assert_eq!(
+///     ENDGAME_DOCS,
+///     "Endgame techniques.\n\
+///     \n\
+///     ### lucena\n\
+///     \n\
+///     Build a bridge for your king.\n\
+///     \n\
+///     ### philidor\n\
+///     \n\
+///     Keep your king in front of the pawn."
+/// );
+/// ```
+///
+/// Only the direct items of the module are included, in declaration order.
+/// This option requires the module's contents to be inline, i.e. not in a
+/// separate file. Note that the compiler does not allow both inner (`//!`) and
+/// outer (`///`) doc comments on a module with an attribute macro.
+///
+/// ## 7. take the docs from another attribute like so:
+///
+/// ```rust
+/// # use documented::docs_const;
+/// /// How the engine evaluates positions.
+/// #[docs_const(source = "describe")]
+/// #[describe = "Choose how strong the computer plays."]
+/// fn set_difficulty() {}
+///
+/// assert_eq!(SET_DIFFICULTY_DOCS, "Choose how strong the computer plays.");
+/// ```
+///
+/// `source` can be specified multiple times, and `source = "doc"` includes
+/// the doc comments, e.g. `source = "doc", source = "describe"`. The docs are
+/// taken from all matching `name = "..."` attributes in order of appearance.
+/// `docs_const` removes these attributes from the item (and the direct items
+/// of a module), so they need not be known to the compiler. This option is
+/// also available for the derive macros, but there the attributes must be
+/// accepted by another macro, e.g. as a helper attribute of your own derive.
+///
 /// ---
 ///
 /// Multiple option can be specified in a list like so:
@@ -470,3 +2111,43 @@ pub fn docs_const(#[allow(unused_variables)] attr: TokenStream, item: TokenStrea
 
     ts.unwrap_or_else(Error::into_compile_error).into()
 }
+
+/// Function-like proc-macro for getting the features of the current crate and
+/// their docs.
+///
+/// Expands to a `&'static [(&'static str, Option<&'static str>)]` of the
+/// features in the `[features]` table of the calling crate's `Cargo.toml`, in
+/// declaration order. Like with `document-features`, the docs of a feature are
+/// the comments starting with `##` directly above it.
+///
+/// # Example
+///
+/// With a `Cargo.toml` like this:
+///
+/// ```toml
+/// [features]
+/// default = ["engine"]
+/// ## Analyse positions with a chess engine.
+/// engine = []
+/// ```
+///
+/// The features can be listed like so:
+///
+/// ```rust,ignore
+/// use documented::cargo_features_docs;
+///
+/// const FEATURES: &[(&str, Option<&str>)] = cargo_features_docs!();
+/// assert_eq!(
+///     FEATURES,
+///     [
+///         ("default", None),
+///         ("engine", Some("Analyse positions with a chess engine.")),
+///     ]
+/// );
+/// ```
+#[proc_macro]
+pub fn cargo_features_docs(input: TokenStream) -> TokenStream {
+    cargo_features_docs_impl(input.into())
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
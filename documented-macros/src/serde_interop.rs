@@ -0,0 +1,196 @@
+//! Reading serde's naming attributes, so that docs can also be looked up by
+//! the names serde uses.
+
+use syn::{
+    ext::IdentExt, punctuated::Punctuated, Attribute, Error, Expr, ExprLit, Ident, Lit, LitStr,
+    Meta, MetaNameValue, Token,
+};
+
+/// The case conventions supported by serde's `rename_all`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    fn from_lit(lit: &LitStr) -> syn::Result<Self> {
+        match lit.value().as_str() {
+            "lowercase" => Ok(Self::Lower),
+            "UPPERCASE" => Ok(Self::Upper),
+            "PascalCase" => Ok(Self::Pascal),
+            "camelCase" => Ok(Self::Camel),
+            "snake_case" => Ok(Self::Snake),
+            "SCREAMING_SNAKE_CASE" => Ok(Self::ScreamingSnake),
+            "kebab-case" => Ok(Self::Kebab),
+            "SCREAMING-KEBAB-CASE" => Ok(Self::ScreamingKebab),
+            _ => Err(Error::new(lit.span(), "Unknown serde rename_all rule")),
+        }
+    }
+
+    /// Rename a variant, which is assumed to be written in `PascalCase`.
+    ///
+    /// Mirrors `serde_derive`, which is not necessarily what `convert_case`
+    /// does.
+    fn apply_to_variant(self, variant: &str) -> String {
+        match self {
+            Self::Pascal => variant.to_owned(),
+            Self::Lower => variant.to_ascii_lowercase(),
+            Self::Upper => variant.to_ascii_uppercase(),
+            Self::Camel => variant[..1].to_ascii_lowercase() + &variant[1..],
+            Self::Snake => {
+                let mut snake = String::new();
+                for (i, ch) in variant.char_indices() {
+                    if i > 0 && ch.is_uppercase() {
+                        snake.push('_');
+                    }
+                    snake.push(ch.to_ascii_lowercase());
+                }
+                snake
+            }
+            Self::ScreamingSnake => Self::Snake.apply_to_variant(variant).to_ascii_uppercase(),
+            Self::Kebab => Self::Snake.apply_to_variant(variant).replace('_', "-"),
+            Self::ScreamingKebab => Self::ScreamingSnake
+                .apply_to_variant(variant)
+                .replace('_', "-"),
+        }
+    }
+
+    /// Rename a field, which is assumed to be written in `snake_case`.
+    ///
+    /// Mirrors `serde_derive`, which is not necessarily what `convert_case`
+    /// does.
+    fn apply_to_field(self, field: &str) -> String {
+        match self {
+            Self::Lower | Self::Snake => field.to_owned(),
+            Self::Upper | Self::ScreamingSnake => field.to_ascii_uppercase(),
+            Self::Pascal => {
+                let mut pascal = String::new();
+                let mut capitalize = true;
+                for ch in field.chars() {
+                    if ch == '_' {
+                        capitalize = true;
+                    } else if capitalize {
+                        pascal.push(ch.to_ascii_uppercase());
+                        capitalize = false;
+                    } else {
+                        pascal.push(ch);
+                    }
+                }
+                pascal
+            }
+            Self::Camel => {
+                let pascal = Self::Pascal.apply_to_field(field);
+                pascal[..1].to_ascii_lowercase() + &pascal[1..]
+            }
+            Self::Kebab => field.replace('_', "-"),
+            Self::ScreamingKebab => Self::ScreamingSnake.apply_to_field(field).replace('_', "-"),
+        }
+    }
+}
+
+/// The serde naming attributes of a container.
+#[derive(Clone, Debug, Default)]
+pub struct SerdeNames {
+    rename_all: Vec<RenameRule>,
+    /// Whether the container has any serde attributes at all.
+    has_attrs: bool,
+}
+
+impl SerdeNames {
+    /// Read `#[serde(rename_all = "...")]` from the attributes of a container.
+    pub fn from_container_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut rename_all = Vec::new();
+        for meta in serde_metas(attrs)? {
+            if meta.path().is_ident("rename_all") {
+                for lit in rename_lits(&meta)? {
+                    rename_all.push(RenameRule::from_lit(&lit)?);
+                }
+            }
+        }
+        let has_attrs = attrs.iter().any(|attr| attr.path().is_ident("serde"));
+        Ok(Self { rename_all, has_attrs })
+    }
+
+    /// The names serde uses for a field or variant, including aliases.
+    ///
+    /// Empty unless the container or the field itself has serde attributes,
+    /// since otherwise the type might not use serde at all. Anonymous fields
+    /// are not named by serde, unless explicitly renamed.
+    pub fn names_of(
+        &self,
+        ident: Option<&Ident>,
+        attrs: &[Attribute],
+        is_variant: bool,
+    ) -> syn::Result<Vec<String>> {
+        if !self.has_attrs && !attrs.iter().any(|attr| attr.path().is_ident("serde")) {
+            return Ok(Vec::new());
+        }
+        let mut renamed = Vec::new();
+        let mut aliases = Vec::new();
+        for meta in serde_metas(attrs)? {
+            if meta.path().is_ident("rename") {
+                renamed.extend(rename_lits(&meta)?.iter().map(LitStr::value));
+            } else if meta.path().is_ident("alias") {
+                aliases.extend(rename_lits(&meta)?.iter().map(LitStr::value));
+            }
+        }
+
+        let mut names = if !renamed.is_empty() {
+            renamed
+        } else if let Some(ident) = ident {
+            let name = ident.unraw().to_string();
+            if self.rename_all.is_empty() {
+                vec![name]
+            } else {
+                self.rename_all
+                    .iter()
+                    .map(|rule| match is_variant {
+                        true => rule.apply_to_variant(&name),
+                        false => rule.apply_to_field(&name),
+                    })
+                    .collect()
+            }
+        } else {
+            Vec::new()
+        };
+        names.extend(aliases);
+        Ok(names)
+    }
+}
+
+/// All arguments of all `#[serde(...)]` attributes.
+fn serde_metas(attrs: &[Attribute]) -> syn::Result<Vec<Meta>> {
+    let mut metas = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        metas.extend(attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?);
+    }
+    Ok(metas)
+}
+
+/// The names given by either `name = "..."` or
+/// `name(serialize = "...", deserialize = "...")`.
+fn rename_lits(meta: &Meta) -> syn::Result<Vec<LitStr>> {
+    match meta {
+        Meta::NameValue(MetaNameValue { value, .. }) => Ok(vec![expect_lit_str(value)?]),
+        Meta::List(list) => list
+            .parse_args_with(Punctuated::<MetaNameValue, Token![,]>::parse_terminated)?
+            .iter()
+            .map(|nv| expect_lit_str(&nv.value))
+            .collect(),
+        Meta::Path(path) => Err(Error::new_spanned(path, "Expected a value")),
+    }
+}
+
+fn expect_lit_str(expr: &Expr) -> syn::Result<LitStr> {
+    match expr {
+        Expr::Lit(ExprLit { lit: Lit::Str(lit), .. }) => Ok(lit.clone()),
+        expr => Err(Error::new_spanned(expr, "Expected a string literal")),
+    }
+}
@@ -1,10 +1,168 @@
+pub use documented_build::get_docs;
+use documented_text::{dedent, strip_markdown, trim_lines, unwrap_lines, wrap};
+use std::fmt::Write;
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+
 use syn::{
-    parse_quote, spanned::Spanned, Attribute, Error, Expr, ExprLit, Item, Lit, Meta, Path,
-    Visibility,
+    parse_quote, punctuated::Punctuated, spanned::Spanned, Attribute, Data, DeriveInput, Error,
+    Expr, ExprLit, Fields, Item, Lit, LitStr, Meta, MetaNameValue, Path, Token, Visibility,
 };
 
-pub fn crate_module_path() -> Path {
-    parse_quote!(::documented)
+#[cfg(feature = "customise")]
+use unicode_normalization::UnicodeNormalization;
+
+#[cfg(feature = "customise")]
+use crate::config::derive::Normalization;
+use crate::config::{derive::DocsOptions, derive_documented::Escape};
+
+/// Extract the doc comments like [`get_docs`], then apply the other
+/// processing steps in `options`.
+pub fn get_processed_docs(
+    attrs: &[Attribute],
+    options: &DocsOptions,
+) -> syn::Result<Option<String>> {
+    let mut docs_opt = match &options.doc {
+        Some(doc) => Some(doc.clone()),
+        None => get_source_docs(attrs, &options.sources, false)?,
+    };
+    if options.interpolate {
+        docs_opt = docs_opt.map(|docs| interpolate(&docs, attrs)).transpose()?;
+    }
+    if options.normalize_newlines {
+        docs_opt = docs_opt.map(|docs| normalize_newlines(&docs));
+    }
+    if let Some(width) = options.expand_tabs {
+        docs_opt = docs_opt.map(|docs| docs.replace('\t', &" ".repeat(width)));
+    }
+    // dedenting replaces trimming the start of each line
+    if options.dedent {
+        docs_opt = docs_opt.map(|docs| dedent(&docs));
+    }
+    let trim_start = options.trim_start && !options.dedent;
+    if trim_start || options.trim_end {
+        docs_opt = docs_opt.map(|docs| trim_lines(&docs, trim_start, options.trim_end));
+    }
+    // normalization can only be requested with customisation
+    #[cfg(feature = "customise")]
+    if let Some(form) = options.normalize {
+        docs_opt = docs_opt.map(|docs| match form {
+            Normalization::Nfc => docs.nfc().collect(),
+            Normalization::Nfd => docs.nfd().collect(),
+            Normalization::Nfkc => docs.nfkc().collect(),
+            Normalization::Nfkd => docs.nfkd().collect(),
+        });
+    }
+    if options.strip_markdown {
+        docs_opt = docs_opt.map(|docs| strip_markdown(&docs));
+    }
+    if options.unwrap_lines {
+        docs_opt = docs_opt.map(|docs| unwrap_lines(&docs));
+    }
+    if let Some(columns) = options.wrap {
+        docs_opt = docs_opt.map(|docs| wrap(&docs, columns));
+    }
+    if options.collapse_blank_lines || !options.keep_blank_lines {
+        docs_opt = docs_opt.map(|docs| collapse_blank_lines(&docs, options.keep_blank_lines));
+    }
+    if let Some(prefix) = &options.prefix {
+        docs_opt = docs_opt.map(|docs| format!("{prefix}{docs}"));
+    }
+    if let Some(suffix) = &options.suffix {
+        docs_opt = docs_opt.map(|docs| format!("{docs}{suffix}"));
+    }
+    if let Some(separator) = &options.separator {
+        docs_opt = docs_opt.map(|docs| join_lines(&docs, separator));
+    }
+    Ok(docs_opt)
+}
+
+/// Extract the docs like [`get_docs`], but from the name-value attributes
+/// named in `sources` (e.g. `#[describe = "..."]`) instead, in order of
+/// appearance. Uses the doc comments if `sources` is empty.
+pub fn get_source_docs(
+    attrs: &[Attribute],
+    sources: &[String],
+    trim: bool,
+) -> syn::Result<Option<String>> {
+    if sources.is_empty() {
+        return get_docs(attrs, trim);
+    }
+    let source_attrs = attrs
+        .iter()
+        .filter(|attr| sources.iter().any(|source| attr.path().is_ident(source)))
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(name_value) => {
+                let mut attr = attr.clone();
+                attr.meta = Meta::NameValue(MetaNameValue {
+                    path: parse_quote!(doc),
+                    ..name_value.clone()
+                });
+                Some(attr)
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    get_docs(&source_attrs, trim)
+}
+
+/// Repeat the macro-valued attributes among `attrs`, such as
+/// `#[doc = include_str!("...")]`, as unnamed constants.
+///
+/// The docs are evaluated by [`get_docs`], which reads the included files and
+/// environment variables behind the compiler's back. Expanding the macros
+/// again in the output lets the compiler track them, so that changing one
+/// triggers a rebuild.
+pub fn track_doc_macros<'a>(attrs: impl IntoIterator<Item = &'a Attribute>) -> TokenStream {
+    let exprs = attrs.into_iter().filter_map(|attr| match &attr.meta {
+        Meta::NameValue(MetaNameValue { value: Expr::Macro(expr), .. })
+            if ["include_str", "concat", "env"]
+                .iter()
+                .any(|name| expr.mac.path.is_ident(name)) =>
+        {
+            Some(expr)
+        }
+        _ => None,
+    });
+    quote! {
+        #(const _: &str = #exprs;)*
+    }
+}
+
+/// [`track_doc_macros`] for the attributes of a type and all of its fields and
+/// variants.
+pub fn track_input_doc_macros(input: &DeriveInput) -> TokenStream {
+    fn fields_attrs(fields: &Fields) -> impl Iterator<Item = &Attribute> {
+        fields.iter().flat_map(|field| &field.attrs)
+    }
+    let mut attrs = input.attrs.iter().collect::<Vec<_>>();
+    match &input.data {
+        Data::Struct(data) => attrs.extend(fields_attrs(&data.fields)),
+        Data::Enum(data) => data.variants.iter().for_each(|variant| {
+            attrs.extend(&variant.attrs);
+            attrs.extend(fields_attrs(&variant.fields));
+        }),
+        Data::Union(data) => attrs.extend(data.fields.named.iter().flat_map(|field| &field.attrs)),
+    }
+    track_doc_macros(attrs)
+}
+
+/// Get the span of the first attribute whose tokens contain `needle`, falling
+/// back to the first doc attribute, so that errors about the contents of the
+/// docs point at the offending line instead of the whole item.
+pub fn attr_span_containing(attrs: &[Attribute], needle: &str) -> Span {
+    attrs
+        .iter()
+        .find(|attr| attr.to_token_stream().to_string().contains(needle))
+        .or_else(|| attrs.iter().find(|attr| attr.path().is_ident("doc")))
+        .map_or_else(Span::call_site, Spanned::span)
+}
+
+pub fn crate_module_path(custom: Option<&Path>) -> Path {
+    custom
+        .cloned()
+        .unwrap_or_else(|| parse_quote!(::documented))
 }
 
 pub fn get_vis_name_attrs(item: &Item) -> syn::Result<(Visibility, String, &[Attribute])> {
@@ -45,38 +203,387 @@ pub fn get_vis_name_attrs(item: &Item) -> syn::Result<(Visibility, String, &[Att
     }
 }
 
-pub fn get_docs(attrs: &[Attribute], trim: bool) -> syn::Result<Option<String>> {
-    let string_literals = attrs
-        .iter()
-        .filter_map(|attr| match attr.meta {
-            Meta::NameValue(ref name_value) if name_value.path.is_ident("doc") => {
-                Some(&name_value.value)
+/// Get the note of a `#[deprecated]` attribute, which is empty if there is
+/// none. Returns `None` if the item is not deprecated.
+pub fn get_deprecation(attrs: &[Attribute]) -> syn::Result<Option<String>> {
+    let Some(attr) = attrs.iter().find(|attr| attr.path().is_ident("deprecated")) else {
+        return Ok(None);
+    };
+    let note = match &attr.meta {
+        Meta::Path(_) => None,
+        Meta::NameValue(MetaNameValue {
+            value: Expr::Lit(ExprLit { lit: Lit::Str(note), .. }),
+            ..
+        }) => Some(note.value()),
+        Meta::NameValue(name_value) => Err(Error::new(
+            name_value.value.span(),
+            "Deprecation note is not a string literal",
+        ))?,
+        Meta::List(_) => {
+            let mut note = None;
+            attr.parse_nested_meta(|meta| {
+                let value = meta.value()?.parse::<LitStr>()?;
+                if meta.path.is_ident("note") {
+                    note = Some(value.value());
+                }
+                Ok(())
+            })?;
+            note
+        }
+    };
+    Ok(Some(note.unwrap_or_default()))
+}
+
+/// Get the search aliases in `#[doc(alias = "...")]` and
+/// `#[doc(alias("...", "..."))]` attributes.
+pub fn get_doc_aliases(attrs: &[Attribute]) -> syn::Result<Vec<LitStr>> {
+    let mut aliases = Vec::new();
+    for attr in attrs {
+        let Meta::List(list) = &attr.meta else {
+            continue;
+        };
+        if !list.path.is_ident("doc") {
+            continue;
+        }
+        for meta in list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)? {
+            match meta {
+                Meta::NameValue(MetaNameValue {
+                    path,
+                    value: Expr::Lit(ExprLit { lit: Lit::Str(alias), .. }),
+                    ..
+                }) if path.is_ident("alias") => aliases.push(alias),
+                Meta::List(list) if list.path.is_ident("alias") => aliases.extend(
+                    list.parse_args_with(Punctuated::<LitStr, Token![,]>::parse_terminated)?,
+                ),
+                _ => {}
             }
-            _ => None,
-        })
-        .map(|expr| match expr {
-            Expr::Lit(ExprLit { lit: Lit::Str(s), .. }) => Ok(s.value()),
-            other => Err(Error::new(
-                other.span(),
-                "Doc comment is not a string literal",
-            )),
-        })
-        .collect::<Result<Vec<_>, _>>()?;
+        }
+    }
+    Ok(aliases)
+}
 
-    if string_literals.is_empty() {
-        return Ok(None);
+/// Remove the first `skip_lines` lines of `docs`, then a markdown heading (e.g.
+/// `# Config`) if `strip_heading` is set and there is one. Blank lines left at
+/// the start are removed too.
+pub fn skip_leading(docs: &str, skip_lines: usize, strip_heading: bool) -> String {
+    let mut lines = docs.lines().skip(skip_lines).peekable();
+    let is_blank = |line: &&str| line.trim().is_empty();
+    while lines.next_if(is_blank).is_some() {}
+    if strip_heading && lines.next_if(|line| is_heading(line)).is_some() {
+        while lines.next_if(is_blank).is_some() {}
     }
+    lines.collect::<Vec<_>>().join("\n")
+}
 
-    let docs = if trim {
-        string_literals
-            .iter()
-            .flat_map(|lit| lit.split('\n').collect::<Vec<_>>())
-            .map(|line| line.trim().to_string())
-            .collect::<Vec<_>>()
-            .join("\n")
-    } else {
-        string_literals.join("\n")
+/// Squash runs of blank lines in `docs` into a single empty line, or remove
+/// them entirely if `keep` is not set. Blank lines at the start and end are
+/// always removed.
+pub fn collapse_blank_lines(docs: &str, keep: bool) -> String {
+    let mut lines = Vec::new();
+    let mut after_blank = false;
+    for line in docs.lines() {
+        if line.trim().is_empty() {
+            after_blank = true;
+            continue;
+        }
+        if after_blank && keep && !lines.is_empty() {
+            lines.push("");
+        }
+        after_blank = false;
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+/// Substitute each `{NAME}` placeholder in `docs`, where `NAME` consists of
+/// uppercase ASCII letters, digits, and underscores, with the value of the
+/// environment variable at compile time. `{{NAME}}` is kept as `{NAME}`.
+/// Errors point at the attribute in `attrs` containing the placeholder.
+fn interpolate(docs: &str, attrs: &[Attribute]) -> syn::Result<String> {
+    fn placeholder(s: &str) -> Option<&str> {
+        let end = s.find('}')?;
+        let name = &s[..end];
+        let is_name = !name.is_empty()
+            && !name.starts_with(|c: char| c.is_ascii_digit())
+            && name
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_');
+        is_name.then_some(name)
+    }
+
+    let mut out = String::with_capacity(docs.len());
+    let mut rest = docs;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        if let Some(name) = rest.strip_prefix("{{").and_then(placeholder) {
+            if rest[2 + name.len()..].starts_with("}}") {
+                write!(out, "{{{name}}}").unwrap();
+                rest = &rest[name.len() + 4..];
+                continue;
+            }
+        }
+        match placeholder(&rest[1..]) {
+            Some(name) => {
+                let value = std::env::var(name).map_err(|_| {
+                    Error::new(
+                        attr_span_containing(attrs, &format!("{{{name}}}")),
+                        format!("environment variable `{name}` not defined at compile time"),
+                    )
+                })?;
+                out.push_str(&value);
+                rest = &rest[name.len() + 2..];
+            }
+            None => {
+                out.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+/// Convert `\r\n` and lone `\r` line endings in `docs` to `\n`.
+fn normalize_newlines(docs: &str) -> String {
+    docs.replace("\r\n", "\n").replace('\r', "\n")
+}
+
+/// Join the lines of `docs` with `separator` instead of `\n`.
+pub fn join_lines(docs: &str, separator: &str) -> String {
+    docs.lines().collect::<Vec<_>>().join(separator)
+}
+
+/// The first paragraph of `docs`, i.e. everything before the first blank line.
+pub fn first_paragraph(docs: &str) -> String {
+    docs.lines()
+        .take_while(|line| !line.trim().is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The paragraphs of `docs`, i.e. the groups of lines separated by blank lines.
+pub fn paragraphs(docs: &str) -> Vec<String> {
+    let mut paragraphs = Vec::new();
+    let mut current = Vec::new();
+    for line in docs.lines() {
+        if line.trim().is_empty() {
+            if !current.is_empty() {
+                paragraphs.push(current.join("\n"));
+                current.clear();
+            }
+        } else {
+            current.push(line);
+        }
+    }
+    if !current.is_empty() {
+        paragraphs.push(current.join("\n"));
+    }
+    paragraphs
+}
+
+/// The sections of `docs`, each keyed by the text of the markdown heading it
+/// starts with. Text before the first heading is keyed by an empty string, and
+/// left out if blank. Headings inside fenced code blocks are ignored.
+pub fn sections(docs: &str) -> Vec<(String, String)> {
+    let mut sections = Vec::new();
+    let mut heading = String::new();
+    let mut current = Vec::new();
+    let mut in_code_block = false;
+    let mut push = |heading: &str, lines: &[&str]| {
+        let body = lines.join("\n").trim_matches('\n').to_owned();
+        if !heading.is_empty() || !body.trim().is_empty() {
+            sections.push((heading.to_owned(), body));
+        }
     };
+    for line in docs.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+        }
+        if !in_code_block && is_heading(line) {
+            push(&heading, &current);
+            heading = line.trim().trim_start_matches('#').trim().to_owned();
+            current.clear();
+        } else {
+            current.push(line);
+        }
+    }
+    push(&heading, &current);
+    sections
+}
+
+/// The contents of the fenced code blocks in `docs`, without the fences.
+///
+/// Lines are unindented by the indentation of the opening fence, so `docs`
+/// should not be trimmed.
+pub fn code_blocks(docs: &str) -> Vec<String> {
+    let mut blocks = Vec::new();
+    let mut current: Option<(usize, Vec<&str>)> = None;
+    for line in docs.lines() {
+        let content = line.trim_start();
+        let indent = line.len() - line.trim_start_matches(' ').len();
+        match current.take() {
+            None if content.starts_with("```") => current = Some((indent, Vec::new())),
+            None => {}
+            Some((_, lines)) if content.starts_with("```") => blocks.push(lines.join("\n")),
+            Some((fence_indent, mut lines)) => {
+                lines.push(&line[indent.min(fence_indent)..]);
+                current = Some((fence_indent, lines));
+            }
+        }
+    }
+    blocks
+}
+
+/// Remove all tag lines, e.g. `@default: 42`, from `docs`, and return the rest
+/// of the docs along with the tags in order.
+///
+/// Tag names consist of alphanumeric characters, `_`, `-`, and `.`. Returns
+/// `None` instead of the docs if there is nothing left but blank lines.
+pub fn extract_tags(docs: &str) -> (Option<String>, Vec<(String, String)>) {
+    let mut rest = Vec::new();
+    let mut tags = Vec::new();
+    for line in docs.lines() {
+        let tag = line
+            .trim_start()
+            .strip_prefix('@')
+            .and_then(|tag| tag.split_once(':'))
+            .filter(|(name, _)| {
+                !name.is_empty()
+                    && name
+                        .chars()
+                        .all(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | '.'))
+            });
+        match tag {
+            Some((name, value)) => tags.push((name.to_owned(), value.trim().to_owned())),
+            None => rest.push(line),
+        }
+    }
+    while rest.last().map_or(false, |line| line.trim().is_empty()) {
+        rest.pop();
+    }
+    let rest = (!rest.is_empty()).then(|| rest.join("\n"));
+    (rest, tags)
+}
+
+/// Whether a line is an ATX markdown heading, e.g. `## Config`.
+fn is_heading(line: &str) -> bool {
+    let line = line.trim_start();
+    let level = line.chars().take_while(|&c| c == '#').count();
+    (1..=6).contains(&level)
+        && line[level..]
+            .chars()
+            .next()
+            .map_or(true, char::is_whitespace)
+}
 
-    Ok(Some(docs))
+/// Escape `docs` for embedding in another format.
+pub fn escape(docs: &str, format: Escape) -> String {
+    let mut escaped = String::with_capacity(docs.len());
+    for c in docs.chars() {
+        match (format, c) {
+            (Escape::Json | Escape::CString, '"') => escaped.push_str("\\\""),
+            (Escape::Json | Escape::CString, '\\') => escaped.push_str("\\\\"),
+            (Escape::Json | Escape::CString, '\n') => escaped.push_str("\\n"),
+            (Escape::Json | Escape::CString, '\r') => escaped.push_str("\\r"),
+            (Escape::Json | Escape::CString, '\t') => escaped.push_str("\\t"),
+            (Escape::Json, c) if c.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            (Escape::CString, c) if c.is_ascii_control() => {
+                // hex escapes in C are greedy, so octal escapes are used instead
+                let _ = write!(escaped, "\\{:03o}", c as u32);
+            }
+            (Escape::Html, '&') => escaped.push_str("&amp;"),
+            (Escape::Html, '<') => escaped.push_str("&lt;"),
+            (Escape::Html, '>') => escaped.push_str("&gt;"),
+            (Escape::Html, '"') => escaped.push_str("&quot;"),
+            (Escape::Html, '\'') => escaped.push_str("&#39;"),
+            (_, c) => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Opening delimiter of an include placeholder, e.g. `{{include: crate::Foo}}`.
+const INCLUDE_OPEN: &str = "{{";
+/// Keyword of an include placeholder, after the opening delimiter and any
+/// whitespace.
+const INCLUDE_KEYWORD: &str = "include:";
+/// Closing delimiter of an include placeholder.
+const INCLUDE_CLOSE: &str = "}}";
+/// Delimiters of an include marker, which replaces an include placeholder
+/// while the docs are being processed. Private use characters are left alone
+/// by all processing steps.
+const MARKER_OPEN: char = '\u{E000}';
+const MARKER_CLOSE: char = '\u{E001}';
+
+/// Replace all include placeholders in `docs` with markers that survive
+/// processing, and return the paths they refer to in order. Errors point at
+/// the attribute in `attrs` containing the placeholder.
+pub fn extract_includes(docs: &str, attrs: &[Attribute]) -> syn::Result<(String, Vec<Path>)> {
+    let mut replaced = String::with_capacity(docs.len());
+    let mut paths = Vec::new();
+    let mut rest = docs;
+    while let Some(start) = rest.find(INCLUDE_OPEN) {
+        let after_open = &rest[start + INCLUDE_OPEN.len()..];
+        let Some(directive) = after_open.trim_start().strip_prefix(INCLUDE_KEYWORD) else {
+            replaced.push_str(&rest[..start + INCLUDE_OPEN.len()]);
+            rest = after_open;
+            continue;
+        };
+        let Some(end) = directive.find(INCLUDE_CLOSE) else {
+            Err(Error::new(
+                attr_span_containing(attrs, INCLUDE_KEYWORD),
+                "Unclosed include placeholder in doc comments",
+            ))?
+        };
+        let path_str = directive[..end].trim();
+        let path = syn::parse_str::<Path>(path_str).map_err(|_| {
+            Error::new(
+                attr_span_containing(attrs, path_str),
+                format!("Invalid path in include placeholder: `{path_str}`"),
+            )
+        })?;
+        replaced.push_str(&rest[..start]);
+        replaced.push(MARKER_OPEN);
+        replaced.push_str(&paths.len().to_string());
+        replaced.push(MARKER_CLOSE);
+        paths.push(path);
+        rest = &directive[end + INCLUDE_CLOSE.len()..];
+    }
+    replaced.push_str(rest);
+    Ok((replaced, paths))
+}
+
+/// Generate a constant expression of type `&'static str`, with the include
+/// markers in `docs` replaced by the docs of the respective paths.
+pub fn include_expr(docs: &str, paths: &[Path], documented_module_path: &Path) -> TokenStream {
+    let mut parts = Vec::new();
+    let mut rest = docs;
+    while let Some(start) = rest.find(MARKER_OPEN) {
+        let after_open = &rest[start + MARKER_OPEN.len_utf8()..];
+        let end = after_open
+            .find(MARKER_CLOSE)
+            .expect("include markers are always closed");
+        let index = after_open[..end]
+            .parse::<usize>()
+            .expect("include markers always contain an index");
+        let text = &rest[..start];
+        let path = &paths[index];
+        parts.push(quote! { #text });
+        parts.push(quote! { <#path as #documented_module_path::Documented>::DOCS });
+        rest = &after_open[end + MARKER_CLOSE.len_utf8()..];
+    }
+    parts.push(quote! { #rest });
+
+    quote! {{
+        const PARTS: &[&str] = &[#(#parts),*];
+        const BYTES: [u8; #documented_module_path::concat::len(PARTS)] =
+            #documented_module_path::concat::bytes(PARTS);
+        match ::core::str::from_utf8(&BYTES) {
+            ::core::result::Result::Ok(docs) => docs,
+            ::core::result::Result::Err(_) => ::core::panic!("included docs are not valid UTF-8"),
+        }
+    }}
 }
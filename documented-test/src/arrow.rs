@@ -0,0 +1,54 @@
+//! Tests for the Arrow schema integration.
+
+use std::collections::HashMap;
+
+use arrow_schema::{DataType, Field, Schema};
+use documented::{arrow::with_field_docs, DocumentedFields};
+
+#[derive(DocumentedFields)]
+#[allow(dead_code)]
+struct Game {
+    /// White's rating.
+    white_elo: u16,
+    /// Black's rating.
+    black_elo: u16,
+}
+
+#[test]
+fn it_works() {
+    let schema = Schema::new(vec![
+        Field::new("white_elo", DataType::UInt16, false),
+        Field::new("black_elo", DataType::UInt16, false),
+    ]);
+    let schema = with_field_docs::<Game>(schema);
+
+    assert_eq!(
+        schema.field(0).metadata(),
+        &HashMap::from([("description".into(), "White's rating.".into())])
+    );
+    assert_eq!(
+        schema.field(1).metadata(),
+        &HashMap::from([("description".into(), "Black's rating.".into())])
+    );
+}
+
+#[test]
+fn existing_metadata_is_kept() {
+    let field = Field::new("white_elo", DataType::UInt16, false)
+        .with_metadata(HashMap::from([("unit".into(), "elo".into())]));
+    let schema = Schema::new_with_metadata(
+        vec![field, Field::new("result", DataType::Utf8, true)],
+        HashMap::from([("source".into(), "lichess".into())]),
+    );
+    let schema = with_field_docs::<Game>(schema);
+
+    assert_eq!(
+        schema.field(0).metadata(),
+        &HashMap::from([
+            ("unit".into(), "elo".into()),
+            ("description".into(), "White's rating.".into()),
+        ])
+    );
+    assert!(schema.field(1).metadata().is_empty());
+    assert_eq!(schema.metadata()["source"], "lichess");
+}
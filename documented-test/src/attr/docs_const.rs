@@ -25,6 +25,17 @@ fn multiple_docs_work() {
     assert_eq!(TEST_FN_DOCS, "This is a test function\nThis is the second line of the doc\nThis is the third line of the doc");
 }
 
+#[test]
+fn doc_macros_work() {
+    #[doc = concat!("Mate in ", 1)]
+    #[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/docs/doge.md"))]
+    #[docs_const]
+    #[allow(dead_code)]
+    fn test_fn() {}
+
+    assert_eq!(TEST_FN_DOCS, "Mate in 1\nSuch include\n\nvery markdown\n");
+}
+
 #[cfg(feature = "customise")]
 mod test_customise {
     use documented::docs_const;
@@ -77,4 +88,289 @@ mod test_customise {
             "           This is a test function        \n        Test Trim"
         ); // The whitespace is preserved, even on the end of the first line
     }
+
+    #[test]
+    fn lazy_works() {
+        fn shout(docs: &str) -> String {
+            format!("{}!", docs.to_uppercase())
+        }
+
+        /// Check
+        #[docs_const(lazy = shout)]
+        #[allow(dead_code)]
+        fn test_fn() {}
+
+        assert_eq!(*TEST_FN_DOCS, "CHECK!");
+    }
+
+    #[test]
+    fn lazy_with_closure_and_trim_works() {
+        ///     Mate
+        #[docs_const(lazy = |docs: &str| docs.replace(' ', "_"), trim = false)]
+        #[allow(dead_code)]
+        fn test_fn() {}
+
+        assert_eq!(*TEST_FN_DOCS, "_____Mate");
+    }
+
+    #[test]
+    fn lazy_transforms_once() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+        fn count(docs: &str) -> String {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+            docs.to_string()
+        }
+
+        /// Much once
+        #[docs_const(lazy = count)]
+        #[allow(dead_code)]
+        fn test_fn() {}
+
+        let threads = (0..4)
+            .map(|_| std::thread::spawn(|| assert_eq!(*TEST_FN_DOCS, "Much once")))
+            .collect::<Vec<_>>();
+        threads.into_iter().for_each(|t| t.join().unwrap());
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn lazy_crate_path_works() {
+        mod doge_facade {
+            pub use documented;
+        }
+
+        /// Such re-export
+        #[docs_const(lazy = |docs: &str| docs.to_uppercase(), crate = "doge_facade::documented")]
+        #[allow(dead_code)]
+        fn test_fn() {}
+
+        assert_eq!(*TEST_FN_DOCS, "SUCH RE-EXPORT");
+    }
+
+    #[test]
+    fn normalize_works() {
+        #[doc = "Cafe\u{301}"]
+        #[docs_const(normalize = "nfc")]
+        #[allow(dead_code)]
+        const CAFE: () = ();
+
+        assert_eq!(CAFE_DOCS, "Caf\u{e9}");
+    }
+
+    #[test]
+    fn strip_markdown_works() {
+        /// Such **mate** in [`one`](crate::One)
+        #[docs_const(strip_markdown)]
+        #[allow(dead_code)]
+        const MATE: () = ();
+
+        assert_eq!(MATE_DOCS, "Such mate in one");
+    }
+
+    #[test]
+    fn dedent_works() {
+        ///   Mate
+        ///     in one
+        #[docs_const(dedent)]
+        #[allow(dead_code)]
+        const MATE: () = ();
+
+        assert_eq!(MATE_DOCS, "Mate\n  in one");
+    }
+
+    #[test]
+    fn trim_start_and_end_work() {
+        #[doc = "  Mate  "]
+        #[docs_const(trim_start = false)]
+        #[allow(dead_code)]
+        const MATE: () = ();
+
+        #[doc = "  Mate  "]
+        #[docs_const(trim = false, trim_end)]
+        #[allow(dead_code)]
+        const CHECK: () = ();
+
+        assert_eq!(MATE_DOCS, "  Mate");
+        assert_eq!(CHECK_DOCS, "  Mate");
+    }
+
+    #[test]
+    fn separator_works() {
+        /// Mate
+        /// in one
+        #[docs_const(separator = " ")]
+        #[allow(dead_code)]
+        const MATE: () = ();
+
+        assert_eq!(MATE_DOCS, "Mate in one");
+    }
+
+    #[test]
+    fn unwrap_lines_works() {
+        /// Mate
+        /// in one
+        ///
+        /// Or two
+        #[docs_const(unwrap_lines)]
+        #[allow(dead_code)]
+        const MATE: () = ();
+
+        assert_eq!(MATE_DOCS, "Mate in one\n\nOr two");
+    }
+
+    #[test]
+    fn wrap_works() {
+        /// Mate in one or two
+        #[docs_const(wrap = 10)]
+        #[allow(dead_code)]
+        const MATE: () = ();
+
+        assert_eq!(MATE_DOCS, "Mate in\none or two");
+    }
+
+    #[test]
+    fn blank_lines_work() {
+        #[doc = ""]
+        /// Mate
+        ///
+        ///
+        /// in one
+        #[docs_const(collapse_blank_lines)]
+        #[allow(dead_code)]
+        const MATE: () = ();
+
+        assert_eq!(MATE_DOCS, "Mate\n\nin one");
+    }
+
+    #[test]
+    fn newlines_and_tabs_work() {
+        #[doc = "Mate\r\n\tin one"]
+        #[docs_const(trim = false, normalize_newlines, expand_tabs = 2)]
+        #[allow(dead_code)]
+        const MATE: () = ();
+
+        assert_eq!(MATE_DOCS, "Mate\n  in one");
+    }
+
+    #[test]
+    fn prefix_and_suffix_work() {
+        /// in one
+        #[docs_const(prefix = "Mate ", suffix = "!")]
+        #[allow(dead_code)]
+        const MATE: () = ();
+
+        assert_eq!(MATE_DOCS, "Mate in one!");
+    }
+
+    #[test]
+    fn allow_empty_works() {
+        #[docs_const(allow_empty)]
+        #[allow(dead_code)]
+        const MATE: () = ();
+
+        assert_eq!(MATE_DOCS, "");
+    }
+
+    #[test]
+    fn interpolate_works() {
+        /// Mate in {CARGO_PKG_NAME}
+        #[docs_const(interpolate)]
+        #[allow(dead_code)]
+        const MATE: () = ();
+
+        assert_eq!(MATE_DOCS, "Mate in documented-test");
+    }
+
+    #[test]
+    fn source_works() {
+        /// Such docs
+        #[docs_const(source = "describe")]
+        #[describe = "Very describe"]
+        #[describe = "much help"]
+        #[allow(dead_code)]
+        const DOGE: () = ();
+
+        /// Such docs
+        #[docs_const(source = "doc", source = "describe")]
+        #[describe = "very describe"]
+        #[allow(dead_code)]
+        const KABUSO: () = ();
+
+        assert_eq!(DOGE_DOCS, "Very describe\nmuch help");
+        assert_eq!(KABUSO_DOCS, "Such docs\nvery describe");
+    }
+
+    #[test]
+    fn source_merge_items_works() {
+        #[docs_const(source = "describe", merge_items)]
+        #[describe = "Such module"]
+        #[allow(dead_code)]
+        mod doge {
+            /// Such docs
+            #[describe = "Very describe"]
+            const WOW: () = ();
+        }
+
+        assert_eq!(DOGE_DOCS, "Such module\n\n## WOW\n\nVery describe");
+    }
+
+    #[test]
+    fn cfg_attr_works() {
+        /// Such docs
+        #[cfg_attr(test, docs_const(rename = "WOW"))]
+        #[cfg_attr(test, doc = "very cfg")]
+        #[allow(dead_code)]
+        const DOGE: () = ();
+
+        assert_eq!(WOW, "Such docs\nvery cfg");
+    }
+
+    #[test]
+    fn merge_items_works() {
+        /// Such module
+        #[docs_const(merge_items, rename = "DOGE")]
+        #[allow(dead_code)]
+        mod doge {
+
+            /// Wow
+            pub struct Shibe;
+
+            impl Shibe {}
+
+            ///   Much trim
+            pub const AMAZE: u8 = 0;
+
+            /// Such nested
+            pub mod nested {
+                /// Not included
+                pub struct Inner;
+            }
+        }
+
+        assert_eq!(
+            DOGE,
+            "Such module\n\n## Shibe\n\nWow\n\n## AMAZE\n\nMuch trim\n\n## nested\n\nSuch nested"
+        );
+    }
+
+    #[test]
+    fn merge_items_without_module_docs_works() {
+        #[docs_const(merge_items)]
+        #[allow(dead_code)]
+        mod doge {
+            /// Wow
+            pub fn shibe() {}
+        }
+
+        #[docs_const(merge_items, default = "Such empty")]
+        #[allow(dead_code)]
+        mod empty {
+            pub fn shibe() {}
+        }
+
+        assert_eq!(DOGE_DOCS, "## shibe\n\nWow");
+        assert_eq!(EMPTY_DOCS, "Such empty");
+    }
 }
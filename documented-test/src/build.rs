@@ -0,0 +1,342 @@
+//! Tests for doc extraction in build scripts.
+
+use std::{env, fs, sync::Mutex};
+
+use documented_build::{
+    extract, extract_features, extract_features_file, extract_file, render_consts, Error,
+    FeatureDocs, ItemDocs,
+};
+
+fn item(path: &[&str], docs: &str) -> ItemDocs {
+    ItemDocs {
+        path: path.iter().map(ToString::to_string).collect(),
+        docs: docs.to_string(),
+    }
+}
+
+#[test]
+fn it_works() {
+    let source = r#"
+        /// Board.
+        struct Board {
+            /// Squares.
+            squares: [u8; 64],
+            undocumented: bool,
+        }
+
+        /// Pieces.
+        mod pieces {
+            /// Piece.
+            pub enum Piece {
+                /// Knight.
+                Knight,
+                Bishop(
+                    /// Colour.
+                    bool,
+                ),
+            }
+        }
+
+        /// Not captured.
+        impl Board {}
+    "#;
+
+    assert_eq!(
+        extract(source, true).unwrap(),
+        [
+            item(&["Board"], "Board."),
+            item(&["Board", "squares"], "Squares."),
+            item(&["pieces"], "Pieces."),
+            item(&["pieces", "Piece"], "Piece."),
+            item(&["pieces", "Piece", "Knight"], "Knight."),
+            item(&["pieces", "Piece", "Bishop", "0"], "Colour."),
+        ]
+    );
+}
+
+#[test]
+fn trim_works() {
+    let source = "///     Indented.\nconst DEPTH: u8 = 20;";
+
+    assert_eq!(
+        extract(source, true).unwrap(),
+        [item(&["DEPTH"], "Indented.")]
+    );
+    assert_eq!(
+        extract(source, false).unwrap(),
+        [item(&["DEPTH"], "     Indented.")]
+    );
+}
+
+#[test]
+fn doc_macros_work() {
+    let source = r#"
+        #[doc = concat!("Version ", env!("CARGO_PKG_NAME"), ' ', 1, "\n")]
+        #[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/docs/doge.md"))]
+        struct Foo;
+    "#;
+
+    assert_eq!(
+        extract(source, true).unwrap(),
+        [item(
+            &["Foo"],
+            "Version documented-test 1\n\nSuch include\n\nvery markdown\n"
+        )]
+    );
+    assert!(extract(r#"#[doc = include_str!("/missing.md")] struct Foo;"#, true).is_err());
+    assert!(extract(r#"#[doc = include_str!("docs/doge.md")] struct Foo;"#, true).is_err());
+    assert!(extract(r#"#[doc = env!("DOCUMENTED_MISSING")] struct Foo;"#, true).is_err());
+}
+
+/// Serialises tests that depend on whether the `CARGO_CFG_*` variables are set.
+static BUILD_SCRIPT_ENV: Mutex<()> = Mutex::new(());
+
+/// Run `f` with the environment variables `cfg` evaluation needs to be set,
+/// like in a build script.
+fn in_build_script<T>(f: impl FnOnce() -> T) -> T {
+    let _guard = BUILD_SCRIPT_ENV
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    env::set_var("CARGO_CFG_TARGET_ARCH", env::consts::ARCH);
+    let result = f();
+    env::remove_var("CARGO_CFG_TARGET_ARCH");
+    result
+}
+
+#[test]
+fn cfg_attr_works() {
+    let source = r#"
+        /// Open
+        #[cfg_attr(all(), doc = "with e4")]
+        #[cfg_attr(any(), doc = "with d4")]
+        #[cfg_attr(not(feature = "documented-missing"), cfg_attr(all(), doc = "or c4"))]
+        fn opening() {}
+    "#;
+
+    assert_eq!(
+        in_build_script(|| extract(source, true)).unwrap(),
+        [item(&["opening"], "Open\nwith e4\nor c4")]
+    );
+}
+
+#[test]
+fn cfg_works() {
+    let source = r#"
+        /// Knight
+        struct Knight(
+            #[cfg(feature = "documented-missing")] u8,
+            /// File
+            u8,
+        );
+
+        /// Castle
+        #[cfg(any())]
+        fn castle() {}
+
+        enum Side {
+            /// Queenside
+            #[cfg(not(all()))]
+            Queen,
+            /// Kingside
+            King,
+        }
+    "#;
+
+    assert_eq!(
+        in_build_script(|| extract(source, true)).unwrap(),
+        [
+            item(&["Knight"], "Knight"),
+            item(&["Knight", "0"], "File"),
+            item(&["Side", "King"], "Kingside"),
+        ]
+    );
+}
+
+#[test]
+fn cfg_outside_build_script_errors() {
+    let _guard = BUILD_SCRIPT_ENV
+        .lock()
+        .unwrap_or_else(|err| err.into_inner());
+    let source = r#"#[cfg_attr(all(), doc = "Castle")] fn castle() {}"#;
+
+    assert_eq!(
+        extract(source, true).unwrap_err().to_string(),
+        "cfg predicates can only be evaluated in build scripts, as `CARGO_CFG_*` environment \
+        variables are not set"
+    );
+}
+
+#[test]
+fn non_literal_docs_error() {
+    let source = r#"#[doc = DOCS] struct Foo;"#;
+
+    assert_eq!(
+        extract(source, true).unwrap_err().to_string(),
+        "Doc comment is not a string literal"
+    );
+}
+
+#[test]
+fn extract_file_works() {
+    let path = env::temp_dir().join("documented_build_extract_file_works.rs");
+    fs::write(&path, "/// Resign.\nfn resign() {}").unwrap();
+
+    let items = extract_file(&path, true);
+    fs::remove_file(&path).unwrap();
+    assert_eq!(items.unwrap(), [item(&["resign"], "Resign.")]);
+
+    assert!(matches!(
+        extract_file(env::temp_dir().join("documented_build_missing.rs"), true),
+        Err(Error::Io(_))
+    ));
+}
+
+#[test]
+fn extract_file_includes_relative_to_file() {
+    let dir = env::temp_dir().join("documented_build_relative_include");
+    fs::create_dir_all(dir.join("docs")).unwrap();
+    fs::write(dir.join("docs/resign.md"), "Resign.").unwrap();
+    let source = r#"#[doc = include_str!("docs/resign.md")] fn resign() {}"#;
+    fs::write(dir.join("lib.rs"), source).unwrap();
+
+    let items = extract_file(dir.join("lib.rs"), true);
+    fs::remove_dir_all(&dir).unwrap();
+    assert_eq!(items.unwrap(), [item(&["resign"], "Resign.")]);
+}
+
+#[test]
+fn render_consts_works() {
+    let items = [
+        item(&["Board", "en_passant"], "Holy hell."),
+        item(&["pieces", "Piece", "0"], "A \"piece\".\nOr a pawn."),
+    ];
+
+    assert_eq!(
+        render_consts(&items),
+        "pub const BOARD_EN_PASSANT_DOCS: &str = \"Holy hell.\";\n\
+        pub const PIECES_PIECE_0_DOCS: &str = \"A \\\"piece\\\".\\nOr a pawn.\";\n"
+    );
+}
+
+fn feature(name: &str, docs: Option<&str>) -> FeatureDocs {
+    FeatureDocs {
+        name: name.to_string(),
+        docs: docs.map(ToString::to_string),
+    }
+}
+
+#[test]
+fn features_works() {
+    let manifest = r#"
+        [package]
+        ## Not a feature.
+        name = "chess"
+
+        [features]
+        ## Default.
+        default = ["engine"]
+
+        ## Engine.
+        ## Very strong.
+        engine = [
+            "dep:stockfish", # not ] a [ doc
+            "tablebase",
+        ]
+        # Tablebase.
+        tablebase = []
+        "quoted" = [] # Quoted.
+
+        [dependencies]
+        ## Not a feature either.
+        stockfish = "1"
+    "#;
+
+    assert_eq!(
+        extract_features(manifest),
+        [
+            feature("default", Some("Default.")),
+            feature("engine", Some("Engine.\nVery strong.")),
+            feature("tablebase", None),
+            feature("quoted", None),
+        ]
+    );
+}
+
+#[test]
+fn features_multi_line_arrays_work() {
+    let manifest = r#"
+        [features]   # such table
+        engine = [
+            ## not the docs of tablebase
+            "dep:stockfish", # not ] a [ doc
+            'literal]', "escaped\"]",
+            "]", '[',
+        ]
+        ## Tablebase.
+        tablebase = [] # ]
+        [[bin]]
+        name = "chess"
+    "#;
+
+    assert_eq!(
+        extract_features(manifest),
+        [
+            feature("engine", None),
+            feature("tablebase", Some("Tablebase.")),
+        ]
+    );
+}
+
+#[test]
+fn features_unsupported_layouts_are_skipped() {
+    let dotted = r#"
+        ## Engine.
+        features.engine = []
+    "#;
+    let inline = r#"
+        ## Engine.
+        features = { engine = [] }
+    "#;
+
+    assert_eq!(extract_features(dotted), []);
+    assert_eq!(extract_features(inline), []);
+}
+
+#[test]
+fn extract_features_file_works() {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/features.toml");
+
+    assert_eq!(
+        extract_features_file(path).unwrap(),
+        [
+            feature("default", None),
+            feature("wow", Some("Such feature,\nvery docs.")),
+            feature("much", None),
+        ]
+    );
+}
+
+#[test]
+fn cargo_features_docs_works() {
+    const FEATURES: &[(&str, Option<&str>)] = documented::cargo_features_docs!();
+
+    assert_eq!(
+        FEATURES,
+        [
+            ("arrow", Some("Test Arrow schema generation.")),
+            ("customise", Some("Test configuration options.")),
+            ("default", None),
+            (
+                "phf",
+                Some("Test lookups through perfect hash maps, rather than `match`es.")
+            ),
+            ("render", Some("Test markdown rendering.")),
+            ("serde", Some("Test serde support.")),
+            ("serde-interop", Some("Test lookups by serde names.")),
+            (
+                "serde_path_to_error",
+                Some("Test serde errors with the path of the failing field.")
+            ),
+        ]
+    );
+}
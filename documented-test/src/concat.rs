@@ -0,0 +1,39 @@
+use documented::{docs_concat, Documented};
+
+/// Such intro
+#[derive(Documented)]
+struct Intro;
+
+/// Very config
+/// wow
+#[derive(Documented)]
+struct Config;
+
+/// Much FAQ ✓
+#[derive(Documented)]
+struct Faq;
+
+#[test]
+fn it_works() {
+    const DOCS: &str = docs_concat!(Intro, Config, Faq);
+    assert_eq!(DOCS, "Such intro\n\nVery config\nwow\n\nMuch FAQ ✓");
+}
+
+#[test]
+fn single_works() {
+    const DOCS: &str = docs_concat!(Intro);
+    assert_eq!(DOCS, "Such intro");
+}
+
+#[test]
+fn separator_works() {
+    const SEPARATOR: &str = "\n---\n";
+    const DOCS: &str = docs_concat!(separator = SEPARATOR, Intro, Config, Faq,);
+    assert_eq!(DOCS, "Such intro\n---\nVery config\nwow\n---\nMuch FAQ ✓");
+}
+
+#[test]
+fn non_const_context_works() {
+    let docs: &'static str = docs_concat!(separator = " ", Intro, Faq);
+    assert_eq!(docs, "Such intro Much FAQ ✓");
+}
@@ -0,0 +1,59 @@
+//! Tests for pairing field docs with default values.
+
+use documented::{defaults::field_defaults, DocumentedFields};
+use serde::Serialize;
+
+#[test]
+fn it_works() {
+    #[derive(DocumentedFields, Serialize)]
+    struct Settings {
+        /// Engine name.
+        engine: String,
+        /// Search depth.
+        depth: u8,
+    }
+    impl Default for Settings {
+        fn default() -> Self {
+            Self { engine: "Stockfish".into(), depth: 20 }
+        }
+    }
+
+    assert_eq!(
+        field_defaults::<Settings>(),
+        [
+            ("engine", "Engine name.", Some(r#""Stockfish""#.into())),
+            ("depth", "Search depth.", Some("20".into())),
+        ]
+    );
+}
+
+#[test]
+fn serde_rename_has_no_default() {
+    #[derive(Default, DocumentedFields, Serialize)]
+    struct Settings {
+        /// Ponder on opponent's time.
+        #[serde(rename = "Ponder")]
+        ponder: bool,
+        /// Number of threads.
+        threads: u16,
+    }
+
+    assert_eq!(
+        field_defaults::<Settings>(),
+        [
+            ("ponder", "Ponder on opponent's time.", None),
+            ("threads", "Number of threads.", Some("0".into())),
+        ]
+    );
+}
+
+#[test]
+fn unnamed_fields_are_skipped() {
+    #[derive(Default, DocumentedFields, Serialize)]
+    struct Elo(
+        /// Rating.
+        u16,
+    );
+
+    assert_eq!(field_defaults::<Elo>(), []);
+}
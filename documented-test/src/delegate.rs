@@ -0,0 +1,65 @@
+//! Tests for the implementations on wrapper types.
+
+use std::{rc::Rc, sync::Arc};
+
+use documented::{Documented, DocumentedFields, DocumentedFieldsOpt, DocumentedOpt};
+
+/// Such wrap
+#[derive(Documented, DocumentedOpt, DocumentedFields, DocumentedFieldsOpt)]
+#[allow(dead_code)]
+struct Doge {
+    /// Wow
+    wow: u8,
+    /// Very nested
+    #[cfg_attr(feature = "customise", documented_fields(nested))]
+    nested: Box<Inner>,
+}
+
+#[derive(DocumentedFields, DocumentedFieldsOpt)]
+#[allow(dead_code)]
+struct Inner {
+    /// Amaze
+    amaze: u8,
+}
+
+fn docs<T: Documented + ?Sized>() -> &'static str {
+    T::DOCS
+}
+
+fn field_docs<T: DocumentedFields + ?Sized>(name: &str) -> Result<&'static str, documented::Error> {
+    T::get_field_docs(name)
+}
+
+#[test]
+fn documented_works() {
+    assert_eq!(docs::<&Doge>(), "Such wrap");
+    assert_eq!(docs::<&mut Doge>(), "Such wrap");
+    assert_eq!(docs::<Box<Doge>>(), "Such wrap");
+    assert_eq!(docs::<Rc<Doge>>(), "Such wrap");
+    assert_eq!(docs::<Arc<Doge>>(), "Such wrap");
+    assert_eq!(docs::<Option<Box<Doge>>>(), "Such wrap");
+    assert_eq!(<Option<Doge> as Documented>::ITEM_NAME, "Doge");
+    assert_eq!(<Arc<Doge> as DocumentedOpt>::DOCS, Some("Such wrap"));
+}
+
+#[test]
+fn documented_fields_works() {
+    assert_eq!(field_docs::<Arc<Doge>>("wow"), Ok("Wow"));
+    assert_eq!(
+        <Rc<Doge> as DocumentedFields>::FIELD_NAMES,
+        ["wow", "nested"]
+    );
+}
+
+#[test]
+#[cfg(feature = "customise")]
+fn nested_works() {
+    assert_eq!(
+        <Option<Doge> as DocumentedFields>::get_field_docs_by_path(["nested", "amaze"]),
+        Ok("Amaze")
+    );
+    assert_eq!(
+        <&Doge as DocumentedFieldsOpt>::get_field_docs_by_path(["nested", "amaze"]),
+        Ok("Amaze")
+    );
+}
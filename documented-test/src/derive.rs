@@ -8,5 +8,8 @@ mod documented;
 mod documented_fields;
 mod documented_fields_opt;
 mod documented_opt;
+mod documented_schema;
+mod documented_tree;
+mod documented_variant_fields;
 mod documented_variants;
 mod documented_variants_opt;
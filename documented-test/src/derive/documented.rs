@@ -36,6 +36,36 @@ mod test_use {
         assert_eq!(Nicer::DOCS, docs);
     }
 
+    #[test]
+    fn trailing_newline_is_kept() {
+        #[doc = "Such newline\n"]
+        #[derive(Documented)]
+        struct Doge;
+
+        assert_eq!(Doge::DOCS, "Such newline\n");
+    }
+
+    #[test]
+    fn doc_macros_work() {
+        #[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/docs/doge.md"))]
+        #[doc = concat!("wow ", 69, '!')]
+        #[derive(Documented)]
+        struct Doge;
+
+        assert_eq!(Doge::DOCS, "Such include\n\nvery markdown\n\nwow 69!");
+    }
+
+    #[test]
+    fn cfg_attr_works() {
+        /// Such docs
+        #[cfg_attr(test, doc = "very test")]
+        #[cfg_attr(not(test), doc = "much release")]
+        #[derive(Documented)]
+        struct Doge;
+
+        assert_eq!(Doge::DOCS, "Such docs\nvery test");
+    }
+
     #[test]
     fn generic_type_works() {
         /// Wow
@@ -83,6 +113,118 @@ mod test_use {
 
         assert_eq!(Doge::DOCS, "Wow");
     }
+
+    #[test]
+    fn item_name_works() {
+        /// Wow
+        #[allow(dead_code)]
+        #[derive(Documented)]
+        struct Doge<'a, T: Copy, const LEN: usize> {
+            much: &'a [T; LEN],
+        }
+
+        assert_eq!(Doge::<u8, 2>::ITEM_NAME, "Doge");
+    }
+
+    #[test]
+    fn item_name_of_raw_ident_works() {
+        /// Wow
+        #[derive(Documented)]
+        struct r#Doge;
+
+        assert_eq!(Doge::ITEM_NAME, "Doge");
+    }
+
+    #[test]
+    fn module_path_works() {
+        mod kennel {
+            /// Wow
+            #[derive(super::Documented)]
+            pub struct Doge;
+        }
+
+        assert_eq!(
+            kennel::Doge::MODULE_PATH,
+            format!("{}::kennel", module_path!())
+        );
+    }
+
+    /// Much wow
+    #[derive(Documented)]
+    struct Common;
+
+    #[test]
+    fn include_works() {
+        /// Such {{include: Common}}, very {{ include: self::Common }}.
+        #[derive(Documented)]
+        struct Doge;
+
+        assert_eq!(Doge::DOCS, "Such Much wow, very Much wow.");
+    }
+
+    #[test]
+    fn include_from_file_works() {
+        #[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/docs/include.md"))]
+        #[derive(Documented)]
+        struct Doge;
+
+        assert_eq!(Doge::DOCS, "Such Much wow\n");
+    }
+
+    #[test]
+    fn include_generic_works() {
+        /// {{include: Common}}
+        #[derive(Documented)]
+        #[allow(dead_code)]
+        struct Doge<T> {
+            much: T,
+        }
+
+        assert_eq!(Doge::<u8>::DOCS, "Much wow");
+    }
+
+    #[test]
+    fn include_opt_works() {
+        /// Wow
+        ///
+        /// {{include: Common}}
+        #[derive(documented::DocumentedOpt)]
+        struct Doge;
+
+        assert_eq!(
+            <Doge as documented::DocumentedOpt>::DOCS,
+            Some("Wow\n\nMuch wow")
+        );
+    }
+
+    #[test]
+    fn include_unicode_works() {
+        /// ✓ {{include: Common}} ✓
+        #[derive(Documented)]
+        struct Doge;
+
+        assert_eq!(Doge::DOCS, "✓ Much wow ✓");
+    }
+}
+
+mod test_dyn {
+    use std::any::Any;
+
+    use documented::{Documented, DocumentedDyn};
+
+    trait Plugin: Any + DocumentedDyn {}
+
+    /// Such plugin
+    #[derive(Documented)]
+    struct Doge;
+    impl Plugin for Doge {}
+
+    #[test]
+    fn it_works() {
+        let plugins: Vec<Box<dyn Plugin>> = vec![Box::new(Doge)];
+        assert_eq!(plugins[0].docs(), "Such plugin");
+        assert_eq!(plugins[0].item_name(), "Doge");
+    }
 }
 
 mod test_qualified {
@@ -164,6 +306,20 @@ doge
         assert_eq!(Age37::DOCS, "3 goals 2 assists!");
     }
 
+    #[test]
+    fn allow_empty_works() {
+        #[derive(Documented)]
+        #[documented(allow_empty)]
+        struct Doge;
+
+        #[derive(Documented)]
+        #[documented(default = "Such default", allow_empty)]
+        struct Kabuso;
+
+        assert_eq!(Doge::DOCS, "");
+        assert_eq!(Kabuso::DOCS, "Such default");
+    }
+
     #[test]
     fn trim_false_works() {
         /** Wow
@@ -180,4 +336,763 @@ doge
         ";
         assert_eq!(Doge::DOCS, doc_str);
     }
+
+    #[test]
+    fn also_raw_works() {
+        ///   Wow
+        ///   much doge
+        #[derive(Documented)]
+        #[documented(also_raw)]
+        struct Doge;
+
+        assert_eq!(Doge::DOCS, "Wow\nmuch doge");
+        assert_eq!(Doge::DOCS_RAW, "   Wow\n   much doge");
+    }
+
+    #[test]
+    fn also_raw_with_value_works() {
+        ///   Wow
+        #[derive(Documented)]
+        #[documented(also_raw = true, default = "Such empty")]
+        struct Doge;
+
+        #[derive(Documented)]
+        #[documented(also_raw = true, default = "Such empty")]
+        struct Kabuso;
+
+        assert_eq!(Doge::DOCS_RAW, "   Wow");
+        assert_eq!(Kabuso::DOCS, "Such empty");
+        assert_eq!(Kabuso::DOCS_RAW, "Such empty");
+    }
+
+    #[test]
+    fn also_raw_generic_type_works() {
+        ///   Wow
+        #[derive(Documented)]
+        #[documented(also_raw)]
+        #[allow(dead_code)]
+        struct Doge<'a, T: Copy, const LEN: usize> {
+            much: &'a [T; LEN],
+        }
+
+        assert_eq!(Doge::<u8, 4>::DOCS_RAW, "   Wow");
+    }
+
+    #[test]
+    fn list_variants_works() {
+        /// Much modes
+        #[derive(Documented)]
+        #[documented(list_variants)]
+        #[allow(dead_code)]
+        enum Mode {
+            ///
+            /// Wow
+            /// such
+            Doge,
+            Kabuso(u8),
+            /**
+                Very
+                speed
+            */
+            Cheems {
+                speed: u8,
+            },
+        }
+
+        assert_eq!(
+            Mode::DOCS,
+            "Much modes\n\nVariants:\n- Doge: Wow\n- Kabuso\n- Cheems: Very"
+        );
+    }
+
+    #[test]
+    fn list_variants_default_works() {
+        #[derive(Documented)]
+        #[documented(list_variants, default = "Such empty")]
+        #[allow(dead_code)]
+        enum Mode {
+            /// Wow
+            Doge,
+        }
+
+        assert_eq!(Mode::DOCS, "Such empty");
+    }
+
+    #[test]
+    fn wrap_works() {
+        /// Such words
+        /// very wrap wow
+        ///
+        /// Amaze
+        #[derive(Documented)]
+        #[documented(wrap = 10)]
+        struct Doge;
+
+        assert_eq!(Doge::DOCS, "Such words\nvery wrap\nwow\n\nAmaze");
+    }
+
+    #[test]
+    fn wrap_keeps_long_words_works() {
+        /// Such extraordinarily long
+        #[derive(Documented)]
+        #[documented(wrap = 4)]
+        struct Doge;
+
+        assert_eq!(Doge::DOCS, "Such\nextraordinarily\nlong");
+    }
+
+    #[test]
+    fn wrap_with_list_variants_works() {
+        /// Much modes very
+        #[derive(Documented)]
+        #[documented(wrap = 10, list_variants)]
+        #[allow(dead_code)]
+        enum Mode {
+            /// Wow such doge
+            Doge,
+        }
+
+        assert_eq!(
+            Mode::DOCS,
+            "Much modes\nvery\n\nVariants:\n- Doge: Wow such doge"
+        );
+    }
+
+    #[test]
+    fn escape_json_works() {
+        #[doc = "Such \"quote\" \\ wow\n\tvery tab\u{1}"]
+        #[derive(Documented)]
+        #[documented(escape = "json", trim = false)]
+        struct Doge;
+
+        assert_eq!(Doge::DOCS, r#"Such \"quote\" \\ wow\n\tvery tab\u0001"#);
+    }
+
+    #[test]
+    fn escape_html_works() {
+        /// <Such> & 'wow'
+        #[derive(Documented)]
+        #[documented(escape = "html")]
+        struct Doge;
+
+        assert_eq!(Doge::DOCS, "&lt;Such&gt; &amp; &#39;wow&#39;");
+    }
+
+    #[test]
+    fn escape_c_string_works() {
+        #[doc = "Such \"quote\"\u{7}\nvery bell ✓"]
+        #[derive(Documented)]
+        #[documented(escape = "c_string")]
+        struct Doge;
+
+        assert_eq!(Doge::DOCS, r#"Such \"quote\"\007\nvery bell ✓"#);
+    }
+
+    #[test]
+    fn escape_after_list_variants_works() {
+        /// Much "modes"
+        #[derive(Documented)]
+        #[documented(list_variants, escape = "json")]
+        #[allow(dead_code)]
+        enum Mode {
+            Doge,
+        }
+
+        assert_eq!(Mode::DOCS, r#"Much \"modes\"\n\nVariants:\n- Doge"#);
+    }
+
+    #[test]
+    fn include_with_processing_works() {
+        /// "Such"
+        #[derive(Documented)]
+        struct Quoted;
+
+        /// Much "wow" {{include: Quoted}} very
+        /// long words
+        #[derive(Documented)]
+        #[documented(wrap = 10, escape = "json")]
+        struct Doge;
+
+        assert_eq!(Doge::DOCS, r#"Much \"wow\"\n"Such" very\nlong words"#);
+    }
+
+    #[test]
+    fn skip_lines_works() {
+        /// Such header
+        ///
+        /// Very docs
+        /// wow
+        #[derive(Documented)]
+        #[documented(skip_lines = 1)]
+        struct Doge;
+
+        assert_eq!(Doge::DOCS, "Very docs\nwow");
+    }
+
+    #[test]
+    fn skip_lines_past_end_works() {
+        /// Such docs
+        #[derive(Documented)]
+        #[documented(skip_lines = 3, default = "Wow")]
+        struct Doge;
+
+        assert_eq!(Doge::DOCS, "");
+    }
+
+    #[test]
+    fn strip_leading_heading_works() {
+        /// ## Such heading
+        ///
+        /// Very docs
+        /// # Not leading
+        #[derive(Documented)]
+        #[documented(strip_leading_heading)]
+        struct Doge;
+
+        assert_eq!(Doge::DOCS, "Very docs\n# Not leading");
+    }
+
+    #[test]
+    fn strip_leading_heading_without_heading_works() {
+        /// #Such hashtag
+        /// wow
+        #[derive(Documented)]
+        #[documented(strip_leading_heading)]
+        struct Doge;
+
+        assert_eq!(Doge::DOCS, "#Such hashtag\nwow");
+    }
+
+    #[test]
+    fn skip_lines_then_strip_leading_heading_works() {
+        /// Such preamble
+        /// # Very heading
+        /// Wow
+        #[derive(Documented)]
+        #[documented(skip_lines = 1, strip_leading_heading)]
+        struct Doge;
+
+        assert_eq!(Doge::DOCS, "Wow");
+    }
+
+    #[test]
+    fn impl_display_works() {
+        /// Such display
+        /// wow
+        #[derive(Documented)]
+        #[documented(impl_display)]
+        struct Doge;
+
+        assert_eq!(Doge.to_string(), "Such display\nwow");
+    }
+
+    #[test]
+    fn impl_display_generic_works() {
+        /// Very generic
+        #[derive(Documented)]
+        #[documented(impl_display)]
+        struct Doge<T>(T);
+
+        assert_eq!(Doge(0).to_string(), "Very generic");
+        assert_eq!(format!("{:>14}", Doge(())), "  Very generic");
+    }
+
+    #[test]
+    fn help_works() {
+        /// Such help
+        #[derive(Documented)]
+        #[documented(help)]
+        #[allow(dead_code)]
+        enum Doge {
+            /// Very unit
+            ///
+            /// Wow
+            Unit,
+            /// Much tuple ✓
+            LongTuple(u8),
+            Undocumented,
+        }
+
+        assert_eq!(Doge::DOCS, "Such help");
+        assert_eq!(
+            Doge::HELP,
+            "Such help\n\n\
+            Variants:\n  \
+            Unit          Very unit\n  \
+            LongTuple     Much tuple ✓\n  \
+            Undocumented"
+        );
+    }
+
+    #[test]
+    fn help_tuple_struct_works() {
+        /// Such tuple
+        #[derive(Documented)]
+        #[documented(help, wrap = 5)]
+        #[allow(dead_code)]
+        struct Doge(
+            /// Very first
+            u8,
+            /// Much second
+            u8,
+        );
+
+        assert_eq!(
+            Doge::HELP,
+            "Such\ntuple\n\nFields:\n  0  Very first\n  1  Much second"
+        );
+    }
+
+    #[test]
+    fn help_unit_struct_works() {
+        /// Such unit
+        #[derive(Documented)]
+        #[documented(help)]
+        struct Doge;
+
+        assert_eq!(Doge::HELP, "Such unit");
+    }
+
+    #[test]
+    fn help_with_include_works() {
+        /// Wow
+        #[derive(Documented)]
+        struct Included;
+
+        /// Such {{include: Included}}
+        #[derive(Documented)]
+        #[documented(help)]
+        #[allow(dead_code)]
+        struct Doge {
+            /// Very field
+            field: u8,
+        }
+
+        assert_eq!(Doge::HELP, "Such Wow\n\nFields:\n  field  Very field");
+    }
+
+    #[test]
+    fn normalize_works() {
+        #[doc = "Such cafe\u{301} ﬁ"]
+        #[derive(Documented)]
+        #[documented(normalize = "nfc")]
+        struct Nfc;
+
+        #[doc = "Such caf\u{e9} ﬁ"]
+        #[derive(Documented)]
+        #[documented(normalize = "nfd")]
+        struct Nfd;
+
+        #[doc = "Such cafe\u{301} ﬁ"]
+        #[derive(Documented)]
+        #[documented(normalize = "nfkc")]
+        struct Nfkc;
+
+        #[doc = "Such caf\u{e9} ﬁ"]
+        #[derive(Documented)]
+        #[documented(normalize = "nfkd")]
+        struct Nfkd;
+
+        assert_eq!(Nfc::DOCS, "Such caf\u{e9} ﬁ");
+        assert_eq!(Nfd::DOCS, "Such cafe\u{301} ﬁ");
+        assert_eq!(Nfkc::DOCS, "Such caf\u{e9} fi");
+        assert_eq!(Nfkd::DOCS, "Such cafe\u{301} fi");
+    }
+
+    #[test]
+    fn strip_markdown_works() {
+        /// ## Wow
+        ///
+        /// Such `code`, very ~~strike~~
+        ///
+        /// ```
+        /// let _much = *code*;
+        /// ```
+        #[derive(Documented)]
+        #[documented(strip_markdown)]
+        struct Doge;
+
+        assert_eq!(
+            Doge::DOCS,
+            "Wow\n\nSuch code, very ~~strike~~\n\nlet _much = *code*;"
+        );
+    }
+
+    #[test]
+    fn dedent_works() {
+        /// Wow
+        ///
+        /// ```
+        /// fn such_indent() {
+        ///     very_nested();
+        /// }
+        /// ```
+        #[derive(Documented)]
+        #[documented(dedent)]
+        struct Doge;
+
+        ///     Much
+        ///       indent
+        #[derive(Documented)]
+        #[documented(dedent, trim = false)]
+        struct Kabuso;
+
+        assert_eq!(
+            Doge::DOCS,
+            "Wow\n\n```\nfn such_indent() {\n    very_nested();\n}\n```"
+        );
+        assert_eq!(Kabuso::DOCS, "Much\n  indent");
+    }
+
+    #[test]
+    fn trim_start_and_end_work() {
+        #[doc = "  Such  "]
+        #[doc = "  trim  "]
+        #[derive(Documented)]
+        #[documented(trim_end = false)]
+        struct Doge;
+
+        #[doc = "  Very  "]
+        #[derive(Documented)]
+        #[documented(dedent, trim_end = false)]
+        struct Kabuso;
+
+        assert_eq!(Doge::DOCS, "Such  \ntrim  ");
+        assert_eq!(Kabuso::DOCS, "Very  ");
+    }
+
+    #[test]
+    fn separator_works() {
+        /// Such
+        /// separator
+        ///
+        /// Very paragraph
+        #[derive(Documented)]
+        #[documented(separator = "\r\n", lines, paragraphs, summary, wrap = 9)]
+        struct Doge;
+
+        assert_eq!(Doge::DOCS, "Such\r\nseparator\r\n\r\nVery\r\nparagraph");
+        assert_eq!(Doge::DOCS_SUMMARY, "Such\r\nseparator");
+        assert_eq!(
+            Doge::DOCS_LINES,
+            ["Such", "separator", "", "Very", "paragraph"]
+        );
+        assert_eq!(
+            Doge::DOCS_PARAGRAPHS,
+            ["Such\r\nseparator", "Very\r\nparagraph"]
+        );
+    }
+
+    #[test]
+    fn unwrap_lines_works() {
+        /// Such
+        /// unwrap
+        ///
+        /// ```
+        /// very
+        /// code
+        /// ```
+        /// Much
+        /// paragraph
+        #[derive(Documented)]
+        #[documented(unwrap_lines)]
+        struct Doge;
+
+        assert_eq!(
+            Doge::DOCS,
+            "Such unwrap\n\n```\nvery\ncode\n```\nMuch paragraph"
+        );
+    }
+
+    #[test]
+    fn blank_lines_work() {
+        /** Such block
+
+        */
+        ///
+        /// Very line
+        ///
+        #[derive(Documented)]
+        #[documented(collapse_blank_lines)]
+        struct Doge;
+
+        /// Much
+        ///
+        /// blank
+        #[derive(Documented)]
+        #[documented(keep_blank_lines = false)]
+        struct Kabuso;
+
+        assert_eq!(Doge::DOCS, "Such block\n\nVery line");
+        assert_eq!(Kabuso::DOCS, "Much\nblank");
+    }
+
+    #[test]
+    fn newlines_and_tabs_work() {
+        #[doc = "Such windows\r\n\tvery tab\rmuch mac"]
+        #[derive(Documented)]
+        #[documented(trim = false, normalize_newlines, expand_tabs = 4)]
+        struct Doge;
+
+        assert_eq!(Doge::DOCS, "Such windows\n    very tab\nmuch mac");
+    }
+
+    #[test]
+    fn prefix_and_suffix_work() {
+        /// Such docs
+        #[derive(Documented)]
+        #[documented(prefix = "Wow: ", suffix = "\nvery manual")]
+        struct Doge;
+
+        assert_eq!(Doge::DOCS, "Wow: Such docs\nvery manual");
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn deprecation_works() {
+        /// Such docs
+        #[derive(Documented)]
+        #[documented(deprecation)]
+        #[deprecated(since = "0.4.20", note = "very old")]
+        struct Doge;
+
+        /// Much docs
+        #[derive(Documented)]
+        #[documented(deprecation)]
+        #[deprecated = "much old"]
+        struct Kabuso;
+
+        /// Wow docs
+        #[derive(Documented)]
+        #[documented(deprecation)]
+        struct Cheems;
+
+        assert_eq!(Doge::DEPRECATION, Some("very old"));
+        assert_eq!(Kabuso::DEPRECATION, Some("much old"));
+        assert_eq!(Cheems::DEPRECATION, None);
+    }
+
+    #[test]
+    fn interpolate_works() {
+        /// Such {CARGO_PKG_NAME}, very {{CARGO_PKG_NAME}}, much {}
+        #[derive(Documented)]
+        #[documented(interpolate)]
+        struct Doge;
+
+        assert_eq!(
+            Doge::DOCS,
+            "Such documented-test, very {CARGO_PKG_NAME}, much {}"
+        );
+    }
+
+    #[test]
+    fn inherit_works() {
+        /// Such inner
+        #[derive(Documented)]
+        struct Inner;
+
+        #[derive(Documented)]
+        #[documented(inherit)]
+        #[allow(dead_code)]
+        struct Doge(Inner);
+
+        /// Very own
+        #[derive(Documented)]
+        #[documented(inherit)]
+        #[allow(dead_code)]
+        struct Own {
+            inner: Inner,
+        }
+
+        assert_eq!(Doge::DOCS, "Such inner");
+        assert_eq!(Own::DOCS, "Very own");
+    }
+
+    #[test]
+    fn inherit_generic_works() {
+        /// Such inner
+        #[derive(Documented)]
+        struct Inner;
+
+        #[derive(Documented)]
+        #[documented(inherit, impl_display)]
+        #[allow(dead_code)]
+        struct Doge<T>(T);
+
+        assert_eq!(Doge::<Inner>::DOCS, "Such inner");
+        assert_eq!(Doge(Inner).to_string(), "Such inner");
+    }
+
+    #[test]
+    fn remote_works() {
+        mod remote {
+            pub struct Doge<T> {
+                pub wow: T,
+            }
+        }
+
+        /// Such remote
+        #[derive(Documented)]
+        #[documented(remote = "remote::Doge")]
+        #[allow(dead_code)]
+        struct Doge<T> {
+            wow: T,
+        }
+
+        assert_eq!(Doge::<u8>::DOCS, "Such remote");
+    }
+
+    #[test]
+    fn summary_works() {
+        /// Wow
+        /// such summary
+        ///
+        /// Much detail
+        #[derive(Documented)]
+        #[documented(summary)]
+        struct Doge;
+
+        /// Very "escape"
+        #[derive(Documented)]
+        #[documented(summary, escape = "json")]
+        struct Kabuso;
+
+        assert_eq!(Doge::DOCS_SUMMARY, "Wow\nsuch summary");
+        assert_eq!(Doge::DOCS, "Wow\nsuch summary\n\nMuch detail");
+        assert_eq!(Kabuso::DOCS_SUMMARY, r#"Very \"escape\""#);
+    }
+
+    #[test]
+    fn lines_and_paragraphs_work() {
+        /// Wow
+        /// such lines
+        ///
+        ///
+        /// Much paragraphs
+        #[derive(Documented)]
+        #[documented(lines, paragraphs)]
+        struct Doge;
+
+        assert_eq!(
+            Doge::DOCS_LINES,
+            ["Wow", "such lines", "", "", "Much paragraphs"]
+        );
+        assert_eq!(
+            Doge::DOCS_PARAGRAPHS,
+            ["Wow\nsuch lines", "Much paragraphs"]
+        );
+    }
+
+    #[test]
+    fn sections_work() {
+        /// # Wow
+        ///
+        /// Such section
+        ///
+        /// ## Much example
+        ///
+        /// ```
+        /// # let doge = "hidden";
+        /// ```
+        ///
+        /// #Very not heading
+        /// # Empty
+        #[derive(Documented)]
+        #[documented(sections)]
+        struct Doge;
+
+        assert_eq!(
+            Doge::DOCS_SECTIONS,
+            [
+                ("Wow", "Such section"),
+                (
+                    "Much example",
+                    "```\n# let doge = \"hidden\";\n```\n\n#Very not heading"
+                ),
+                ("Empty", ""),
+            ]
+        );
+    }
+
+    #[test]
+    fn examples_work() {
+        /// Wow
+        ///
+        /// ```
+        /// let doge = 1;
+        /// if doge > 0 {
+        ///     println!("such example");
+        /// }
+        /// ```
+        ///
+        ///   ```text
+        ///   much
+        ///  indent
+        ///   ```
+        #[derive(Documented)]
+        #[documented(examples)]
+        struct Doge;
+
+        assert_eq!(
+            Doge::DOCS_EXAMPLES,
+            [
+                "let doge = 1;\nif doge > 0 {\n    println!(\"such example\");\n}",
+                "much\nindent"
+            ]
+        );
+    }
+
+    #[test]
+    fn tags_work() {
+        /// Wow
+        /// @such: tag
+        /// @very-tag.name: much value
+        /// @ not tag
+        /// @doge:
+        #[derive(Documented)]
+        #[documented(tags)]
+        struct Doge;
+
+        assert_eq!(Doge::DOCS, "Wow\n@ not tag");
+        assert_eq!(
+            Doge::DOCS_TAGS,
+            [
+                ("such", "tag"),
+                ("very-tag.name", "much value"),
+                ("doge", "")
+            ]
+        );
+    }
+
+    #[test]
+    fn crate_path_works() {
+        mod doge_facade {
+            pub use documented;
+        }
+
+        /// Such re-export
+        #[derive(Documented)]
+        #[documented(crate = "doge_facade::documented")]
+        struct Doge;
+
+        assert_eq!(Doge::DOCS, "Such re-export");
+    }
+
+    #[test]
+    fn inherent_works() {
+        mod doge_mod {
+            /// Such inherent
+            #[derive(documented::Documented)]
+            #[documented(inherent)]
+            pub struct Doge;
+
+            /// Very inherent
+            #[derive(documented::DocumentedOpt)]
+            #[documented(inherent)]
+            pub struct DogeOpt;
+        }
+
+        assert_eq!(doge_mod::Doge::DOCS, "Such inherent");
+        assert_eq!(doge_mod::DogeOpt::DOCS, Some("Very inherent"));
+    }
 }
@@ -16,11 +16,12 @@ fn it_works() {
     assert_eq!(Foo::get_field_docs("second"), Ok("2"));
     assert_eq!(
         Foo::get_field_docs("third"),
-        Err(Error::NoSuchField("third".into()))
+        Err(Error::NoSuchField { name: "third".into(), suggestion: None })
     );
 }
 
 #[test]
+#[allow(deprecated)] // not opted in with `variants`
 fn enum_works() {
     #[derive(DocumentedFields)]
     #[allow(dead_code)]
@@ -36,7 +37,7 @@ fn enum_works() {
     assert_eq!(Bar::get_field_docs("Second"), Ok("2"));
     assert_eq!(
         Bar::get_field_docs("Third"),
-        Err(Error::NoSuchField("Third".into()))
+        Err(Error::NoSuchField { name: "Third".into(), suggestion: None })
     );
 }
 
@@ -75,6 +76,27 @@ fn unnamed_fields() {
     assert_eq!(Foo::FIELD_DOCS[2], "2");
 }
 
+#[test]
+fn by_index_works() {
+    #[derive(DocumentedFields)]
+    #[allow(dead_code)]
+    struct Foo(
+        /// 0
+        i32,
+        /// 1
+        u32,
+    );
+
+    assert_eq!(Foo::FIELD_COUNT, 2);
+    assert_eq!(Foo::get_field_docs_by_index(1), Ok("1"));
+    assert_eq!(Foo::get_field_docs("1"), Ok("1"));
+    assert!(Foo::FIELD_NAMES.is_empty());
+    assert_eq!(
+        Foo::get_field_docs_by_index(2),
+        Err(documented::Error::NoSuchField { name: "2".to_string(), suggestion: None })
+    );
+}
+
 #[test]
 fn generic_type_works() {
     #[derive(DocumentedFields)]
@@ -124,9 +146,120 @@ fn lifetimed_type_works() {
     assert_eq!(Foo::get_field_docs("foo"), Ok("foo"));
 }
 
+#[test]
+fn field_docs_iter_works() {
+    #[derive(DocumentedFields)]
+    #[allow(dead_code)]
+    struct Foo {
+        /// 1
+        first: i32,
+        /// 2
+        second: i32,
+    }
+
+    #[derive(DocumentedFields)]
+    #[allow(dead_code)]
+    struct Bar(
+        /// 1
+        i32,
+    );
+
+    let iter = Foo::field_docs_iter();
+    assert_eq!(iter.len(), 2);
+    assert_eq!(
+        iter.collect::<Vec<_>>(),
+        [(Some("first"), "1"), (Some("second"), "2")]
+    );
+    assert_eq!(Bar::field_docs_iter().collect::<Vec<_>>(), [(None, "1")]);
+}
+
+#[test]
+fn doc_macros_work() {
+    #[derive(DocumentedFields)]
+    #[allow(dead_code)]
+    struct Doge {
+        #[doc = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/docs/doge.md"))]
+        wow: u8,
+        #[doc = env!("CARGO_PKG_NAME")]
+        much: u8,
+    }
+
+    assert_eq!(
+        Doge::FIELD_DOCS,
+        ["Such include\n\nvery markdown\n", "documented-test"]
+    );
+}
+
+#[test]
+fn cfg_works() {
+    #[derive(DocumentedFields)]
+    #[allow(dead_code)]
+    struct Doge {
+        /// Such release
+        #[cfg(not(test))]
+        wow: u8,
+        /// Very test
+        #[cfg(test)]
+        much: u8,
+    }
+
+    assert_eq!(Doge::FIELD_DOCS, ["Very test"]);
+    assert_eq!(Doge::FIELD_NAMES, ["much"]);
+    assert_eq!(Doge::get_field_docs("much"), Ok("Very test"));
+    assert!(Doge::get_field_docs("wow").is_err());
+}
+
+#[test]
+fn doc_alias_works() {
+    #[derive(DocumentedFields)]
+    #[allow(dead_code)]
+    struct Doge {
+        /// Such field
+        #[doc(alias = "such", alias("very", "much"))]
+        wow: u8,
+        /// Very field
+        #[doc(hidden, alias = "wow", alias = "much")]
+        very: u8,
+    }
+
+    assert_eq!(Doge::get_field_docs("such"), Ok("Such field"));
+    assert_eq!(Doge::get_field_docs("much"), Ok("Such field"));
+    assert_eq!(Doge::get_field_docs("very"), Ok("Very field"));
+    assert_eq!(Doge::get_field_docs("wow"), Ok("Such field"));
+    assert_eq!(Doge::FIELD_NAMES, ["wow", "very"]);
+}
+
+#[test]
+fn suggestion_works() {
+    #[derive(DocumentedFields)]
+    #[allow(dead_code)]
+    struct Doge {
+        /// Such suggestion
+        such_suggestion: u8,
+        /// Wow
+        wow: u8,
+    }
+
+    let err = Doge::get_field_docs("such_sugestion").unwrap_err();
+    assert_eq!(err.suggestion(), Some("such_suggestion"));
+    assert_eq!(
+        err.to_string(),
+        r#"No field named "such_sugestion" exists; did you mean "such_suggestion"?"#
+    );
+    assert_eq!(
+        Doge::get_field_docs("WOW").unwrap_err().suggestion(),
+        Some("wow")
+    );
+    assert_eq!(Doge::get_field_docs("much").unwrap_err().suggestion(), None);
+    assert_eq!(
+        Doge::get_field_docs("much").unwrap_err().to_string(),
+        r#"No field named "much" exists"#
+    );
+}
+
 #[cfg(feature = "customise")]
 mod test_customise {
-    use documented::DocumentedFields;
+    use documented::{DocumentedFields, Error};
 
     #[test]
     fn empty_customise_works() {
@@ -214,7 +347,7 @@ mod test_customise {
     #[test]
     fn default_works() {
         #[derive(DocumentedFields)]
-        #[documented_fields(default = "Woosh")]
+        #[documented_fields(variants, default = "Woosh")]
         #[allow(dead_code)]
         enum Mission {
             /// Rumble
@@ -282,4 +415,821 @@ mod test_customise {
         assert_eq!(OkYouWin::FIELD_DOCS, ["Leave me alone.", "Just kidding."]);
         assert_eq!(OkYouWin::get_field_docs("ahhh"), Ok("Leave me alone."));
     }
+
+    #[test]
+    fn field_vis_works() {
+        mod inner {
+            #[derive(super::DocumentedFields)]
+            #[documented_fields(field_vis)]
+            #[allow(dead_code)]
+            pub struct Foo {
+                /// 1
+                pub first: i32,
+                /// 2
+                pub(crate) second: i32,
+                /// 3
+                pub(super) third: i32,
+                /// 4
+                pub(in crate::derive) fourth: i32,
+                /// 5
+                fifth: i32,
+            }
+        }
+
+        assert_eq!(
+            inner::Foo::FIELD_VIS,
+            [
+                "pub",
+                "pub(crate)",
+                "pub(super)",
+                "pub(in crate::derive)",
+                ""
+            ]
+        );
+    }
+
+    #[test]
+    fn field_vis_tuple_struct_works() {
+        #[derive(DocumentedFields)]
+        #[documented_fields(field_vis = true)]
+        #[allow(dead_code)]
+        struct Foo(
+            /// 1
+            pub i32,
+            /// 2
+            i32,
+        );
+
+        assert_eq!(Foo::FIELD_VIS, ["pub", ""]);
+    }
+
+    #[test]
+    fn variants_works() {
+        #[derive(DocumentedFields)]
+        #[documented_fields(variants = true)]
+        #[allow(dead_code)]
+        enum Bar {
+            /// 1
+            First,
+            /// 2
+            Second(u8),
+        }
+
+        assert_eq!(Bar::FIELD_DOCS, ["1", "2"]);
+        assert_eq!(Bar::get_field_docs("Second"), Ok("2"));
+    }
+
+    #[test]
+    fn nested_works() {
+        #[derive(DocumentedFields)]
+        #[allow(dead_code)]
+        struct Outer {
+            /// 1
+            #[documented_fields(nested)]
+            middle: Middle,
+            /// 2
+            plain: u8,
+        }
+
+        #[derive(DocumentedFields)]
+        #[documented_fields(rename_all = "kebab-case")]
+        #[allow(dead_code)]
+        struct Middle {
+            /// 1.1
+            #[documented_fields(nested)]
+            inner_most: Inner,
+        }
+
+        #[derive(DocumentedFields)]
+        #[allow(dead_code)]
+        struct Inner {
+            /// 1.1.1
+            value: u8,
+        }
+
+        assert_eq!(Outer::get_field_docs_by_path(["middle"]), Ok("1"));
+        assert_eq!(
+            Outer::get_field_docs_by_path(["middle", "inner-most"]),
+            Ok("1.1")
+        );
+        assert_eq!(
+            Outer::get_field_docs_by_path("middle.inner-most.value".split('.')),
+            Ok("1.1.1")
+        );
+        assert_eq!(
+            Outer::get_field_docs_by_path(["middle", "inner-most", "nope"]),
+            Err(Error::NoSuchField {
+                name: "middle.inner-most.nope".to_string(),
+                suggestion: None,
+            })
+        );
+        assert_eq!(
+            Outer::get_field_docs_by_path(["plain", "nope"]),
+            Err(Error::NoSuchField {
+                name: "plain.nope".to_string(),
+                suggestion: None,
+            })
+        );
+        assert_eq!(
+            Outer::get_field_docs_by_path(["nope", "nope"]),
+            Err(Error::NoSuchField {
+                name: "nope".to_string(),
+                suggestion: None,
+            })
+        );
+        assert_eq!(
+            Outer::get_field_docs_by_path::<_, &str>([]),
+            Err(Error::NoSuchField { name: "".to_string(), suggestion: None })
+        );
+    }
+
+    #[test]
+    fn nested_generic_works() {
+        #[derive(DocumentedFields)]
+        #[allow(dead_code)]
+        struct Outer<T> {
+            /// 1
+            #[documented_fields(nested)]
+            inner: T,
+        }
+
+        #[derive(DocumentedFields)]
+        #[allow(dead_code)]
+        struct Inner {
+            /// 1.1
+            value: u8,
+        }
+
+        assert_eq!(
+            Outer::<Inner>::get_field_docs_by_path(["inner", "value"]),
+            Ok("1.1")
+        );
+    }
+
+    #[test]
+    fn with_types_works() {
+        #[derive(DocumentedFields)]
+        #[documented_fields(with_types)]
+        #[allow(dead_code)]
+        struct Doge<'a, T> {
+            /// Such reference
+            name: &'a str,
+            /// Very generic
+            age: Option<T>,
+            /// Much path
+            #[documented_fields(rename = "wow")]
+            time: std::time::Duration,
+            /// Amaze
+            #[documented_fields(with_types = false)]
+            good_boi: bool,
+            #[documented_fields(default = "Excite")]
+            tail: (u8, [bool; 2]),
+        }
+
+        assert_eq!(
+            Doge::<u8>::FIELD_DOCS,
+            [
+                "Such reference (type: &'a str)",
+                "Very generic (type: Option<T>)",
+                "Much path (type: std::time::Duration)",
+                "Amaze",
+                "Excite",
+            ]
+        );
+    }
+
+    #[test]
+    fn with_types_per_field_works() {
+        #[derive(DocumentedFields)]
+        #[allow(dead_code)]
+        struct Doge(
+            /// Such tuple
+            #[documented_fields(with_types)]
+            Vec<u8>,
+            /// Wow
+            u8,
+        );
+
+        assert_eq!(Doge::FIELD_DOCS, ["Such tuple (type: Vec<u8>)", "Wow"]);
+    }
+
+    #[test]
+    fn normalize_works() {
+        #[derive(DocumentedFields)]
+        #[documented_fields(normalize = "nfc")]
+        #[allow(dead_code)]
+        struct Doge {
+            #[doc = "Such cafe\u{301}"]
+            coffee: u8,
+            #[doc = "Very cafe\u{301}"]
+            #[documented_fields(normalize = "nfd")]
+            more_coffee: u8,
+        }
+
+        assert_eq!(Doge::FIELD_DOCS, ["Such caf\u{e9}", "Very cafe\u{301}"]);
+    }
+
+    #[test]
+    fn strip_markdown_works() {
+        #[derive(DocumentedFields)]
+        #[allow(dead_code)]
+        struct Doge {
+            /// # Such heading
+            ///
+            /// Very [link][Doge]
+            #[documented_fields(strip_markdown)]
+            wow: u8,
+            /// Much __markdown__
+            much: u8,
+        }
+
+        assert_eq!(
+            Doge::FIELD_DOCS,
+            ["Such heading\n\nVery link", "Much __markdown__"]
+        );
+    }
+
+    #[test]
+    fn dedent_works() {
+        #[derive(DocumentedFields)]
+        #[documented_fields(dedent)]
+        #[allow(dead_code)]
+        struct Doge {
+            /// Such
+            ///   indent
+            wow: u8,
+            /// Very
+            ///   trim
+            #[documented_fields(dedent = false)]
+            much: u8,
+        }
+
+        assert_eq!(Doge::FIELD_DOCS, ["Such\n  indent", "Very\ntrim"]);
+    }
+
+    #[test]
+    fn trim_start_and_end_work() {
+        #[derive(DocumentedFields)]
+        #[documented_fields(trim_start = false)]
+        #[allow(dead_code)]
+        struct Doge {
+            #[doc = "  Such  "]
+            wow: u8,
+            #[doc = "  Very  "]
+            #[documented_fields(trim = false, trim_start)]
+            much: u8,
+        }
+
+        assert_eq!(Doge::FIELD_DOCS, ["  Such", "Very  "]);
+    }
+
+    #[test]
+    fn separator_works() {
+        #[derive(DocumentedFields)]
+        #[documented_fields(separator = " ")]
+        #[allow(dead_code)]
+        struct Doge {
+            /// Such
+            /// separator
+            wow: u8,
+            /// Very
+            /// default
+            #[documented_fields(separator = "\n")]
+            much: u8,
+        }
+
+        assert_eq!(Doge::FIELD_DOCS, ["Such separator", "Very\ndefault"]);
+    }
+
+    #[test]
+    fn unwrap_lines_works() {
+        #[derive(DocumentedFields)]
+        #[allow(dead_code)]
+        struct Doge {
+            /// Such
+            /// unwrap
+            #[documented_fields(unwrap_lines)]
+            wow: u8,
+            /// Very
+            /// lines
+            much: u8,
+        }
+
+        assert_eq!(Doge::FIELD_DOCS, ["Such unwrap", "Very\nlines"]);
+    }
+
+    #[test]
+    fn wrap_works() {
+        #[derive(DocumentedFields)]
+        #[documented_fields(wrap = 10)]
+        #[allow(dead_code)]
+        struct Doge {
+            /// Such wrap very width
+            wow: u8,
+            /// Much wrap very width
+            #[documented_fields(wrap = 20)]
+            much: u8,
+        }
+
+        assert_eq!(
+            Doge::FIELD_DOCS,
+            ["Such wrap\nvery width", "Much wrap very width"]
+        );
+    }
+
+    #[test]
+    fn blank_lines_work() {
+        #[derive(DocumentedFields)]
+        #[documented_fields(keep_blank_lines = false)]
+        #[allow(dead_code)]
+        struct Doge {
+            /// Such
+            ///
+            /// blank
+            wow: u8,
+            /// Very
+            ///
+            ///
+            /// blank
+            #[documented_fields(keep_blank_lines, collapse_blank_lines)]
+            much: u8,
+        }
+
+        assert_eq!(Doge::FIELD_DOCS, ["Such\nblank", "Very\n\nblank"]);
+    }
+
+    #[test]
+    fn newlines_and_tabs_work() {
+        #[derive(DocumentedFields)]
+        #[documented_fields(trim = false, normalize_newlines)]
+        #[allow(dead_code)]
+        struct Doge {
+            #[doc = "Such\r\nwindows"]
+            wow: u8,
+            #[doc = "Very\ttab"]
+            #[documented_fields(expand_tabs = 1)]
+            much: u8,
+        }
+
+        assert_eq!(Doge::FIELD_DOCS, ["Such\nwindows", "Very tab"]);
+    }
+
+    #[test]
+    fn prefix_and_suffix_work() {
+        #[derive(DocumentedFields)]
+        #[documented_fields(suffix = "\nSee the manual for details.")]
+        #[allow(dead_code)]
+        struct Doge {
+            /// Such field
+            wow: u8,
+            /// Very field
+            #[documented_fields(prefix = "Much ", suffix = "")]
+            much: u8,
+        }
+
+        assert_eq!(
+            Doge::FIELD_DOCS,
+            ["Such field\nSee the manual for details.", "Much Very field"]
+        );
+    }
+
+    #[test]
+    fn deprecation_works() {
+        #[derive(DocumentedFields)]
+        #[documented_fields(deprecation)]
+        #[allow(dead_code, deprecated)]
+        struct Doge {
+            /// Such field
+            #[deprecated(since = "0.4.20")]
+            wow: u8,
+            /// Very field
+            #[deprecated(note = "much old")]
+            much: u8,
+            /// Many field
+            many: u8,
+        }
+
+        assert_eq!(Doge::FIELD_DEPRECATIONS, [Some(""), Some("much old"), None]);
+    }
+
+    #[test]
+    fn doc_works() {
+        #[derive(DocumentedFields)]
+        #[allow(dead_code)]
+        struct Doge {
+            /// Such rustdoc
+            #[documented_fields(doc = "  Very runtime  ")]
+            wow: u8,
+            #[documented_fields(doc = "Much undocumented")]
+            much: u8,
+        }
+
+        assert_eq!(Doge::FIELD_DOCS, ["Very runtime", "Much undocumented"]);
+    }
+
+    #[test]
+    fn interpolate_works() {
+        #[derive(DocumentedFields)]
+        #[allow(dead_code)]
+        struct Doge {
+            /// Such {CARGO_PKG_NAME}
+            #[documented_fields(interpolate)]
+            wow: u8,
+            /// Very {CARGO_PKG_NAME}
+            much: u8,
+        }
+
+        assert_eq!(
+            Doge::FIELD_DOCS,
+            ["Such documented-test", "Very {CARGO_PKG_NAME}"]
+        );
+    }
+
+    #[test]
+    fn alias_works() {
+        #[derive(DocumentedFields)]
+        #[documented_fields(rename_all = "camelCase")]
+        #[allow(dead_code)]
+        struct Doge {
+            /// Such connections
+            #[documented_fields(alias = "max_conns", alias = "maxConns")]
+            max_connections: u8,
+            /// Wow
+            #[documented_fields(alias = "woof")]
+            bark: u8,
+        }
+
+        assert_eq!(Doge::FIELD_NAMES, ["maxConnections", "bark"]);
+        assert_eq!(
+            Doge::get_field_docs("maxConnections"),
+            Ok("Such connections")
+        );
+        assert_eq!(Doge::get_field_docs("max_conns"), Ok("Such connections"));
+        assert_eq!(Doge::get_field_docs("maxConns"), Ok("Such connections"));
+        assert_eq!(Doge::get_field_docs("woof"), Ok("Wow"));
+        assert_eq!(
+            Doge::get_field_docs("max_connections"),
+            Err(documented::Error::NoSuchField {
+                name: "max_connections".to_string(),
+                suggestion: Some("maxConnections"),
+            })
+        );
+    }
+
+    #[test]
+    fn alias_on_unnamed_field_works() {
+        #[derive(DocumentedFields)]
+        #[allow(dead_code)]
+        struct Doge(
+            /// Such tuple
+            #[documented_fields(alias = "first")]
+            u8,
+        );
+
+        assert_eq!(Doge::get_field_docs("first"), Ok("Such tuple"));
+    }
+
+    #[test]
+    fn cfg_attr_works() {
+        #[derive(DocumentedFields)]
+        #[cfg_attr(test, documented_fields(rename_all = "kebab-case"))]
+        #[cfg_attr(not(test), documented_fields(rename_all = "UPPERCASE"))]
+        #[allow(dead_code)]
+        struct Doge {
+            /// Such cfg
+            #[cfg_attr(test, documented_fields(rename = "wow"))]
+            such_cfg: u8,
+            /// Very attr
+            #[cfg_attr(not(test), documented_fields(rename = "nope"))]
+            very_attr: u8,
+            #[cfg_attr(test, doc = "Much doc")]
+            much_doc: u8,
+        }
+
+        assert_eq!(Doge::FIELD_NAMES, ["wow", "very-attr", "much-doc"]);
+        assert_eq!(Doge::FIELD_DOCS, ["Such cfg", "Very attr", "Much doc"]);
+    }
+
+    #[test]
+    fn field_enum_works() {
+        #[derive(DocumentedFields)]
+        #[documented_fields(field_enum, rename_all = "kebab-case")]
+        #[allow(dead_code)]
+        struct Doge {
+            /// Such field
+            such_field: u8,
+            /// Very raw
+            r#type: u8,
+        }
+
+        assert_eq!(
+            Doge::get_field_docs_typed(DogeField::SuchField),
+            "Such field"
+        );
+        assert_eq!(Doge::get_field_docs_typed(DogeField::Type), "Very raw");
+    }
+
+    #[test]
+    fn field_enum_unnamed_fields_works() {
+        #[derive(DocumentedFields)]
+        #[documented_fields(field_enum)]
+        #[allow(dead_code)]
+        struct Doge(
+            /// Such tuple
+            u8,
+            /// Very index
+            u8,
+        );
+
+        assert_eq!(Doge::get_field_docs_typed(DogeField::Field0), "Such tuple");
+        assert_eq!(Doge::get_field_docs_typed(DogeField::Field1), "Very index");
+    }
+
+    #[test]
+    fn field_enum_variants_works() {
+        #[derive(DocumentedFields)]
+        #[documented_fields(field_enum, variants)]
+        #[allow(dead_code)]
+        enum Doge {
+            /// Such variant
+            Wow,
+            /// Much lookup
+            MuchLookup,
+        }
+
+        const DOCS: &str = Doge::get_field_docs_typed(DogeField::MuchLookup);
+        assert_eq!(DOCS, "Much lookup");
+        assert_eq!(Doge::get_field_docs_typed(DogeField::Wow), "Such variant");
+    }
+
+    #[test]
+    fn skip_works() {
+        #[derive(DocumentedFields)]
+        #[documented_fields(field_vis, field_enum)]
+        #[allow(dead_code)]
+        struct Doge {
+            /// Such secret
+            #[documented_fields(skip)]
+            secret: u8,
+            /// Very public
+            pub public: u8,
+            /// Much nested
+            #[documented_fields(nested)]
+            nested: Inner,
+        }
+
+        /// Wow
+        #[derive(DocumentedFields)]
+        #[allow(dead_code)]
+        struct Inner {
+            /// Wow
+            wow: u8,
+        }
+
+        assert_eq!(Doge::FIELD_NAMES, ["public", "nested"]);
+        assert_eq!(Doge::FIELD_DOCS, ["Very public", "Much nested"]);
+        assert_eq!(Doge::FIELD_VIS, ["pub", ""]);
+        assert_eq!(Doge::get_field_docs_typed(DogeField::Nested), "Much nested");
+        assert_eq!(Doge::get_field_docs_by_path(["nested", "wow"]), Ok("Wow"));
+        assert_eq!(
+            Doge::get_field_docs("secret"),
+            Err(documented::Error::NoSuchField {
+                name: "secret".to_string(),
+                suggestion: None,
+            })
+        );
+    }
+
+    #[test]
+    fn flatten_works() {
+        #[derive(DocumentedFields)]
+        #[allow(dead_code)]
+        struct Doge {
+            /// Such flat
+            #[documented_fields(flatten)]
+            flat: Flat,
+            /// Very shadow
+            shadowed: u8,
+        }
+
+        #[derive(DocumentedFields)]
+        #[allow(dead_code)]
+        struct Flat {
+            /// Wow
+            wow: u8,
+            /// Much shadowed
+            shadowed: u8,
+            /// Many nested
+            #[documented_fields(nested)]
+            inner: Inner,
+        }
+
+        #[derive(DocumentedFields)]
+        #[allow(dead_code)]
+        struct Inner {
+            /// Amaze
+            amaze: u8,
+        }
+
+        assert_eq!(Doge::get_field_docs("flat"), Ok("Such flat"));
+        assert_eq!(Doge::get_field_docs("wow"), Ok("Wow"));
+        assert_eq!(Doge::get_field_docs("shadowed"), Ok("Very shadow"));
+        assert_eq!(
+            Doge::get_field_docs_by_path(["inner", "amaze"]),
+            Ok("Amaze")
+        );
+        assert_eq!(
+            Doge::get_field_docs("nope"),
+            Err(documented::Error::NoSuchField {
+                name: "nope".to_string(),
+                suggestion: None,
+            })
+        );
+    }
+
+    #[test]
+    fn flatten_generic_works() {
+        #[derive(DocumentedFields)]
+        #[allow(dead_code)]
+        struct Doge<T> {
+            /// Such generic
+            #[documented_fields(flatten)]
+            flat: T,
+        }
+
+        #[derive(DocumentedFields)]
+        #[allow(dead_code)]
+        struct Flat {
+            /// Wow
+            wow: u8,
+        }
+
+        assert_eq!(Doge::<Flat>::get_field_docs("wow"), Ok("Wow"));
+    }
+
+    #[test]
+    fn index_names_work() {
+        #[derive(DocumentedFields)]
+        #[allow(dead_code)]
+        struct Doge(
+            /// Such rename
+            #[documented_fields(rename = "1")]
+            u8,
+            /// Very index
+            u8,
+            /// Much index
+            u8,
+        );
+
+        assert_eq!(Doge::get_field_docs("0"), Ok("Such rename"));
+        assert_eq!(Doge::get_field_docs("1"), Ok("Such rename"));
+        assert_eq!(Doge::get_field_docs("2"), Ok("Much index"));
+    }
+
+    #[test]
+    fn case_insensitive_works() {
+        #[derive(DocumentedFields)]
+        #[documented_fields(case_insensitive, rename_all = "camelCase")]
+        #[allow(dead_code)]
+        struct Doge {
+            /// Such case
+            #[documented_fields(alias = "WOW")]
+            such_case: u8,
+            /// Very Ünicode
+            #[documented_fields(rename = "Ünicode")]
+            very_unicode: u8,
+        }
+
+        assert_eq!(Doge::FIELD_NAMES, ["suchCase", "Ünicode"]);
+        assert_eq!(Doge::get_field_docs("suchCase"), Ok("Such case"));
+        assert_eq!(Doge::get_field_docs("SUCHCASE"), Ok("Such case"));
+        assert_eq!(Doge::get_field_docs("wow"), Ok("Such case"));
+        assert_eq!(Doge::get_field_docs("üNICODE"), Ok("Very Ünicode"));
+        assert_eq!(
+            Doge::field_docs_iter().collect::<Vec<_>>(),
+            [
+                (Some("suchCase"), "Such case"),
+                (Some("Ünicode"), "Very Ünicode")
+            ]
+        );
+    }
+
+    #[test]
+    fn remote_works() {
+        mod remote {
+            pub struct Doge(pub u8, pub u8);
+
+            #[non_exhaustive]
+            #[allow(dead_code)]
+            pub enum Wow {
+                Such,
+                Very(u8),
+                Much { doge: u8 },
+            }
+        }
+
+        #[derive(DocumentedFields)]
+        #[documented_fields(remote = "remote::Doge")]
+        #[allow(dead_code)]
+        struct Doge(
+            /// Such remote
+            u8,
+            /// Very skip
+            #[documented_fields(skip)]
+            u8,
+        );
+
+        #[derive(DocumentedFields)]
+        #[documented_fields(remote = "remote::Wow", variants)]
+        #[allow(dead_code)]
+        enum Wow {
+            /// Such variant
+            Such,
+            /// Much variant
+            Much { doge: u8 },
+        }
+
+        assert_eq!(Doge::FIELD_DOCS, ["Such remote"]);
+        assert_eq!(Wow::get_field_docs("Much"), Ok("Much variant"));
+    }
+
+    #[test]
+    fn examples_work() {
+        #[derive(DocumentedFields)]
+        #[documented_fields(examples)]
+        #[allow(dead_code)]
+        struct Doge {
+            /// Such example
+            ///
+            /// ```
+            /// wow
+            /// ```
+            ///
+            /// ```
+            /// much
+            /// ```
+            such_example: u8,
+            /// Very none
+            very_none: u8,
+        }
+
+        assert_eq!(Doge::FIELD_EXAMPLES, [&["wow", "much"][..], &[]]);
+    }
+
+    #[test]
+    fn tags_work() {
+        #[derive(DocumentedFields)]
+        #[documented_fields(tags, default = "Much default")]
+        #[allow(dead_code)]
+        struct Doge {
+            /// Such tag
+            ///
+            /// @wow: 42
+            such_tag: u8,
+            /// @only: tag
+            very_tag: u8,
+        }
+
+        assert_eq!(Doge::FIELD_DOCS, ["Such tag", "Much default"]);
+        assert_eq!(Doge::FIELD_TAGS, [&[("wow", "42")][..], &[("only", "tag")]]);
+    }
+
+    #[test]
+    fn crate_path_works() {
+        mod doge_facade {
+            pub use documented;
+        }
+
+        #[derive(DocumentedFields)]
+        #[documented_fields(crate = "doge_facade::documented", field_enum)]
+        #[allow(dead_code)]
+        struct Doge {
+            /// Such re-export
+            wow: u8,
+        }
+
+        assert_eq!(Doge::FIELD_DOCS, ["Such re-export"]);
+        assert_eq!(Doge::get_field_docs("wow"), Ok("Such re-export"));
+    }
+
+    #[test]
+    fn const_lookup_works() {
+        #[derive(DocumentedFields)]
+        #[documented_fields(const_lookup, rename_all = "kebab-case")]
+        #[allow(dead_code)]
+        struct Doge {
+            /// Such const
+            #[documented_fields(alias = "wow")]
+            such_const: u8,
+            /// Very lookup
+            very_lookup: u8,
+        }
+
+        const SUCH_CONST: Option<&str> = Doge::get_field_docs_const("such-const");
+        assert_eq!(SUCH_CONST, Some("Such const"));
+        assert_eq!(Doge::get_field_docs_const("wow"), Some("Such const"));
+        assert_eq!(Doge::get_field_docs_const("very_lookup"), None);
+        assert_eq!(Doge::get_field_docs("very-lookup"), Ok("Very lookup"));
+        assert_eq!(Doge::get_field_docs("wow"), Ok("Such const"));
+    }
 }
@@ -22,11 +22,34 @@ fn it_works() {
     assert_eq!(Foo::get_field_docs("third"), Ok("3"));
     assert_eq!(
         Foo::get_field_docs("fourth"),
-        Err(Error::NoSuchField("fourth".into()))
+        Err(Error::NoSuchField { name: "fourth".into(), suggestion: None })
     );
 }
 
 #[test]
+fn by_index_works() {
+    #[derive(DocumentedFieldsOpt)]
+    #[allow(dead_code)]
+    struct Foo(
+        /// 0
+        i32,
+        u32,
+    );
+
+    assert_eq!(Foo::FIELD_COUNT, 2);
+    assert_eq!(Foo::get_field_docs_by_index(0), Ok("0"));
+    assert_eq!(
+        Foo::get_field_docs_by_index(1),
+        Err(Error::NoDocComments("1".into()))
+    );
+    assert_eq!(
+        Foo::get_field_docs_by_index(2),
+        Err(Error::NoSuchField { name: "2".into(), suggestion: None })
+    );
+}
+
+#[test]
+#[allow(deprecated)] // not opted in with `variants`
 fn enum_works() {
     #[derive(DocumentedFieldsOpt)]
     #[allow(dead_code)]
@@ -45,7 +68,7 @@ fn enum_works() {
     assert_eq!(Bar::get_field_docs("Second"), Ok("2"));
     assert_eq!(
         Bar::get_field_docs("Third"),
-        Err(Error::NoSuchField("Third".into()))
+        Err(Error::NoSuchField { name: "Third".into(), suggestion: None })
     );
 }
 
@@ -73,6 +96,22 @@ fn union_works() {
     );
 }
 
+#[test]
+fn field_docs_iter_works() {
+    #[derive(DocumentedFieldsOpt)]
+    #[allow(dead_code)]
+    struct Foo {
+        /// 1
+        first: i32,
+        second: i32,
+    }
+
+    assert_eq!(
+        Foo::field_docs_iter().collect::<Vec<_>>(),
+        [(Some("first"), Some("1")), (Some("second"), None)]
+    );
+}
+
 #[cfg(feature = "customise")]
 mod test_customise {
     use documented::{DocumentedFieldsOpt, Error};
@@ -80,7 +119,7 @@ mod test_customise {
     #[test]
     fn default_works() {
         #[derive(DocumentedFieldsOpt)]
-        #[documented_fields(default = Some("Woosh"))]
+        #[documented_fields(variants, default = Some("Woosh"))]
         #[allow(dead_code)]
         enum Mission {
             /// Rumble
@@ -104,4 +143,134 @@ mod test_customise {
         );
         assert_eq!(Mission::get_field_docs("Touchdown"), Ok("Boom"));
     }
+
+    #[test]
+    fn nested_works() {
+        #[derive(DocumentedFieldsOpt)]
+        #[allow(dead_code)]
+        struct Outer {
+            #[documented_fields(nested)]
+            inner: Inner,
+        }
+
+        #[derive(DocumentedFieldsOpt)]
+        #[allow(dead_code)]
+        struct Inner {
+            /// 1.1
+            documented: u8,
+            undocumented: u8,
+        }
+
+        assert_eq!(
+            Outer::get_field_docs_by_path(["inner"]),
+            Err(Error::NoDocComments("inner".to_string()))
+        );
+        assert_eq!(
+            Outer::get_field_docs_by_path(["inner", "documented"]),
+            Ok("1.1")
+        );
+        assert_eq!(
+            Outer::get_field_docs_by_path(["inner", "undocumented"]),
+            Err(Error::NoDocComments("inner.undocumented".to_string()))
+        );
+    }
+
+    #[test]
+    fn alias_works() {
+        #[derive(DocumentedFieldsOpt)]
+        #[allow(dead_code)]
+        struct Doge {
+            #[documented_fields(alias = "wow")]
+            undocumented: u8,
+        }
+
+        assert_eq!(
+            Doge::get_field_docs("wow"),
+            Err(Error::NoDocComments("wow".to_string()))
+        );
+    }
+
+    #[test]
+    fn field_enum_works() {
+        #[derive(DocumentedFieldsOpt)]
+        #[documented_fields(field_enum)]
+        #[allow(dead_code)]
+        struct Doge {
+            /// Such field
+            such_field: u8,
+            undocumented: u8,
+        }
+
+        assert_eq!(
+            Doge::get_field_docs_typed(DogeField::SuchField),
+            Some("Such field")
+        );
+        assert_eq!(Doge::get_field_docs_typed(DogeField::Undocumented), None);
+    }
+
+    #[test]
+    fn flatten_works() {
+        #[derive(DocumentedFieldsOpt)]
+        #[allow(dead_code)]
+        struct Doge {
+            #[documented_fields(flatten)]
+            flat: Flat,
+        }
+
+        #[derive(DocumentedFieldsOpt)]
+        #[allow(dead_code)]
+        struct Flat {
+            /// Wow
+            wow: u8,
+            undocumented: u8,
+        }
+
+        assert_eq!(Doge::get_field_docs("wow"), Ok("Wow"));
+        assert_eq!(
+            Doge::get_field_docs("undocumented"),
+            Err(Error::NoDocComments("undocumented".to_string()))
+        );
+        assert_eq!(
+            Doge::get_field_docs("flat"),
+            Err(Error::NoDocComments("flat".to_string()))
+        );
+    }
+
+    #[test]
+    fn require_all_works() {
+        #[derive(DocumentedFieldsOpt)]
+        #[documented_fields(require_all)]
+        #[allow(dead_code)]
+        struct Doge {
+            /// Such required
+            wow: u8,
+            #[documented_fields(doc = "Very override")]
+            such: u8,
+        }
+
+        assert_eq!(
+            Doge::FIELD_DOCS,
+            [Some("Such required"), Some("Very override")]
+        );
+    }
+
+    #[test]
+    fn const_lookup_works() {
+        #[derive(DocumentedFieldsOpt)]
+        #[documented_fields(const_lookup)]
+        #[allow(dead_code)]
+        struct Doge(
+            /// Such const
+            u8,
+            u8,
+        );
+
+        const FIRST: Option<&str> = Doge::get_field_docs_const("0");
+        assert_eq!(FIRST, Some("Such const"));
+        assert_eq!(Doge::get_field_docs_const("1"), None);
+        assert_eq!(
+            Doge::get_field_docs("1"),
+            Err(Error::NoDocComments("1".into()))
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use documented::DocumentedOpt;
+use documented::{DocumentedOpt, DocumentedOptDyn};
 
 #[test]
 fn some_works() {
@@ -17,6 +17,29 @@ fn none_works() {
     assert_eq!(NotSoNice::DOCS, None);
 }
 
+#[test]
+fn item_name_works() {
+    #[derive(DocumentedOpt)]
+    struct NotSoNice;
+
+    assert_eq!(NotSoNice::ITEM_NAME, "NotSoNice");
+    assert_eq!(NotSoNice::MODULE_PATH, module_path!());
+}
+
+#[test]
+fn dyn_works() {
+    /// 69
+    #[derive(DocumentedOpt)]
+    struct Nice;
+
+    #[derive(DocumentedOpt)]
+    struct NotSoNice;
+
+    let all: [&dyn DocumentedOptDyn; 2] = [&Nice, &NotSoNice];
+    assert_eq!(all.map(|d| d.docs()), [Some("69"), None]);
+    assert_eq!(all.map(|d| d.item_name()), ["Nice", "NotSoNice"]);
+}
+
 #[cfg(feature = "customise")]
 mod test_customise {
     use documented::DocumentedOpt;
@@ -29,4 +52,79 @@ mod test_customise {
 
         assert_eq!(NiceFlight::DOCS, Some("Nice catch!"));
     }
+
+    #[test]
+    fn also_raw_works() {
+        #[derive(DocumentedOpt)]
+        #[documented(also_raw)]
+        struct NiceFlight;
+
+        ///  Nice catch!
+        #[derive(DocumentedOpt)]
+        #[documented(also_raw)]
+        struct NiceCatch;
+
+        assert_eq!(NiceFlight::DOCS_RAW, None);
+        assert_eq!(NiceCatch::DOCS_RAW, Some("  Nice catch!"));
+    }
+
+    #[test]
+    fn help_works() {
+        #[derive(DocumentedOpt)]
+        #[documented(help)]
+        #[allow(dead_code)]
+        struct NiceFlight {
+            /// Nice catch!
+            catch: bool,
+        }
+
+        assert_eq!(NiceFlight::HELP, "Fields:\n  catch  Nice catch!");
+    }
+
+    #[test]
+    fn inherit_works() {
+        #[derive(DocumentedOpt)]
+        struct NiceFlight;
+
+        #[derive(DocumentedOpt)]
+        #[documented(inherit)]
+        #[allow(dead_code)]
+        struct NiceCatch(NiceFlight);
+
+        assert_eq!(NiceCatch::DOCS, None);
+    }
+
+    #[test]
+    fn summary_works() {
+        #[derive(DocumentedOpt)]
+        #[documented(summary)]
+        struct NiceFlight;
+
+        /// Nice catch!
+        ///
+        /// Much wow
+        #[derive(DocumentedOpt)]
+        #[documented(summary)]
+        struct NiceCatch;
+
+        assert_eq!(NiceFlight::DOCS_SUMMARY, None);
+        assert_eq!(NiceCatch::DOCS_SUMMARY, Some("Nice catch!"));
+    }
+
+    #[test]
+    fn lines_and_paragraphs_work() {
+        #[derive(DocumentedOpt)]
+        #[documented(lines, paragraphs)]
+        struct NiceFlight;
+
+        /// Nice catch!
+        #[derive(DocumentedOpt)]
+        #[documented(lines, paragraphs)]
+        struct NiceCatch;
+
+        assert!(NiceFlight::DOCS_LINES.is_empty());
+        assert!(NiceFlight::DOCS_PARAGRAPHS.is_empty());
+        assert_eq!(NiceCatch::DOCS_LINES, ["Nice catch!"]);
+        assert_eq!(NiceCatch::DOCS_PARAGRAPHS, ["Nice catch!"]);
+    }
 }
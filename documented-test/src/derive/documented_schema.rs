@@ -0,0 +1,255 @@
+use std::collections::BTreeMap;
+
+use documented::DocumentedSchema;
+
+#[test]
+fn it_works() {
+    /// Foo.
+    #[derive(DocumentedSchema)]
+    #[allow(dead_code)]
+    struct Foo {
+        /// 1
+        first: i32,
+        second: String,
+    }
+
+    assert_eq!(
+        Foo::json_schema(),
+        r#"{"description":"Foo.","title":"Foo","type":"object","properties":{"first":{"description":"1","type":"integer"},"second":{"type":"string"}},"required":["first","second"]}"#
+    );
+}
+
+#[test]
+fn optional_fields_are_not_required() {
+    #[derive(DocumentedSchema)]
+    #[allow(dead_code)]
+    struct Foo {
+        first: Option<bool>,
+        second: Box<Option<f64>>,
+    }
+
+    assert_eq!(
+        Foo::json_schema(),
+        r#"{"title":"Foo","type":"object","properties":{"first":{"type":"boolean"},"second":{"type":"number"}},"required":[]}"#
+    );
+}
+
+#[test]
+fn collections_work() {
+    #[derive(DocumentedSchema)]
+    #[allow(dead_code)]
+    struct Foo {
+        /// Moves.
+        moves: Vec<String>,
+        squares: [u8; 64],
+        ratings: BTreeMap<String, u16>,
+    }
+
+    assert_eq!(
+        Foo::json_schema(),
+        r#"{"title":"Foo","type":"object","properties":{"#.to_owned()
+            + r#""moves":{"description":"Moves.","type":"array","items":{"type":"string"}},"#
+            + r#""squares":{"type":"array","items":{"type":"integer"}},"#
+            + r#""ratings":{"type":"object","additionalProperties":{"type":"integer"}}},"#
+            + r#""required":["moves","squares","ratings"]}"#
+    );
+}
+
+#[test]
+fn field_docs_override_type_docs() {
+    /// Inner.
+    #[derive(DocumentedSchema)]
+    #[allow(dead_code)]
+    struct Inner {
+        x: u8,
+    }
+
+    #[derive(DocumentedSchema)]
+    #[allow(dead_code)]
+    struct Outer {
+        /// Documented.
+        documented: Inner,
+        undocumented: Inner,
+    }
+
+    assert_eq!(
+        Outer::json_schema(),
+        r#"{"title":"Outer","type":"object","properties":{"#.to_owned()
+            + r##""documented":{"description":"Documented.","$ref":"#/$defs/Inner"},"##
+            + r##""undocumented":{"$ref":"#/$defs/Inner"}},"##
+            + r#""required":["documented","undocumented"],"$defs":{"#
+            + r#""Inner":{"description":"Inner.","title":"Inner","type":"object","#
+            + r#""properties":{"x":{"type":"integer"}},"required":["x"]}}}"#
+    );
+}
+
+#[test]
+fn recursive_works() {
+    /// Node.
+    #[derive(DocumentedSchema)]
+    #[allow(dead_code)]
+    struct Node {
+        /// Children.
+        children: Vec<Node>,
+        parent: Option<Box<Node>>,
+    }
+
+    let node = r#"{"description":"Node.","title":"Node","type":"object","properties":{"#.to_owned()
+        + r#""children":{"description":"Children.","type":"array","items":{"$ref":"{ref}"}},"#
+        + r#""parent":{"$ref":"{ref}"}},"required":["children"]"#;
+    assert_eq!(Node::json_schema(), node.replace("{ref}", "#") + "}");
+    assert_eq!(
+        Vec::<Node>::json_schema(),
+        r##"{"type":"array","items":{"$ref":"#/$defs/Node"},"$defs":{"Node":"##.to_owned()
+            + &node.replace("{ref}", "#/$defs/Node")
+            + "}}}"
+    );
+}
+
+#[test]
+fn mutually_recursive_works() {
+    #[derive(DocumentedSchema)]
+    #[allow(dead_code)]
+    struct Foo {
+        bar: Option<Box<Bar>>,
+    }
+
+    #[derive(DocumentedSchema)]
+    #[allow(dead_code)]
+    struct Bar {
+        foo: Foo,
+    }
+
+    assert_eq!(
+        Foo::json_schema(),
+        r##"{"title":"Foo","type":"object","properties":{"bar":{"$ref":"#/$defs/Bar"}},"##
+            .to_owned()
+            + r#""required":[],"$defs":{"Bar":{"title":"Bar","type":"object","#
+            + r##""properties":{"foo":{"$ref":"#"}},"required":["foo"]}}}"##
+    );
+}
+
+#[test]
+fn generic_definitions_are_distinct() {
+    #[derive(DocumentedSchema)]
+    #[allow(dead_code)]
+    struct Foo<T> {
+        value: T,
+    }
+
+    #[derive(DocumentedSchema)]
+    #[allow(dead_code)]
+    struct Bar {
+        first: Foo<u8>,
+        second: Foo<bool>,
+    }
+
+    assert_eq!(
+        Bar::json_schema(),
+        r##"{"title":"Bar","type":"object","properties":{"##.to_owned()
+            + r##""first":{"$ref":"#/$defs/Foo"},"second":{"$ref":"#/$defs/Foo2"}},"##
+            + r#""required":["first","second"],"$defs":{"#
+            + r#""Foo":{"title":"Foo","type":"object","properties":{"value":{"type":"integer"}},"required":["value"]},"#
+            + r#""Foo2":{"title":"Foo","type":"object","properties":{"value":{"type":"boolean"}},"required":["value"]}}}"#
+    );
+}
+
+#[test]
+fn enum_works() {
+    /// Foo.
+    #[derive(DocumentedSchema)]
+    #[allow(dead_code)]
+    enum Foo {
+        /// 1
+        First,
+        Second,
+    }
+
+    assert_eq!(
+        Foo::json_schema(),
+        r#"{"description":"Foo.","title":"Foo","oneOf":[{"description":"1","const":"First"},{"const":"Second"}]}"#
+    );
+}
+
+#[test]
+fn generic_works() {
+    #[derive(DocumentedSchema)]
+    #[allow(dead_code)]
+    struct Foo<T> {
+        /// Value.
+        value: T,
+    }
+
+    assert_eq!(
+        Foo::<char>::json_schema(),
+        r#"{"title":"Foo","type":"object","properties":{"value":{"description":"Value.","type":"string"}},"required":["value"]}"#
+    );
+}
+
+#[test]
+fn escaping_works() {
+    /// A "quoted"
+    /// docstring.
+    #[derive(DocumentedSchema)]
+    enum Foo {}
+
+    assert_eq!(
+        Foo::json_schema(),
+        r#"{"description":"A \"quoted\"\ndocstring.","title":"Foo","oneOf":[]}"#
+    );
+}
+
+#[cfg(feature = "customise")]
+mod test_customise {
+    use documented::DocumentedSchema;
+
+    #[test]
+    fn rename_works() {
+        #[derive(DocumentedSchema)]
+        #[documented_schema(rename_all = "camelCase")]
+        #[allow(dead_code)]
+        struct Foo {
+            time_control: u32,
+            #[documented_schema(rename = "inc")]
+            increment: u32,
+        }
+
+        assert_eq!(
+            Foo::json_schema(),
+            r#"{"title":"Foo","type":"object","properties":{"timeControl":{"type":"integer"},"inc":{"type":"integer"}},"required":["timeControl","inc"]}"#
+        );
+    }
+
+    #[test]
+    fn default_works() {
+        #[derive(DocumentedSchema)]
+        #[documented_schema(default = Some("?"))]
+        #[allow(dead_code)]
+        enum Foo {
+            First,
+            #[documented_schema(default = None)]
+            Second,
+        }
+
+        assert_eq!(
+            Foo::json_schema(),
+            r#"{"title":"Foo","oneOf":[{"description":"?","const":"First"},{"const":"Second"}]}"#
+        );
+    }
+
+    #[test]
+    fn skip_works() {
+        #[derive(DocumentedSchema)]
+        #[allow(dead_code)]
+        enum Foo {
+            First,
+            #[documented_schema(skip)]
+            Second(u8),
+        }
+
+        assert_eq!(
+            Foo::json_schema(),
+            r#"{"title":"Foo","oneOf":[{"const":"First"}]}"#
+        );
+    }
+}
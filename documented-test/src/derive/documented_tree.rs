@@ -0,0 +1,147 @@
+use documented::{tree::DocNode, DocumentedTree};
+
+#[test]
+fn it_works() {
+    /// Such tree
+    #[derive(DocumentedTree)]
+    #[allow(dead_code)]
+    struct Doge {
+        /// Wow
+        wow: u8,
+        much_undocumented: u8,
+    }
+
+    assert_eq!(
+        Doge::DOC_TREE,
+        DocNode {
+            name: "Doge",
+            docs: Some("Such tree"),
+            children: &[
+                DocNode {
+                    name: "wow",
+                    docs: Some("Wow"),
+                    children: &[],
+                },
+                DocNode {
+                    name: "much_undocumented",
+                    docs: None,
+                    children: &[],
+                },
+            ],
+        }
+    );
+}
+
+#[test]
+fn enum_works() {
+    #[derive(DocumentedTree)]
+    #[allow(dead_code)]
+    enum Doge {
+        /// Such unit
+        Unit,
+        /// Very tuple
+        Tuple(
+            /// Much field
+            u8,
+        ),
+        Struct {
+            /// Wow
+            wow: u8,
+        },
+    }
+
+    assert_eq!(
+        Doge::DOC_TREE.children,
+        [
+            DocNode {
+                name: "Unit",
+                docs: Some("Such unit"),
+                children: &[],
+            },
+            DocNode {
+                name: "Tuple",
+                docs: Some("Very tuple"),
+                children: &[DocNode {
+                    name: "0",
+                    docs: Some("Much field"),
+                    children: &[],
+                }],
+            },
+            DocNode {
+                name: "Struct",
+                docs: None,
+                children: &[DocNode {
+                    name: "wow",
+                    docs: Some("Wow"),
+                    children: &[],
+                }],
+            },
+        ]
+    );
+}
+
+#[test]
+fn walk_works() {
+    /// Such walk
+    #[derive(DocumentedTree)]
+    #[allow(dead_code)]
+    struct Doge {
+        /// Wow
+        wow: u8,
+        /// Amaze
+        amaze: u8,
+    }
+
+    let mut visited = Vec::new();
+    Doge::DOC_TREE.walk(|depth, node| visited.push((depth, node.name)));
+    assert_eq!(visited, [(0, "Doge"), (1, "wow"), (1, "amaze")]);
+    assert_eq!(Doge::DOC_TREE.child("amaze").unwrap().docs, Some("Amaze"));
+    assert_eq!(Doge::DOC_TREE.child("nope"), None);
+    assert_eq!(Doge::DOC_TREE.descendant::<_, &str>([]), None);
+}
+
+#[cfg(feature = "customise")]
+mod test_customise {
+    use documented::DocumentedTree;
+
+    #[test]
+    fn nested_works() {
+        #[derive(DocumentedTree)]
+        #[documented_tree(rename_all = "kebab-case")]
+        #[allow(dead_code)]
+        struct Doge<T> {
+            /// Such nested
+            #[documented_tree(nested)]
+            such_nested: T,
+            #[documented_tree(skip)]
+            secret: u8,
+        }
+
+        #[derive(DocumentedTree)]
+        #[allow(dead_code)]
+        enum Inner {
+            Wow {
+                /// Amaze
+                #[documented_tree(nested)]
+                amaze: Leaf,
+            },
+        }
+
+        #[derive(DocumentedTree)]
+        #[allow(dead_code)]
+        struct Leaf {
+            /// Very leaf
+            very_leaf: u8,
+        }
+
+        let tree = Doge::<Inner>::DOC_TREE;
+        assert_eq!(tree.children.len(), 1);
+        assert_eq!(tree.children[0].docs, Some("Such nested"));
+        assert_eq!(
+            tree.descendant(["such-nested", "Wow", "amaze", "very_leaf"])
+                .unwrap()
+                .docs,
+            Some("Very leaf")
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use documented::DocumentedVariantFields;
+
+#[test]
+fn it_works() {
+    #[derive(DocumentedVariantFields)]
+    #[allow(dead_code)]
+    enum Foo {
+        /// Not captured.
+        Unit,
+        Tuple(
+            /// 0
+            i32,
+            u32,
+        ),
+        Struct {
+            first: i32,
+            /// 2
+            second: i32,
+        },
+    }
+
+    assert_eq!(
+        Foo::VARIANT_FIELD_DOCS,
+        [
+            ("Unit", &[][..]),
+            ("Tuple", &[("0", Some("0")), ("1", None)]),
+            ("Struct", &[("first", None), ("second", Some("2"))]),
+        ]
+    );
+}
+
+#[test]
+fn works_on_generic_enums() {
+    #[derive(DocumentedVariantFields)]
+    #[allow(dead_code)]
+    enum Foo<'a, T: Copy, const LEN: usize> {
+        Rufus(
+            /// 600
+            &'a T,
+        ),
+        Dufus {
+            /// 599
+            dufus: [T; LEN],
+        },
+    }
+
+    assert_eq!(
+        Foo::<u8, 69>::VARIANT_FIELD_DOCS,
+        [
+            ("Rufus", &[("0", Some("600"))][..]),
+            ("Dufus", &[("dufus", Some("599"))]),
+        ]
+    );
+}
+
+#[cfg(feature = "customise")]
+mod test_customise {
+    use documented::DocumentedVariantFields;
+
+    #[test]
+    fn customise_override_works() {
+        #[derive(DocumentedVariantFields)]
+        #[documented_variant_fields(trim = false, default = Some("?"))]
+        #[allow(dead_code)]
+        enum Name {
+            Doge {
+                ///     Wow
+                wow: bool,
+                such: bool,
+            },
+            #[documented_variant_fields(trim = true)]
+            Kabuso(
+                ///     RIP
+                bool,
+                #[documented_variant_fields(default = None)] bool,
+            ),
+        }
+
+        assert_eq!(
+            Name::VARIANT_FIELD_DOCS,
+            [
+                (
+                    "Doge",
+                    &[("wow", Some("     Wow")), ("such", Some("?"))][..]
+                ),
+                ("Kabuso", &[("0", Some("RIP")), ("1", None)]),
+            ]
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use documented::DocumentedVariants;
+use documented::{DocumentedVariants, VariantShape};
 
 #[test]
 fn it_works() {
@@ -107,6 +107,105 @@ fn works_on_lifetimed_enums() {
     assert_eq!(Foo::Dufus(69, &420).get_variant_docs(), "599");
 }
 
+#[test]
+fn variant_shape_works() {
+    #[allow(dead_code)]
+    #[derive(DocumentedVariants)]
+    enum Bar<'a, const LEN: usize> {
+        /// A unit variant.
+        Unit,
+        /// A tuple variant.
+        Tuple(u8, &'a str, [u8; LEN]),
+        /// A struct variant.
+        Struct {
+            alpha: Vec<Option<u8>>,
+            bravo: std::collections::HashMap<String, (u8, u16)>,
+        },
+        /// An empty struct variant.
+        StructEmpty {},
+    }
+    type Bar1 = Bar<'static, 1>;
+
+    assert_eq!(Bar1::Unit.get_variant_shape(), VariantShape::Unit);
+    assert_eq!(
+        Bar::Tuple(0, "", [0]).get_variant_shape(),
+        VariantShape::Tuple(&["u8", "&'a str", "[u8; LEN]"])
+    );
+    assert_eq!(
+        Bar1::Struct { alpha: vec![], bravo: Default::default() }.get_variant_shape(),
+        VariantShape::Struct(&[
+            ("alpha", "Vec<Option<u8>>"),
+            ("bravo", "std::collections::HashMap<String, (u8, u16)>"),
+        ])
+    );
+    assert_eq!(
+        Bar1::StructEmpty {}.get_variant_shape(),
+        VariantShape::Struct(&[])
+    );
+}
+
+#[test]
+fn variant_docs_works() {
+    #[allow(dead_code)]
+    #[derive(DocumentedVariants)]
+    enum Foo {
+        /// 1
+        First(u8),
+        /// 2
+        Second { third: u8 },
+        /// 4
+        r#Fourth,
+    }
+
+    assert_eq!(
+        Foo::VARIANT_DOCS,
+        [("First", "1"), ("Second", "2"), ("Fourth", "4")]
+    );
+}
+
+#[test]
+fn get_variant_docs_by_name_works() {
+    #[allow(dead_code)]
+    #[derive(DocumentedVariants)]
+    enum Foo {
+        /// 1
+        First(u8),
+        /// 2
+        Second { third: u8 },
+    }
+
+    assert_eq!(Foo::get_variant_docs_by_name("First"), Ok("1"));
+    assert_eq!(Foo::get_variant_docs_by_name("Second"), Ok("2"));
+    assert_eq!(
+        Foo::get_variant_docs_by_name("Third"),
+        Err(documented::Error::NoSuchField {
+            name: "Third".to_string(),
+            suggestion: None,
+        })
+    );
+    assert_eq!(
+        Foo::get_variant_docs_by_name("Secnod")
+            .unwrap_err()
+            .suggestion(),
+        Some("Second")
+    );
+}
+
+#[test]
+fn variant_shape_signature_works() {
+    assert_eq!(VariantShape::Unit.signature("Resign"), "Resign");
+    assert_eq!(
+        VariantShape::Tuple(&["Square", "Square"]).signature("Move"),
+        "Move(Square, Square)"
+    );
+    assert_eq!(VariantShape::Tuple(&[]).signature("Pass"), "Pass()");
+    assert_eq!(
+        VariantShape::Struct(&[("to", "Piece"), ("on", "Square")]).signature("Promote"),
+        "Promote { to: Piece, on: Square }"
+    );
+    assert_eq!(VariantShape::Struct(&[]).signature("Draw"), "Draw {}");
+}
+
 #[cfg(feature = "customise")]
 mod test_customise {
     use documented::DocumentedVariants;
@@ -216,4 +315,169 @@ mod test_customise {
             "I think you're more prepared than Noah"
         );
     }
+
+    #[test]
+    fn allow_empty_works() {
+        #[derive(DocumentedVariants)]
+        #[documented_variants(allow_empty)]
+        #[allow(dead_code)]
+        enum Doge {
+            Wow,
+            /// Much docs
+            Much,
+            #[documented_variants(default = "Very default")]
+            Very,
+        }
+
+        assert_eq!(Doge::Wow.get_variant_docs(), "");
+        assert_eq!(Doge::Much.get_variant_docs(), "Much docs");
+        assert_eq!(Doge::Very.get_variant_docs(), "Very default");
+    }
+
+    #[test]
+    fn normalize_works() {
+        #[derive(DocumentedVariants)]
+        #[documented_variants(normalize = "nfkc")]
+        #[allow(dead_code)]
+        enum Doge {
+            /// Such ﬁne
+            Fine,
+        }
+
+        assert_eq!(Doge::Fine.get_variant_docs(), "Such fine");
+    }
+
+    #[test]
+    fn markdown_reference_works() {
+        #[derive(DocumentedVariants)]
+        #[documented_variants(markdown_reference)]
+        #[allow(dead_code)]
+        #[repr(i8)]
+        enum Doge {
+            /// Such reference
+            Such = -1,
+            /// Very markdown
+            ///
+            /// Much docs
+            Very = 0x10,
+            /// Wow
+            r#Wow,
+        }
+
+        assert_eq!(
+            Doge::MARKDOWN_REFERENCE,
+            "## `Such`\n\nCode: `-1`\n\nSuch reference\n\n\
+            ## `Very`\n\nCode: `0x10`\n\nVery markdown\n\nMuch docs\n\n\
+            ## `Wow`\n\nWow"
+        );
+    }
+
+    #[test]
+    fn strip_markdown_works() {
+        #[derive(DocumentedVariants)]
+        #[documented_variants(strip_markdown)]
+        #[allow(dead_code)]
+        enum Doge {
+            /// Such *markdown*
+            Wow,
+            /// Very `markdown`
+            #[documented_variants(strip_markdown = false)]
+            Much,
+        }
+
+        assert_eq!(Doge::Wow.get_variant_docs(), "Such markdown");
+        assert_eq!(Doge::Much.get_variant_docs(), "Very `markdown`");
+    }
+
+    #[test]
+    fn wrap_works() {
+        #[derive(DocumentedVariants)]
+        #[documented_variants(wrap = 10)]
+        #[allow(dead_code)]
+        enum Doge {
+            /// Such wrap very width
+            Wow,
+        }
+
+        assert_eq!(Doge::Wow.get_variant_docs(), "Such wrap\nvery width");
+    }
+
+    #[test]
+    fn compact_works() {
+        use documented::VariantShape;
+
+        #[derive(DocumentedVariants)]
+        #[documented_variants(compact)]
+        #[allow(dead_code)]
+        enum Doge<T> {
+            /// Such compact
+            Such(T),
+            /// Very small
+            Very { small: bool },
+            /// Wow
+            Wow,
+        }
+
+        assert_eq!(Doge::Such(0).get_variant_docs(), "Such compact");
+        assert_eq!(
+            Doge::<u8>::Very { small: true }.get_variant_docs(),
+            "Very small"
+        );
+        assert_eq!(Doge::<u8>::Wow.get_variant_docs(), "Wow");
+        assert_eq!(
+            Doge::<u8>::Very { small: true }.get_variant_shape(),
+            VariantShape::Struct(&[("small", "bool")])
+        );
+        assert_eq!(
+            Doge::Such(0).get_variant_shape(),
+            VariantShape::Tuple(&["T"])
+        );
+        assert_eq!(Doge::<u8>::get_variant_docs_by_name("Wow"), Ok("Wow"));
+    }
+
+    #[test]
+    fn compact_keeps_user_methods_working() {
+        #[derive(DocumentedVariants)]
+        #[documented_variants(compact)]
+        #[allow(dead_code)]
+        enum Doge {
+            /// Such index
+            Such,
+            /// Very own
+            Very,
+        }
+
+        impl Doge {
+            fn variant_index(&self) -> &'static str {
+                "wow"
+            }
+        }
+
+        assert_eq!(Doge::Very.variant_index(), "wow");
+        assert_eq!(Doge::Very.get_variant_docs(), "Very own");
+    }
+
+    #[test]
+    fn compact_works_with_both_traits() {
+        use documented::DocumentedVariantsOpt;
+
+        #[derive(DocumentedVariants, DocumentedVariantsOpt)]
+        #[documented_variants(compact)]
+        #[allow(dead_code)]
+        enum Doge {
+            /// Such both
+            Such,
+            /// Very compact
+            Very,
+        }
+
+        assert_eq!(
+            DocumentedVariants::get_variant_docs(&Doge::Very),
+            "Very compact"
+        );
+        assert_eq!(
+            DocumentedVariantsOpt::get_variant_docs(&Doge::Such),
+            Some("Such both")
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use documented::DocumentedVariantsOpt;
+use documented::{DocumentedVariantsOpt, VariantShape};
 
 #[test]
 fn it_works() {
@@ -13,6 +13,71 @@ fn it_works() {
     assert_eq!(Foo::Second.get_variant_docs(), Some("2"));
 }
 
+#[test]
+fn variant_docs_works() {
+    #[allow(dead_code)]
+    #[derive(DocumentedVariantsOpt)]
+    enum Foo {
+        First(u8),
+        /// 2
+        Second,
+    }
+
+    assert_eq!(Foo::VARIANT_DOCS, [("First", None), ("Second", Some("2"))]);
+}
+
+#[test]
+fn get_variant_docs_by_name_works() {
+    #[allow(dead_code)]
+    #[derive(DocumentedVariantsOpt)]
+    enum Foo {
+        First(u8),
+        /// 2
+        Second,
+    }
+
+    assert_eq!(Foo::get_variant_docs_by_name("Second"), Ok("2"));
+    assert_eq!(
+        Foo::get_variant_docs_by_name("First"),
+        Err(documented::Error::NoVariantDocComments("First".to_string()))
+    );
+    assert_eq!(
+        Foo::get_variant_docs_by_name("First")
+            .unwrap_err()
+            .to_string(),
+        r#"The variant "First" has no doc comments"#
+    );
+    assert_eq!(
+        Foo::get_variant_docs_by_name("Third"),
+        Err(documented::Error::NoSuchField {
+            name: "Third".to_string(),
+            suggestion: None,
+        })
+    );
+}
+
+#[test]
+fn variant_shape_works() {
+    #[allow(dead_code)]
+    #[derive(DocumentedVariantsOpt)]
+    enum Foo {
+        First(bool),
+        /// 2
+        Second {
+            third: i32,
+        },
+    }
+
+    assert_eq!(
+        Foo::First(true).get_variant_shape(),
+        VariantShape::Tuple(&["bool"])
+    );
+    assert_eq!(
+        Foo::Second { third: 3 }.get_variant_shape(),
+        VariantShape::Struct(&[("third", "i32")])
+    );
+}
+
 #[cfg(feature = "customise")]
 mod test_customise {
     use documented::DocumentedVariantsOpt;
@@ -41,4 +106,53 @@ mod test_customise {
         );
         assert_eq!(Dead::OJ.get_variant_docs(), None);
     }
+
+    #[test]
+    fn markdown_reference_works() {
+        #[derive(DocumentedVariantsOpt)]
+        #[documented_variants(markdown_reference, default = Some("Wow"))]
+        #[allow(dead_code)]
+        enum Doge {
+            /// Such reference
+            Such,
+            Very,
+        }
+
+        assert_eq!(
+            Doge::MARKDOWN_REFERENCE,
+            "## `Such`\n\nSuch reference\n\n## `Very`"
+        );
+        assert_eq!(Doge::Very.get_variant_docs(), Some("Wow"));
+    }
+
+    #[test]
+    fn require_all_works() {
+        #[derive(DocumentedVariantsOpt)]
+        #[documented_variants(require_all)]
+        #[allow(dead_code)]
+        enum Doge {
+            /// Such required
+            Wow,
+            /// Very documented
+            Such,
+        }
+
+        assert_eq!(Doge::Wow.get_variant_docs(), Some("Such required"));
+        assert_eq!(Doge::Such.get_variant_docs(), Some("Very documented"));
+    }
+
+    #[test]
+    fn compact_works() {
+        #[derive(DocumentedVariantsOpt)]
+        #[documented_variants(compact)]
+        #[allow(dead_code)]
+        enum Doge {
+            /// Such compact
+            Such,
+            Very,
+        }
+
+        assert_eq!(Doge::Such.get_variant_docs(), Some("Such compact"));
+        assert_eq!(Doge::Very.get_variant_docs(), None);
+    }
 }
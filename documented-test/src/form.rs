@@ -0,0 +1,96 @@
+//! Tests for form metadata.
+
+use documented::{
+    form::{fields, grouped_fields, FormField},
+    DocumentedFields,
+};
+
+#[test]
+fn it_works() {
+    #[derive(DocumentedFields)]
+    #[allow(dead_code, non_snake_case)]
+    struct Engine {
+        /// Search depth.
+        max_depth: u8,
+        /// Starting position.
+        start_FEN: String,
+        /// Move overhead.
+        moveOverhead: u32,
+    }
+
+    assert_eq!(
+        fields::<Engine>(),
+        [
+            FormField {
+                name: Some("max_depth"),
+                label: "Max depth".to_string(),
+                docs: "Search depth.",
+                group: None,
+            },
+            FormField {
+                name: Some("start_FEN"),
+                label: "Start FEN".to_string(),
+                docs: "Starting position.",
+                group: None,
+            },
+            FormField {
+                name: Some("moveOverhead"),
+                label: "Move overhead".to_string(),
+                docs: "Move overhead.",
+                group: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn anonymous_fields_are_labelled_by_index() {
+    #[derive(DocumentedFields)]
+    #[allow(dead_code)]
+    struct Score(
+        /// White.
+        u8,
+        /// Black.
+        u8,
+    );
+
+    let labels = fields::<Score>()
+        .into_iter()
+        .map(|field| (field.name, field.label))
+        .collect::<Vec<_>>();
+    assert_eq!(labels, [(None, "0".to_string()), (None, "1".to_string())]);
+}
+
+#[test]
+fn grouped_fields_work() {
+    #[derive(DocumentedFields)]
+    #[allow(dead_code, non_snake_case)]
+    struct Theme {
+        /// Green and cream.
+        TOURNAMENT_GREEN: bool,
+    }
+
+    assert_eq!(
+        grouped_fields::<Theme>("Board"),
+        [FormField {
+            name: Some("TOURNAMENT_GREEN"),
+            label: "Tournament green".to_string(),
+            docs: "Green and cream.",
+            group: Some("Board"),
+        }]
+    );
+}
+
+#[cfg(feature = "customise")]
+#[test]
+fn custom_names_are_labelled() {
+    #[derive(DocumentedFields)]
+    #[documented_fields(rename_all = "kebab-case")]
+    #[allow(dead_code)]
+    struct Clock {
+        /// Increment per move.
+        increment_seconds: u32,
+    }
+
+    assert_eq!(fields::<Clock>()[0].label, "Increment seconds");
+}
@@ -0,0 +1,93 @@
+//! Tests for generated code in modules without the standard prelude.
+
+#[allow(deprecated)] // `DocumentedFields` on an enum, without opting in
+mod no_prelude {
+    #![no_implicit_prelude]
+
+    /// Everything
+    #[derive(
+        ::documented::Documented,
+        ::documented::DocumentedOpt,
+        ::documented::DocumentedFields,
+        ::documented::DocumentedFieldsOpt,
+        ::documented::DocumentedVariants,
+        ::documented::DocumentedVariantsOpt,
+        ::documented::DocumentedVariantFields,
+        ::documented::DocumentedTree,
+    )]
+    #[allow(dead_code)]
+    pub enum Everything {
+        /// Unit
+        Unit,
+        /// Struct
+        Struct {
+            /// Field
+            field: u8,
+        },
+    }
+
+    /// Record
+    #[derive(::documented::DocumentedSchema)]
+    #[allow(dead_code)]
+    pub struct Record {
+        /// Field
+        field: ::core::option::Option<u8>,
+    }
+
+    /// Nothing
+    #[::documented::docs_const]
+    #[allow(dead_code)]
+    pub fn nothing() {}
+}
+
+use documented::{
+    Documented, DocumentedFields, DocumentedFieldsOpt, DocumentedOpt, DocumentedSchema,
+    DocumentedTree, DocumentedVariantFields, DocumentedVariants, DocumentedVariantsOpt,
+};
+use no_prelude::{Everything, Record, NOTHING_DOCS};
+
+#[test]
+fn derives_work_without_prelude() {
+    assert_eq!(<Everything as Documented>::DOCS, "Everything");
+    assert_eq!(<Everything as DocumentedOpt>::DOCS, Some("Everything"));
+    assert_eq!(
+        <Everything as DocumentedFields>::get_field_docs("Struct"),
+        Ok("Struct")
+    );
+    assert_eq!(
+        <Everything as DocumentedFieldsOpt>::FIELD_DOCS,
+        [Some("Unit"), Some("Struct")]
+    );
+    assert_eq!(
+        DocumentedVariants::get_variant_docs(&Everything::Unit),
+        "Unit"
+    );
+    assert_eq!(
+        DocumentedVariantsOpt::get_variant_docs(&Everything::Unit),
+        Some("Unit")
+    );
+    assert_eq!(
+        Everything::VARIANT_FIELD_DOCS,
+        [("Unit", &[][..]), ("Struct", &[("field", Some("Field"))])]
+    );
+    assert_eq!(
+        Everything::DOC_TREE
+            .descendant(["Struct", "field"])
+            .unwrap()
+            .docs,
+        Some("Field")
+    );
+}
+
+#[test]
+fn schema_works_without_prelude() {
+    assert_eq!(
+        Record::json_schema(),
+        r#"{"description":"Record","title":"Record","type":"object","properties":{"field":{"description":"Field","type":"integer"}},"required":[]}"#
+    );
+}
+
+#[test]
+fn docs_const_works_without_prelude() {
+    assert_eq!(NOTHING_DOCS, "Nothing");
+}
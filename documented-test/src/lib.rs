@@ -1,4 +1,24 @@
 #![cfg(test)]
 
+#[cfg(feature = "arrow")]
+mod arrow;
 mod attr;
+mod build;
+mod concat;
+#[cfg(feature = "serde")]
+mod defaults;
+mod delegate;
 mod derive;
+mod form;
+mod hygiene;
+mod manual;
+mod markdown;
+mod process;
+mod reference;
+#[cfg(feature = "render")]
+mod render;
+#[cfg(feature = "serde-interop")]
+mod serde_interop;
+#[cfg(all(feature = "customise", feature = "serde_path_to_error"))]
+mod serde_path;
+mod snapshot;
@@ -0,0 +1,84 @@
+//! Tests for the macros implementing the traits manually.
+
+use documented::{
+    impl_documented, impl_documented_fields, impl_documented_fields_opt, impl_documented_opt,
+    Documented, DocumentedFields, DocumentedFieldsOpt, DocumentedOpt, Error,
+};
+
+mod foreign {
+    #[allow(dead_code)]
+    pub struct Doge {
+        pub such_field: u8,
+        pub very_field: u8,
+    }
+}
+use foreign::Doge;
+
+const WOW: &str = "Wow";
+
+impl_documented!(Doge, "Such manual");
+impl_documented_opt!(foreign::Doge, None);
+impl_documented_fields!(Doge { such_field: WOW, very_field: "Amaze" });
+impl_documented_fields_opt!(Doge {
+    such_field: Some("Much opt"),
+    very_field: None
+});
+
+#[test]
+fn it_works() {
+    assert_eq!(<Doge as Documented>::DOCS, "Such manual");
+    assert_eq!(<Doge as Documented>::ITEM_NAME, "Doge");
+    assert_eq!(<Doge as Documented>::MODULE_PATH, module_path!());
+    assert_eq!(<Doge as DocumentedOpt>::DOCS, None);
+    assert_eq!(<Doge as DocumentedOpt>::ITEM_NAME, "foreign::Doge");
+}
+
+#[test]
+fn fields_work() {
+    assert_eq!(
+        <Doge as DocumentedFields>::FIELD_NAMES,
+        ["such_field", "very_field"]
+    );
+    assert_eq!(
+        <Doge as DocumentedFields>::get_field_docs("very_field"),
+        Ok("Amaze")
+    );
+    assert_eq!(
+        <Doge as DocumentedFields>::get_field_docs("such_field"),
+        Ok("Wow")
+    );
+    assert_eq!(
+        <Doge as DocumentedFields>::get_field_docs("nope"),
+        Err(Error::NoSuchField {
+            name: "nope".to_string(),
+            suggestion: None,
+        })
+    );
+}
+
+#[test]
+fn fields_opt_work() {
+    assert_eq!(
+        <Doge as DocumentedFieldsOpt>::get_field_docs("such_field"),
+        Ok("Much opt")
+    );
+    assert_eq!(
+        <Doge as DocumentedFieldsOpt>::get_field_docs("very_field"),
+        Err(Error::NoDocComments("very_field".to_string()))
+    );
+}
+
+#[test]
+fn empty_works() {
+    struct Empty;
+    impl_documented_fields!(Empty {});
+
+    assert_eq!(Empty::FIELD_DOCS, [""; 0]);
+    assert_eq!(
+        Empty::get_field_docs("nope"),
+        Err(Error::NoSuchField {
+            name: "nope".to_string(),
+            suggestion: None,
+        })
+    );
+}
@@ -0,0 +1,49 @@
+//! Tests for markdown table rendering.
+
+use documented::{markdown, DocumentedFields, DocumentedVariants};
+
+#[derive(DocumentedFields)]
+#[allow(dead_code)]
+struct Doge(
+    /// Such field
+    u8,
+    /// Very | pipe
+    ///
+    /// Much lines
+    #[cfg_attr(feature = "customise", documented_fields(rename = "wow"))]
+    u8,
+);
+
+#[derive(DocumentedVariants)]
+#[allow(dead_code)]
+enum Kabuso {
+    /// Such variant
+    Such,
+    /// Very `code`
+    Very,
+}
+
+#[test]
+fn field_table_works() {
+    let wow_name = if cfg!(feature = "customise") { "wow" } else { "1" };
+    assert_eq!(
+        markdown::field_table::<Doge>(),
+        format!(
+            "| Field | Description |\n\
+            | --- | --- |\n\
+            | `0` | Such field |\n\
+            | `{wow_name}` | Very \\| pipe<br><br>Much lines |\n"
+        )
+    );
+}
+
+#[test]
+fn variant_table_works() {
+    assert_eq!(
+        markdown::variant_table::<Kabuso>(),
+        "| Variant | Description |\n\
+        | --- | --- |\n\
+        | `Such` | Such variant |\n\
+        | `Very` | Very `code` |\n"
+    );
+}
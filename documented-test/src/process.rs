@@ -0,0 +1,169 @@
+//! Tests for runtime doc processing.
+
+use std::borrow::Cow;
+
+use documented::{process::Docs, Documented};
+
+#[test]
+fn of_works() {
+    /// Checkmate.
+    #[derive(Documented)]
+    struct Mate;
+
+    assert_eq!(Docs::of::<Mate>().as_str(), "Checkmate.");
+}
+
+#[test]
+fn no_op_does_not_allocate() {
+    let docs = Docs::new("Short and sweet.")
+        .trim_lines()
+        .dedent()
+        .unwrap_lines()
+        .wrap(80)
+        .strip_markdown();
+
+    assert!(matches!(
+        docs.into_inner(),
+        Cow::Borrowed("Short and sweet.")
+    ));
+}
+
+#[test]
+fn trim_lines_works() {
+    let docs = Docs::new("  a  \n b").trim_lines();
+    assert_eq!(docs.as_str(), "a\nb");
+}
+
+#[test]
+fn dedent_works() {
+    let docs = Docs::new("    fn main() {\n\n        e4();\n    }").dedent();
+    assert_eq!(docs.as_str(), "fn main() {\n\n    e4();\n}");
+}
+
+#[test]
+fn dedent_keeps_trailing_newline() {
+    let docs = Docs::new("  Such indent\n    very code\n").dedent();
+    assert_eq!(docs.as_str(), "Such indent\n  very code\n");
+}
+
+#[test]
+fn unwrap_lines_works() {
+    let docs = Docs::new("Play e4\nand win.\n\nOr don't.").unwrap_lines();
+    assert_eq!(docs.as_str(), "Play e4 and win.\n\nOr don't.");
+}
+
+#[test]
+fn wrap_works() {
+    let docs = Docs::new("The quick brown fox\nsupercalifragilistic").wrap(10);
+    assert_eq!(docs.as_str(), "The quick\nbrown fox\nsupercalifragilistic");
+}
+
+#[test]
+fn unwrap_lines_keeps_code_blocks() {
+    let docs = Docs::new("Such\nunwrap\n```\nlet very = 1;\nlet code = 2;\n```").unwrap_lines();
+    assert_eq!(
+        docs.as_str(),
+        "Such unwrap\n```\nlet very = 1;\nlet code = 2;\n```"
+    );
+}
+
+#[test]
+fn unwrap_lines_agrees_with_wrap() {
+    let docs = "Such\nunwrap\n- very\n  item\n- much item\n\n    let indented = code;\n";
+
+    assert_eq!(
+        Docs::new(docs).unwrap_lines().as_str(),
+        "Such unwrap\n- very item\n- much item\n\n    let indented = code;\n"
+    );
+    assert_eq!(
+        Docs::new(docs).unwrap_lines().wrap(80).as_str(),
+        Docs::new(docs).wrap(80).as_str()
+    );
+}
+
+#[test]
+fn wrap_reflows_paragraphs() {
+    let docs = Docs::new("Such\nwrap\n\nVery paragraph").wrap(20);
+    assert_eq!(docs.as_str(), "Such wrap\n\nVery paragraph");
+}
+
+#[test]
+fn wrap_keeps_code_blocks() {
+    let docs = Docs::new(
+        "Such wrap\n\
+        ```\n\
+        let very = code;\n\
+        ```\n\
+        \n\
+        \x20   let much = indent;\n\
+        \x20   let wow = 1;",
+    )
+    .wrap(5);
+    assert_eq!(
+        docs.as_str(),
+        "Such\nwrap\n```\nlet very = code;\n```\n\n    let much = indent;\n    let wow = 1;"
+    );
+}
+
+#[test]
+fn wrap_keeps_list_items() {
+    let docs = Docs::new(
+        "Such list:\n\
+        - very item\n\
+        - much long item that wraps\n\
+        \x20 - nested\n\
+        10. numbered",
+    )
+    .wrap(16);
+    assert_eq!(
+        docs.as_str(),
+        "Such list:\n\
+        - very item\n\
+        - much long item\n\
+        \x20 that wraps\n\
+        \x20 - nested\n\
+        10. numbered"
+    );
+}
+
+#[test]
+fn strip_markdown_works() {
+    let docs = Docs::new(
+        "# Opening\n\
+        Play *the* **Sicilian** with `Nf6`, see [`Najdorf`](crate::Najdorf).\n\
+        Also [the Dragon][dragon] and ![a board](board.png).\n\
+        snake_case and 2 * 3 are kept.\n\
+        ```\n\
+        let *x* = 1;\n\
+        ```\n\
+        [dragon]: crate::Dragon",
+    )
+    .strip_markdown();
+    assert_eq!(
+        docs.as_str(),
+        "Opening\n\
+        Play the Sicilian with Nf6, see Najdorf.\n\
+        Also the Dragon and a board.\n\
+        snake_case and 2 * 3 are kept.\n\
+        let *x* = 1;"
+    );
+}
+
+#[test]
+fn strip_markdown_keeps_unpaired_markers() {
+    let docs = Docs::new(
+        "a * b, 2 *3, *args and **kwargs, snake_case_name, _private\n\
+        Much *emphasis* and **such** wow_",
+    )
+    .strip_markdown();
+    assert_eq!(
+        docs.as_str(),
+        "a * b, 2 *3, *args and **kwargs, snake_case_name, _private\n\
+        Much emphasis and such wow_"
+    );
+}
+
+#[test]
+fn display_works() {
+    assert_eq!(Docs::new("e4").to_string(), "e4");
+}
@@ -0,0 +1,51 @@
+//! Tests for settings reference rendering.
+
+use documented::{reference, Documented, DocumentedFields};
+
+/// Engine settings.
+///
+/// Restart to apply.
+#[derive(Documented, DocumentedFields)]
+#[allow(dead_code)]
+struct Engine(
+    /// Number of threads.
+    u16,
+    /// Hash size, in "MB".
+    #[cfg_attr(feature = "customise", documented_fields(rename = "hash"))]
+    u32,
+);
+
+#[test]
+fn html_works() {
+    let hash_name = if cfg!(feature = "customise") { "hash" } else { "1" };
+    assert_eq!(
+        reference::html::<Engine>("Engine"),
+        format!(
+            "<section class=\"documented-reference\">\n\
+            <h2>Engine</h2>\n\
+            <p>Engine settings.</p><p>Restart to apply.</p>\n\
+            <dl>\n\
+            <dt><code>0</code></dt>\n\
+            <dd><p>Number of threads.</p></dd>\n\
+            <dt><code>{hash_name}</code></dt>\n\
+            <dd><p>Hash size, in &quot;MB&quot;.</p></dd>\n\
+            </dl>\n\
+            </section>\n"
+        )
+    );
+}
+
+#[test]
+fn json_works() {
+    let hash_name = if cfg!(feature = "customise") {
+        r#""hash""#
+    } else {
+        "null"
+    };
+    assert_eq!(
+        reference::json::<Engine>("Engine"),
+        format!(
+            r#"{{"name":"Engine","docs":"Engine settings.\n\nRestart to apply.","fields":[{{"name":null,"docs":"Number of threads."}},{{"name":{hash_name},"docs":"Hash size, in \"MB\"."}}]}}"#
+        )
+    );
+}
@@ -0,0 +1,88 @@
+//! Tests for markdown rendering.
+
+use documented::{render, Documented, DocumentedFields, DocumentedVariants};
+
+/// # Wow
+///
+/// Such **bold**, very *emphasis*,
+/// much `code` and [link](https://example.com).
+///
+/// - Such [`Item`]
+/// - Very <html>
+///
+/// ```
+/// let doge = 1 < 2;
+/// ```
+#[derive(Documented)]
+#[documented(trim = false)]
+struct Doge;
+
+#[test]
+fn ansi_works() {
+    assert_eq!(
+        render::to_ansi::<Doge>(),
+        "\x1b[1m\x1b[4mWow\x1b[24m\x1b[22m\n\n\
+        Such \x1b[1mbold\x1b[22m, very \x1b[3memphasis\x1b[23m,\n\
+        much \x1b[36mcode\x1b[39m and \x1b[4mlink\x1b[24m.\n\n  \
+        \u{2022} Such \x1b[4m\x1b[36mItem\x1b[39m\x1b[24m\n  \
+        \u{2022} Very <html>\n\n    \
+        \x1b[36m let doge = 1 < 2;\x1b[39m"
+    );
+}
+
+#[test]
+fn html_works() {
+    assert_eq!(
+        render::to_html::<Doge>(),
+        "<h1>Wow</h1>\n\
+        <p>Such <strong>bold</strong>, very <em>emphasis</em>,\n\
+        much <code>code</code> and <a href=\"https://example.com\">link</a>.</p>\n\
+        <ul><li>Such <code>Item</code></li><li>Very &lt;html&gt;</li></ul>\n\
+        <pre><code> let doge = 1 &lt; 2;</code></pre>"
+    );
+}
+
+#[test]
+fn unbalanced_markers_are_kept() {
+    assert_eq!(render::ansi("Such **bold"), "Such **bold");
+    assert_eq!(
+        render::ansi("Such *args and **bold**"),
+        "Such *args and \x1b[1mbold\x1b[22m"
+    );
+    assert_eq!(render::html("snake_case_name"), "<p>snake_case_name</p>");
+}
+
+#[test]
+fn field_and_variant_helpers_work() {
+    #[derive(DocumentedVariants)]
+    #[allow(dead_code)]
+    enum Wow {
+        /// Such `code`
+        Doge,
+    }
+
+    #[derive(DocumentedFields)]
+    #[allow(dead_code)]
+    struct Kabuso {
+        /// Very **bold**
+        shiba: bool,
+    }
+
+    assert_eq!(
+        render::field_to_ansi::<Kabuso>("shiba"),
+        Ok("Very \x1b[1mbold\x1b[22m".to_string())
+    );
+    assert_eq!(
+        render::field_to_html::<Kabuso>("shiba"),
+        Ok("<p>Very <strong>bold</strong></p>".to_string())
+    );
+    assert!(render::field_to_html::<Kabuso>("doge").is_err());
+    assert_eq!(
+        render::variant_to_ansi(&Wow::Doge),
+        "Such \x1b[36mcode\x1b[39m"
+    );
+    assert_eq!(
+        render::variant_to_html(&Wow::Doge),
+        "<p>Such <code>code</code></p>"
+    );
+}
@@ -0,0 +1,84 @@
+//! Tests for looking up docs by the names serde uses.
+
+use documented::{DocumentedFields, DocumentedFieldsOpt, Error};
+use serde::Deserialize;
+
+#[test]
+fn rename_all_works() {
+    #[derive(Deserialize, DocumentedFields)]
+    #[serde(rename_all = "camelCase")]
+    #[allow(dead_code)]
+    struct Doge {
+        /// Such camel
+        such_camel: u8,
+        /// Very raw
+        r#type: u8,
+    }
+
+    assert_eq!(Doge::get_field_docs("suchCamel"), Ok("Such camel"));
+    assert_eq!(Doge::get_field_docs("such_camel"), Ok("Such camel"));
+    assert_eq!(Doge::get_field_docs("type"), Ok("Very raw"));
+}
+
+#[test]
+fn rename_and_alias_work() {
+    #[derive(Deserialize, DocumentedFieldsOpt)]
+    #[serde(rename_all(serialize = "kebab-case", deserialize = "SCREAMING_SNAKE_CASE"))]
+    #[allow(dead_code)]
+    struct Doge {
+        /// Such rename
+        #[serde(rename = "wow", alias = "much-alias", default)]
+        such_rename: u8,
+        very_cases: u8,
+    }
+
+    assert_eq!(Doge::get_field_docs("wow"), Ok("Such rename"));
+    assert_eq!(Doge::get_field_docs("much-alias"), Ok("Such rename"));
+    assert_eq!(
+        Doge::get_field_docs("such-rename"),
+        Err(Error::NoSuchField {
+            name: "such-rename".to_string(),
+            suggestion: Some("such_rename"),
+        })
+    );
+    assert_eq!(
+        Doge::get_field_docs("very-cases"),
+        Err(Error::NoDocComments("very-cases".to_string()))
+    );
+    assert_eq!(
+        Doge::get_field_docs("VERY_CASES"),
+        Err(Error::NoDocComments("VERY_CASES".to_string()))
+    );
+}
+
+#[test]
+fn field_attrs_work() {
+    #[derive(Deserialize, DocumentedFields)]
+    #[allow(dead_code)]
+    struct Doge(
+        /// Such tuple
+        #[serde(rename = "wow")]
+        u8,
+    );
+
+    assert_eq!(Doge::get_field_docs("wow"), Ok("Such tuple"));
+}
+
+#[test]
+fn variants_work() {
+    #[derive(Deserialize, DocumentedFields)]
+    #[serde(rename_all = "snake_case")]
+    #[cfg_attr(feature = "customise", documented_fields(variants))]
+    #[allow(dead_code)]
+    enum Doge {
+        /// Such variant
+        SuchVariant,
+        /// Very rename
+        #[serde(rename = "wow")]
+        VeryRename,
+    }
+
+    assert_eq!(Doge::get_field_docs("such_variant"), Ok("Such variant"));
+    assert_eq!(Doge::get_field_docs("SuchVariant"), Ok("Such variant"));
+    assert_eq!(Doge::get_field_docs("wow"), Ok("Very rename"));
+}
@@ -0,0 +1,78 @@
+//! Tests for looking up docs by deserialisation error paths.
+
+use documented::{
+    serde_path::{field_docs, field_docs_opt},
+    DocumentedFields, DocumentedFieldsOpt, Error,
+};
+use serde::Deserialize;
+
+#[derive(Deserialize, DocumentedFields)]
+#[serde(rename_all = "kebab-case")]
+#[documented_fields(rename_all = "kebab-case")]
+#[allow(dead_code)]
+struct Config {
+    /// Engines to run.
+    #[documented_fields(nested)]
+    engines: Engines,
+    /// Time control.
+    time_control: String,
+}
+
+#[derive(Deserialize, DocumentedFields)]
+#[allow(dead_code)]
+struct Engine {
+    /// Number of search threads.
+    threads: u16,
+    /// Whether to ponder.
+    ponder: bool,
+}
+
+#[derive(Deserialize, DocumentedFieldsOpt)]
+#[allow(dead_code)]
+struct UndocumentedEngine {
+    threads: u16,
+}
+
+/// A list of engines, whose elements are looked up as `Engine`.
+#[derive(Deserialize)]
+#[serde(transparent)]
+#[allow(dead_code)]
+struct Engines(Vec<Engine>);
+
+impl DocumentedFields for Engines {
+    const FIELD_NAMES: &'static [&'static str] = <Engine as DocumentedFields>::FIELD_NAMES;
+    const FIELD_DOCS: &'static [&'static str] = <Engine as DocumentedFields>::FIELD_DOCS;
+}
+
+fn error_path<T: for<'de> Deserialize<'de>>(json: &str) -> serde_path_to_error::Path {
+    let deserializer = &mut serde_json::Deserializer::from_str(json);
+    serde_path_to_error::deserialize::<_, T>(deserializer)
+        .map(|_| ())
+        .unwrap_err()
+        .path()
+        .clone()
+}
+
+#[test]
+fn it_works() {
+    let path = error_path::<Config>(r#"{ "engines": [], "time-control": 5 }"#);
+    assert_eq!(field_docs::<Config>(&path), Ok("Time control."));
+}
+
+#[test]
+fn sequences_are_skipped() {
+    let path = error_path::<Config>(
+        r#"{ "engines": [{ "threads": -1, "ponder": true }], "time-control": "5+3" }"#,
+    );
+    assert_eq!(path.to_string(), "engines[0].threads");
+    assert_eq!(field_docs::<Config>(&path), Ok("Number of search threads."));
+}
+
+#[test]
+fn opt_works() {
+    let path = error_path::<UndocumentedEngine>(r#"{ "threads": -1 }"#);
+    assert_eq!(
+        field_docs_opt::<UndocumentedEngine>(&path),
+        Err(Error::NoDocComments("threads".to_string()))
+    );
+}
@@ -0,0 +1,78 @@
+//! Tests for documentation snapshots.
+
+use std::path::Path;
+
+use documented::{document_snapshot, snapshot, Documented, DocumentedFields};
+
+/// Always play the Sicilian.
+/// Unless you're white.
+#[derive(Documented, DocumentedFields)]
+#[allow(dead_code)]
+struct Sicilian {
+    /// The Najdorf.
+    najdorf: bool,
+    /// The Dragon.
+    dragon: bool,
+}
+
+/// Two pawns.
+#[derive(Documented, DocumentedFields)]
+#[allow(dead_code)]
+struct Gambit(
+    /// Just one.
+    bool,
+    /// And another.
+    bool,
+);
+
+#[test]
+fn render_works() {
+    let rendered = snapshot::render::<Sicilian>("Sicilian");
+    assert_eq!(
+        rendered,
+        "# Sicilian\n\nAlways play the Sicilian.\nUnless you're white.\n\
+        \n## najdorf\n\nThe Najdorf.\n\
+        \n## dragon\n\nThe Dragon.\n"
+    );
+}
+
+#[test]
+fn render_unnamed_fields_works() {
+    let rendered = snapshot::render::<Gambit>("Gambit");
+    assert_eq!(
+        rendered,
+        "# Gambit\n\nTwo pawns.\n\n## 0\n\nJust one.\n\n## 1\n\nAnd another.\n"
+    );
+}
+
+#[test]
+fn snapshot_matches() {
+    document_snapshot!(Sicilian, "snapshots/sicilian.txt");
+}
+
+// these compare without `document_snapshot!`, which would overwrite the
+// snapshots instead of panicking when run with `DOCUMENTED_UPDATE_SNAPSHOTS=1`
+
+#[test]
+#[should_panic(expected = "is outdated")]
+fn snapshot_mismatch_panics() {
+    snapshot::compare_snapshot(
+        &snapshot::render::<Gambit>("Gambit"),
+        Path::new(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/snapshots/sicilian.txt"
+        )),
+    );
+}
+
+#[test]
+#[should_panic(expected = "does not exist")]
+fn snapshot_missing_panics() {
+    snapshot::compare_snapshot(
+        &snapshot::render::<Gambit>("Gambit"),
+        Path::new(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/snapshots/nonexistent.txt"
+        )),
+    );
+}
@@ -0,0 +1,360 @@
+//! Doc string processing shared by the macros of `documented` at compile time
+//! and by `documented` itself at runtime, so that both behave the same.
+//!
+//! This is an implementation detail of `documented`, with no stability
+//! guarantees of its own.
+
+/// An inline markdown element, as produced by [`inline_markdown`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Inline {
+    /// Plain text.
+    Text(String),
+    /// The contents of inline code, without the backticks.
+    Code(String),
+    /// A run of `*` or `_` that opens or closes emphasis. Runs are paired with
+    /// a run of the same character and length; unpaired ones are plain text.
+    Delimiter(char, usize),
+    /// A link or image, with its (unparsed) text and destination. The
+    /// destination is `None` for reference links, e.g. `[text][ref]`.
+    Link(String, Option<String>),
+}
+
+/// Split a line of markdown into inline elements.
+///
+/// Only the markdown commonly found in doc comments is recognised: inline
+/// code, emphasis, and links (including intra-doc links and images).
+pub fn inline_markdown(line: &str) -> Vec<Inline> {
+    let chars = line.chars().collect::<Vec<_>>();
+    let mut inlines = Vec::new();
+    let mut text = String::new();
+    // the index in `inlines` of each delimiter, and whether it can open and close
+    let mut delimiters = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '`' => {
+                let ticks = chars[i..].iter().take_while(|&&c| c == '`').count();
+                let content_start = i + ticks;
+                let close = (content_start..chars.len())
+                    .find(|&j| chars[j..].iter().take_while(|&&c| c == '`').count() == ticks);
+                match close {
+                    Some(close) => {
+                        let code = chars[content_start..close].iter().collect();
+                        push_inline(&mut inlines, &mut text, Inline::Code(code));
+                        i = close + ticks;
+                    }
+                    None => {
+                        text.extend(&chars[i..content_start]);
+                        i = content_start;
+                    }
+                }
+            }
+            '!' if chars.get(i + 1) == Some(&'[') => i += 1,
+            '[' => match find_closing(&chars, i, '[', ']') {
+                Some(close) => {
+                    let link_text = chars[i + 1..close].iter().collect();
+                    i = close + 1;
+                    let mut url = None;
+                    // skip the link destination or reference, if any
+                    if let Some(&open @ ('(' | '[')) = chars.get(i) {
+                        let close_char = if open == '(' { ')' } else { ']' };
+                        if let Some(close) = find_closing(&chars, i, open, close_char) {
+                            if open == '(' {
+                                url = Some(chars[i + 1..close].iter().collect());
+                            }
+                            i = close + 1;
+                        }
+                    }
+                    push_inline(&mut inlines, &mut text, Inline::Link(link_text, url));
+                }
+                None => {
+                    text.push('[');
+                    i += 1;
+                }
+            },
+            c @ ('*' | '_') => {
+                let run = chars[i..].iter().take_while(|&&r| r == c).count();
+                let before = i.checked_sub(1).map(|j| chars[j]);
+                let after = chars.get(i + run).copied();
+                let is_word = |c: Option<char>| c.map_or(false, char::is_alphanumeric);
+                let is_space = |c: Option<char>| c.map_or(true, char::is_whitespace);
+                let left_flanking = !is_space(after) && !is_word(before);
+                let right_flanking = !is_space(before) && !is_word(after);
+                if left_flanking || right_flanking {
+                    push_inline(&mut inlines, &mut text, Inline::Delimiter(c, run));
+                    delimiters.push((inlines.len() - 1, left_flanking, right_flanking));
+                } else {
+                    text.extend(&chars[i..i + run]);
+                }
+                i += run;
+            }
+            c => {
+                text.push(c);
+                i += 1;
+            }
+        }
+    }
+    if !text.is_empty() {
+        inlines.push(Inline::Text(text));
+    }
+    unpair_delimiters(inlines, &delimiters)
+}
+
+/// Turn the delimiters that do not pair up into text, e.g. in `a * b`, `*args`
+/// or `2 *3`. `delimiters` holds the index of each delimiter in `inlines`, and
+/// whether it can open and close emphasis.
+fn unpair_delimiters(inlines: Vec<Inline>, delimiters: &[(usize, bool, bool)]) -> Vec<Inline> {
+    let mut paired = vec![false; inlines.len()];
+    let mut openers = Vec::<usize>::new();
+    for &(index, can_open, can_close) in delimiters {
+        let opener = openers
+            .iter()
+            .rposition(|&opener| inlines[opener] == inlines[index])
+            .filter(|_| can_close);
+        match opener {
+            Some(position) => {
+                paired[openers[position]] = true;
+                paired[index] = true;
+                openers.truncate(position);
+            }
+            None if can_open => openers.push(index),
+            None => {}
+        }
+    }
+
+    let mut merged = Vec::<Inline>::with_capacity(inlines.len());
+    for (inline, paired) in inlines.into_iter().zip(paired) {
+        let inline = match inline {
+            Inline::Delimiter(c, run) if !paired => Inline::Text(c.to_string().repeat(run)),
+            inline => inline,
+        };
+        match (merged.last_mut(), inline) {
+            (Some(Inline::Text(previous)), Inline::Text(text)) => previous.push_str(&text),
+            (_, inline) => merged.push(inline),
+        }
+    }
+    merged
+}
+
+/// Push an inline element, after any pending text.
+fn push_inline(inlines: &mut Vec<Inline>, text: &mut String, inline: Inline) {
+    if !text.is_empty() {
+        inlines.push(Inline::Text(std::mem::take(text)));
+    }
+    inlines.push(inline);
+}
+
+/// Find the index of the bracket closing the one at `open_index`.
+fn find_closing(chars: &[char], open_index: usize, open: char, close: char) -> Option<usize> {
+    let mut depth = 0;
+    for (i, &c) in chars.iter().enumerate().skip(open_index) {
+        if c == open {
+            depth += 1;
+        } else if c == close {
+            depth -= 1;
+            if depth == 0 {
+                return Some(i);
+            }
+        }
+    }
+    None
+}
+
+/// Remove common markdown syntax: headings, emphasis, inline code backticks,
+/// code fences, link reference definitions, and links (including intra-doc
+/// links), keeping only their text.
+pub fn strip_markdown(docs: &str) -> String {
+    let mut in_code_block = false;
+    let lines = docs
+        .lines()
+        .filter_map(|line| {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("```") {
+                in_code_block = !in_code_block;
+                return None;
+            }
+            if in_code_block {
+                return Some(line.to_string());
+            }
+            if is_link_definition(trimmed) {
+                return None;
+            }
+            let heading = trimmed.trim_start_matches('#');
+            let line = if heading.len() < trimmed.len() && heading.starts_with(' ') {
+                heading.trim_start()
+            } else {
+                line
+            };
+            Some(strip_inline_markdown(line))
+        })
+        .collect::<Vec<_>>();
+    lines.join("\n")
+}
+
+/// Whether a line is a link reference definition, e.g. `[foo]: crate::Foo`.
+fn is_link_definition(line: &str) -> bool {
+    line.starts_with('[')
+        && line
+            .find("]:")
+            .map_or(false, |close| !line[..close].contains(']'))
+}
+
+/// Remove the inline markdown syntax of a single line, keeping only the text.
+pub fn strip_inline_markdown(line: &str) -> String {
+    let mut stripped = String::with_capacity(line.len());
+    for inline in inline_markdown(line) {
+        match inline {
+            Inline::Text(text) | Inline::Code(text) => stripped.push_str(&text),
+            Inline::Delimiter(..) => {}
+            Inline::Link(text, _) => stripped.push_str(&strip_inline_markdown(&text)),
+        }
+    }
+    stripped
+}
+
+/// Join the lines of each paragraph and list item of `docs` with a space.
+/// Code blocks and blank lines are kept as they are, like in [`wrap`].
+pub fn unwrap_lines(docs: &str) -> String {
+    blocks(docs)
+        .into_iter()
+        .map(|block| match block {
+            Block::Verbatim(line) => line.to_string(),
+            Block::Text(lines) => {
+                let rest = lines[1..].iter().map(|line| line.trim_start());
+                std::iter::once(lines[0])
+                    .chain(rest)
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Trim whitespace from the start and/or end of each line of `docs`.
+///
+/// Unlike [`str::lines`], this keeps a trailing newline, matching the trimming
+/// of `documented_build::get_docs`.
+pub fn trim_lines(docs: &str, start: bool, end: bool) -> String {
+    docs.split('\n')
+        .map(|line| match (start, end) {
+            (true, true) => line.trim(),
+            (true, false) => line.trim_start(),
+            (false, true) => line.trim_end(),
+            (false, false) => line,
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Remove the longest common leading whitespace from all non-blank lines of
+/// `docs`, preserving any further indentation. Blank lines become empty.
+///
+/// Like [`trim_lines`], this keeps a trailing newline.
+pub fn dedent(docs: &str) -> String {
+    let indent_of = |line: &str| line.len() - line.trim_start().len();
+    let indent = docs
+        .split('\n')
+        .filter(|line| !line.trim().is_empty())
+        .map(indent_of)
+        .min()
+        .unwrap_or(0);
+    docs.split('\n')
+        .map(|line| line.get(indent..).filter(|_| indent_of(line) >= indent))
+        .map(|line| line.unwrap_or(""))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Reflow each paragraph and list item of `docs` to at most `columns`
+/// characters per line, breaking on whitespace. Words longer than `columns` are
+/// kept on their own line. Code blocks and blank lines are kept as they are.
+pub fn wrap(docs: &str, columns: usize) -> String {
+    blocks(docs)
+        .into_iter()
+        .map(|block| match block {
+            Block::Verbatim(line) => line.to_string(),
+            Block::Text(lines) => wrap_text(&lines, columns),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Reflow the lines of a paragraph or list item. List items keep their marker,
+/// and their continuation lines are indented to match.
+fn wrap_text(lines: &[&str], columns: usize) -> String {
+    let first = lines[0].trim_start();
+    let prefix = match list_marker_len(first) {
+        Some(marker_len) => &lines[0][..lines[0].len() - first.len() + marker_len],
+        None => "",
+    };
+    let indent = " ".repeat(prefix.chars().count());
+    let words = std::iter::once(&lines[0][prefix.len()..])
+        .chain(lines[1..].iter().copied())
+        .flat_map(str::split_whitespace);
+
+    let mut wrapped = prefix.to_string();
+    let mut width = indent.len();
+    for (i, word) in words.enumerate() {
+        let word_width = word.chars().count();
+        if i > 0 && width + 1 + word_width <= columns {
+            wrapped.push(' ');
+            width += 1;
+        } else if i > 0 {
+            wrapped.push('\n');
+            wrapped.push_str(&indent);
+            width = indent.len();
+        }
+        wrapped.push_str(word);
+        width += word_width;
+    }
+    wrapped
+}
+
+/// A block of lines, as produced by [`blocks`].
+enum Block<'a> {
+    /// The lines of a paragraph or list item, which may be reflowed.
+    Text(Vec<&'a str>),
+    /// A line to keep as it is: a blank line, or a line of a code block.
+    Verbatim(&'a str),
+}
+
+/// Split `docs` into the blocks that line-based processing must respect:
+/// paragraphs, list items, and the lines of fenced and indented code blocks
+/// and blank lines.
+fn blocks(docs: &str) -> Vec<Block<'_>> {
+    let mut blocks = Vec::new();
+    let mut in_fence = false;
+    for line in docs.split('\n') {
+        let trimmed = line.trim_start();
+        let is_fence = trimmed.starts_with("```");
+        let in_text = matches!(blocks.last(), Some(Block::Text(_)));
+        // indented code cannot interrupt a paragraph
+        let is_indented_code = !in_text && (line.starts_with("    ") || line.starts_with('\t'));
+        let is_verbatim = in_fence || is_fence || is_indented_code || trimmed.is_empty();
+        in_fence ^= is_fence;
+        match blocks.last_mut() {
+            _ if is_verbatim => blocks.push(Block::Verbatim(line)),
+            Some(Block::Text(lines)) if list_marker_len(trimmed).is_none() => lines.push(line),
+            _ => blocks.push(Block::Text(vec![line])),
+        }
+    }
+    blocks
+}
+
+/// The length of the list item marker at the start of `line`, including the
+/// space after it, e.g. 2 for `- Such` and 3 for `1. Such`.
+fn list_marker_len(line: &str) -> Option<usize> {
+    let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    let is_marker = |c: char| match digits {
+        0 => c == '-' || c == '*' || c == '+',
+        _ => c == '.' || c == ')',
+    };
+    let marker_len = match line[digits..].chars().next() {
+        Some(c) if digits <= 9 && is_marker(c) => digits + 1,
+        _ => return None,
+    };
+    line[marker_len..]
+        .starts_with(' ')
+        .then_some(marker_len + 1)
+}
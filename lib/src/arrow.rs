@@ -0,0 +1,57 @@
+//! Attaching field docs to Arrow schemas, and thus to Parquet files written
+//! with them.
+//!
+//! Any `arrow-schema` version from 53 to 58 is accepted, so that the version
+//! used by the rest of the dependency tree can be shared. Of these, only
+//! version 53 supports the MSRV of this crate; newer ones need a newer Rust.
+
+use std::sync::Arc;
+
+use arrow_schema::{Field, Schema};
+
+use crate::DocumentedFields;
+
+/// The field metadata key under which docs are stored.
+pub const DESCRIPTION_KEY: &str = "description";
+
+/// Attach the docs of each field of `T` as [`DESCRIPTION_KEY`] metadata to the
+/// schema field of the same name.
+///
+/// Schema fields without a matching documented field are left untouched.
+///
+/// # Example
+///
+/// ```rust
+/// use arrow_schema::{DataType, Field, Schema};
+/// use documented::{arrow::with_field_docs, DocumentedFields};
+///
+/// #[derive(DocumentedFields)]
+/// struct Game {
+///     /// Number of moves played.
+///     moves: u32,
+/// }
+///
+/// let schema = Schema::new(vec![Field::new("moves", DataType::UInt32, false)]);
+/// let schema = with_field_docs::<Game>(schema);
+/// assert_eq!(
+///     schema.field(0).metadata()["description"],
+///     "Number of moves played."
+/// );
+/// ```
+pub fn with_field_docs<T: DocumentedFields>(schema: Schema) -> Schema {
+    let fields = schema
+        .fields()
+        .iter()
+        .map(|field| match T::get_field_docs(field.name()) {
+            Ok(docs) => Arc::new(field_with_docs(field, docs)),
+            Err(_) => Arc::clone(field),
+        })
+        .collect::<Vec<_>>();
+    Schema::new_with_metadata(fields, schema.metadata)
+}
+
+fn field_with_docs(field: &Field, docs: &str) -> Field {
+    let mut metadata = field.metadata().clone();
+    metadata.insert(DESCRIPTION_KEY.to_string(), docs.to_string());
+    field.clone().with_metadata(metadata)
+}
@@ -0,0 +1,35 @@
+//! Compile-time concatenation of `&'static str`s.
+//!
+//! Used internally by generated code, e.g. to expand `{{include: ...}}`
+//! placeholders, and by [`docs_concat!`](crate::docs_concat).
+
+/// The total length of `parts`, in bytes.
+pub const fn len(parts: &[&str]) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        len += parts[i].len();
+        i += 1;
+    }
+    len
+}
+
+/// Concatenate `parts` into a byte array of length `N`, which must be exactly
+/// [`len(parts)`](len).
+pub const fn bytes<const N: usize>(parts: &[&str]) -> [u8; N] {
+    let mut bytes = [0; N];
+    let mut offset = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        let part = parts[i].as_bytes();
+        let mut j = 0;
+        while j < part.len() {
+            bytes[offset] = part[j];
+            offset += 1;
+            j += 1;
+        }
+        i += 1;
+    }
+    assert!(offset == N, "length mismatch in string concatenation");
+    bytes
+}
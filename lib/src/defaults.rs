@@ -0,0 +1,53 @@
+//! Pairing of field docs with default values.
+
+use serde::Serialize;
+
+use crate::{util::field_names_by_index, DocumentedFields};
+
+/// Get the name, docs, and default value of each named field of `T`.
+///
+/// The default values are taken from `T::default()`, serialised to JSON. A
+/// value is `None` if the serialised form of `T` has no entry under the
+/// field's name (e.g. because of `#[serde(rename)]` or `#[serde(skip)]`, or
+/// because `T` does not serialise to a map).
+///
+/// # Example
+///
+/// ```rust
+/// use documented::{defaults::field_defaults, DocumentedFields};
+/// use serde::Serialize;
+///
+/// #[derive(Default, DocumentedFields, Serialize)]
+/// struct Clock {
+///     /// Minutes per side.
+///     minutes: u32,
+///     /// Increment per move.
+///     increment: Option<u32>,
+/// }
+///
+/// assert_eq!(
+///     field_defaults::<Clock>(),
+///     [
+///         ("minutes", "Minutes per side.", Some("0".to_string())),
+///         ("increment", "Increment per move.", Some("null".to_string())),
+///     ]
+/// );
+/// ```
+pub fn field_defaults<T>() -> Vec<(&'static str, &'static str, Option<String>)>
+where
+    T: DocumentedFields + Default + Serialize,
+{
+    let defaults = serde_json::to_value(T::default()).ok();
+    field_names_by_index::<T>()
+        .into_iter()
+        .zip(T::FIELD_DOCS)
+        .filter_map(|(name, &docs)| name.map(|name| (name, docs)))
+        .map(|(name, docs)| {
+            let default = defaults
+                .as_ref()
+                .and_then(|defaults| defaults.get(name))
+                .map(ToString::to_string);
+            (name, docs, default)
+        })
+        .collect()
+}
@@ -0,0 +1,77 @@
+//! Implementations of the traits for common wrapper types, delegating to the
+//! wrapped type.
+
+use std::{rc::Rc, sync::Arc};
+
+use crate::{Documented, DocumentedFields, DocumentedFieldsOpt, DocumentedOpt, Error};
+
+macro_rules! impl_delegate {
+    ($(<$param:ident $(: ?$sized:ident)?> $wrapper:ty),+ $(,)?) => {
+        $(
+            impl<$param: Documented $(+ ?$sized)?> Documented for $wrapper {
+                const DOCS: &'static str = $param::DOCS;
+                const ITEM_NAME: &'static str = $param::ITEM_NAME;
+                const MODULE_PATH: &'static str = $param::MODULE_PATH;
+            }
+
+            impl<$param: DocumentedOpt $(+ ?$sized)?> DocumentedOpt for $wrapper {
+                const DOCS: Option<&'static str> = $param::DOCS;
+                const ITEM_NAME: &'static str = $param::ITEM_NAME;
+                const MODULE_PATH: &'static str = $param::MODULE_PATH;
+            }
+
+            impl<$param: DocumentedFields $(+ ?$sized)?> DocumentedFields for $wrapper {
+                const FIELD_DOCS: &'static [&'static str] = $param::FIELD_DOCS;
+                const FIELD_NAMES: &'static [&'static str] = $param::FIELD_NAMES;
+
+                fn __documented_get_index<S: AsRef<str>>(field_name: S) -> Option<usize> {
+                    $param::__documented_get_index(field_name)
+                }
+
+                fn __documented_get_flattened_field_docs(
+                    path: &[&str],
+                ) -> Option<Result<&'static str, Error>> {
+                    $param::__documented_get_flattened_field_docs(path)
+                }
+
+                fn __documented_get_nested_field_docs(
+                    index: usize,
+                    path: &[&str],
+                ) -> Option<Result<&'static str, Error>> {
+                    $param::__documented_get_nested_field_docs(index, path)
+                }
+            }
+
+            impl<$param: DocumentedFieldsOpt $(+ ?$sized)?> DocumentedFieldsOpt for $wrapper {
+                const FIELD_DOCS: &'static [Option<&'static str>] = $param::FIELD_DOCS;
+                const FIELD_NAMES: &'static [&'static str] = $param::FIELD_NAMES;
+
+                fn __documented_get_index<S: AsRef<str>>(field_name: S) -> Option<usize> {
+                    $param::__documented_get_index(field_name)
+                }
+
+                fn __documented_get_flattened_field_docs(
+                    path: &[&str],
+                ) -> Option<Result<&'static str, Error>> {
+                    $param::__documented_get_flattened_field_docs(path)
+                }
+
+                fn __documented_get_nested_field_docs(
+                    index: usize,
+                    path: &[&str],
+                ) -> Option<Result<&'static str, Error>> {
+                    $param::__documented_get_nested_field_docs(index, path)
+                }
+            }
+        )+
+    };
+}
+
+impl_delegate!(
+    <T: ?Sized> &T,
+    <T: ?Sized> &mut T,
+    <T: ?Sized> Box<T>,
+    <T: ?Sized> Rc<T>,
+    <T: ?Sized> Arc<T>,
+    <T> Option<T>,
+);
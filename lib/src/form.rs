@@ -0,0 +1,116 @@
+//! Framework-agnostic form metadata, for generating settings forms from
+//! documented structs.
+//!
+//! Rendering is left to the UI framework of choice; a [`FormField`] carries
+//! everything needed for an input's label and help text.
+
+use crate::{util::field_names_by_index, DocumentedFields};
+
+/// Describes a single input of a form.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FormField {
+    /// The name of the field, as accepted by
+    /// [`get_field_docs`](DocumentedFields::get_field_docs). `None` for
+    /// anonymous fields without a custom name.
+    pub name: Option<&'static str>,
+    /// A human-readable label, derived from the field name: separators are
+    /// replaced by spaces, and the first letter is capitalised. Anonymous
+    /// fields are labelled with their index.
+    pub label: String,
+    /// The docs of the field, suitable as help text.
+    pub docs: &'static str,
+    /// The group this field belongs to, if any.
+    pub group: Option<&'static str>,
+}
+
+/// Describe each field of `T` as an ungrouped form input.
+///
+/// # Example
+///
+/// ```rust
+/// use documented::{form::{fields, FormField}, DocumentedFields};
+///
+/// #[derive(DocumentedFields)]
+/// struct Clock {
+///     /// Minutes per side.
+///     base_minutes: u32,
+/// }
+///
+/// assert_eq!(
+///     fields::<Clock>(),
+///     [FormField {
+///         name: Some("base_minutes"),
+///         label: "Base minutes".to_string(),
+///         docs: "Minutes per side.",
+///         group: None,
+///     }]
+/// );
+/// ```
+pub fn fields<T: DocumentedFields>() -> Vec<FormField> {
+    field_names_by_index::<T>()
+        .into_iter()
+        .zip(T::FIELD_DOCS)
+        .enumerate()
+        .map(|(index, (name, &docs))| FormField {
+            name,
+            label: name.map_or_else(|| index.to_string(), label),
+            docs,
+            group: None,
+        })
+        .collect()
+}
+
+/// Describe each field of `T` as a form input in `group`.
+///
+/// This is useful for settings split into several structs, each rendered as
+/// its own section of the form.
+pub fn grouped_fields<T: DocumentedFields>(group: &'static str) -> Vec<FormField> {
+    let mut fields = fields::<T>();
+    for field in &mut fields {
+        field.group = Some(group);
+    }
+    fields
+}
+
+/// Turn a field name in any common case into a label, e.g. `time_control`,
+/// `timeControl`, and `TIME-CONTROL` all become `Time control`. Acronyms in
+/// otherwise lowercase names are kept, e.g. `start_FEN` becomes `Start FEN`.
+fn label(name: &str) -> String {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut after_lowercase = false;
+    for c in name.chars() {
+        let is_boundary =
+            c == '_' || c == '-' || c.is_whitespace() || (c.is_uppercase() && after_lowercase);
+        if is_boundary && !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+        if c.is_alphanumeric() {
+            word.push(c);
+        }
+        after_lowercase = c.is_lowercase() || c.is_numeric();
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    let is_acronym = |word: &String| word.chars().all(|c| !c.is_lowercase());
+    let all_acronyms = words.iter().all(is_acronym);
+    let words = words
+        .into_iter()
+        .map(|word| {
+            if is_acronym(&word) && !all_acronyms {
+                word
+            } else {
+                word.to_lowercase()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut chars = words.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
@@ -0,0 +1,316 @@
+//! Basic JSON Schema generation from documented types, without depending on
+//! a full-blown schema library.
+//!
+//! See [`DocumentedSchema`] and [`macro@crate::DocumentedSchema`] for usage.
+//!
+//! Derived types nested in another type are emitted once under `$defs` and
+//! referenced with `$ref`, so recursive types are supported.
+
+use std::{
+    any::type_name,
+    collections::{BTreeMap, HashMap},
+    rc::Rc,
+    sync::Arc,
+};
+
+use crate::util::escape_json;
+
+/// Produces a basic [JSON Schema](https://json-schema.org/) of a type, using
+/// doc comments as `description`s.
+///
+/// This trait is implemented for common standard library types, and can be
+/// derived for structs with named fields and enums with unit variants. See
+/// [`macro@crate::DocumentedSchema`].
+pub trait DocumentedSchema {
+    /// Whether a field of this type is required. Only `false` for [`Option`].
+    const REQUIRED: bool = true;
+
+    /// The JSON Schema of this type, as a JSON object.
+    ///
+    /// `description`, if set, overrides the type's own docs. It is used to
+    /// describe a field of this type with the field's docs.
+    fn json_schema_with_description(description: Option<&str>) -> String;
+
+    /// The JSON Schema of this type, as a JSON object.
+    fn json_schema() -> String {
+        Self::json_schema_with_description(None)
+    }
+
+    /// The JSON Schema of this type as part of another schema, adding the
+    /// definitions it refers to to `definitions`.
+    ///
+    /// Used internally by the derive macro. Inlines
+    /// [`json_schema_with_description`](Self::json_schema_with_description)
+    /// by default.
+    #[doc(hidden)]
+    fn json_schema_in(description: Option<&str>, _definitions: &mut Definitions) -> String {
+        Self::json_schema_with_description(description)
+    }
+}
+
+/// The types defined under `$defs` while building a schema.
+///
+/// Used internally by the derive macro.
+#[doc(hidden)]
+#[derive(Debug, Default)]
+pub struct Definitions {
+    /// The `$ref` of each type by its name, with the root as `#`.
+    refs: HashMap<&'static str, String>,
+    /// The definitions in order, with their schemas filled in once built.
+    defs: Vec<(String, String)>,
+    /// The root type, until its schema is being built.
+    pending_root: Option<&'static str>,
+}
+
+impl Definitions {
+    /// Start building the schema of `T`, which is referred to as `#`.
+    pub fn root<T: ?Sized>() -> Self {
+        let name = type_name::<T>();
+        Self {
+            refs: HashMap::from([(name, "#".to_string())]),
+            defs: Vec::new(),
+            pending_root: Some(name),
+        }
+    }
+
+    /// The schema of `T` with `title`, where `body` builds it from its
+    /// description.
+    ///
+    /// For the root, this is the schema itself. Otherwise, the schema is
+    /// defined under `$defs` the first time, and a `$ref` to it is returned.
+    pub fn schema<T: ?Sized>(
+        &mut self,
+        title: &str,
+        description: Option<&str>,
+        body: impl FnOnce(&mut Self, Option<&str>) -> String,
+    ) -> String {
+        let name = type_name::<T>();
+        if self.pending_root == Some(name) {
+            self.pending_root = None;
+            return body(self, description);
+        }
+        if let Some(reference) = self.refs.get(name) {
+            return schema(description, &[("$ref", escape_json(reference))]);
+        }
+        let mut def_name = title.to_string();
+        let mut suffix = 1;
+        while self.defs.iter().any(|(existing, _)| *existing == def_name) {
+            suffix += 1;
+            def_name = format!("{title}{suffix}");
+        }
+        let reference = format!("#/$defs/{def_name}");
+        self.refs.insert(name, reference.clone());
+        let index = self.defs.len();
+        self.defs.push((def_name, String::new()));
+        self.defs[index].1 = body(self, None);
+        schema(description, &[("$ref", escape_json(&reference))])
+    }
+
+    /// Add the collected definitions to the root `schema`.
+    pub fn complete(self, schema: String) -> String {
+        if self.defs.is_empty() {
+            return schema;
+        }
+        let defs = self
+            .defs
+            .iter()
+            .map(|(name, schema)| format!("{}:{schema}", escape_json(name)))
+            .collect::<Vec<_>>();
+        let body = schema.strip_suffix('}').expect("schemas are JSON objects");
+        let separator = if body.ends_with('{') { "" } else { "," };
+        format!(r#"{body}{separator}"$defs":{{{}}}}}"#, defs.join(","))
+    }
+}
+
+/// The schema of `T` on its own, with all definitions it refers to.
+fn standalone<T: DocumentedSchema + ?Sized>(description: Option<&str>) -> String {
+    let mut definitions = Definitions::default();
+    let schema = T::json_schema_in(description, &mut definitions);
+    definitions.complete(schema)
+}
+
+/// Build a schema object from its description and already serialised
+/// key-value pairs.
+fn schema(description: Option<&str>, entries: &[(&str, String)]) -> String {
+    let description = description.map(|description| ("description", escape_json(description)));
+    let entries = description
+        .iter()
+        .chain(entries)
+        .map(|(key, value)| format!(r#""{key}":{value}"#))
+        .collect::<Vec<_>>();
+    format!("{{{}}}", entries.join(","))
+}
+
+/// Build the schema of an object from its fields.
+///
+/// Used internally by the derive macro.
+#[doc(hidden)]
+pub fn object_schema(
+    title: &str,
+    description: Option<&str>,
+    properties: &[(&str, String, bool)],
+) -> String {
+    let required = properties
+        .iter()
+        .filter(|(_, _, required)| *required)
+        .map(|(name, _, _)| escape_json(name))
+        .collect::<Vec<_>>();
+    let properties = properties
+        .iter()
+        .map(|(name, schema, _)| format!("{}:{schema}", escape_json(name)))
+        .collect::<Vec<_>>();
+    schema(
+        description,
+        &[
+            ("title", escape_json(title)),
+            ("type", escape_json("object")),
+            ("properties", format!("{{{}}}", properties.join(","))),
+            ("required", format!("[{}]", required.join(","))),
+        ],
+    )
+}
+
+/// Build the schema of a string enumeration from its variants.
+///
+/// Used internally by the derive macro.
+#[doc(hidden)]
+pub fn enum_schema(
+    title: &str,
+    description: Option<&str>,
+    variants: &[(&str, Option<&str>)],
+) -> String {
+    let variants = variants
+        .iter()
+        .map(|&(name, description)| schema(description, &[("const", escape_json(name))]))
+        .collect::<Vec<_>>();
+    schema(
+        description,
+        &[
+            ("title", escape_json(title)),
+            ("oneOf", format!("[{}]", variants.join(","))),
+        ],
+    )
+}
+
+macro_rules! impl_primitive {
+    ($json_type:literal => $($ty:ty),+) => {
+        $(
+            impl DocumentedSchema for $ty {
+                fn json_schema_with_description(description: Option<&str>) -> String {
+                    schema(description, &[("type", escape_json($json_type))])
+                }
+            }
+        )+
+    };
+}
+
+impl_primitive!("boolean" => bool);
+impl_primitive!("integer" => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_primitive!("number" => f32, f64);
+impl_primitive!("string" => char, str, String);
+
+impl<T: DocumentedSchema + ?Sized> DocumentedSchema for &T {
+    const REQUIRED: bool = T::REQUIRED;
+
+    fn json_schema_with_description(description: Option<&str>) -> String {
+        T::json_schema_with_description(description)
+    }
+
+    fn json_schema_in(description: Option<&str>, definitions: &mut Definitions) -> String {
+        T::json_schema_in(description, definitions)
+    }
+}
+
+macro_rules! impl_wrapper {
+    ($($wrapper:ident),+) => {
+        $(
+            impl<T: DocumentedSchema + ?Sized> DocumentedSchema for $wrapper<T> {
+                const REQUIRED: bool = T::REQUIRED;
+
+                fn json_schema_with_description(description: Option<&str>) -> String {
+                    T::json_schema_with_description(description)
+                }
+
+                fn json_schema_in(
+                    description: Option<&str>,
+                    definitions: &mut Definitions,
+                ) -> String {
+                    T::json_schema_in(description, definitions)
+                }
+            }
+        )+
+    };
+}
+
+impl_wrapper!(Box, Rc, Arc);
+
+impl<T: DocumentedSchema> DocumentedSchema for Option<T> {
+    const REQUIRED: bool = false;
+
+    fn json_schema_with_description(description: Option<&str>) -> String {
+        T::json_schema_with_description(description)
+    }
+
+    fn json_schema_in(description: Option<&str>, definitions: &mut Definitions) -> String {
+        T::json_schema_in(description, definitions)
+    }
+}
+
+macro_rules! impl_array {
+    ($($ty:ty),+) => {
+        $(
+            impl<T: DocumentedSchema> DocumentedSchema for $ty {
+                fn json_schema_with_description(description: Option<&str>) -> String {
+                    standalone::<Self>(description)
+                }
+
+                fn json_schema_in(
+                    description: Option<&str>,
+                    definitions: &mut Definitions,
+                ) -> String {
+                    let items = T::json_schema_in(None, definitions);
+                    schema(description, &[("type", escape_json("array")), ("items", items)])
+                }
+            }
+        )+
+    };
+}
+
+impl_array!([T], Vec<T>);
+
+impl<T: DocumentedSchema, const N: usize> DocumentedSchema for [T; N] {
+    fn json_schema_with_description(description: Option<&str>) -> String {
+        <[T]>::json_schema_with_description(description)
+    }
+
+    fn json_schema_in(description: Option<&str>, definitions: &mut Definitions) -> String {
+        <[T]>::json_schema_in(description, definitions)
+    }
+}
+
+impl<K, V: DocumentedSchema, S> DocumentedSchema for HashMap<K, V, S> {
+    fn json_schema_with_description(description: Option<&str>) -> String {
+        standalone::<Self>(description)
+    }
+
+    fn json_schema_in(description: Option<&str>, definitions: &mut Definitions) -> String {
+        let values = V::json_schema_in(None, definitions);
+        schema(
+            description,
+            &[
+                ("type", escape_json("object")),
+                ("additionalProperties", values),
+            ],
+        )
+    }
+}
+
+impl<K, V: DocumentedSchema> DocumentedSchema for BTreeMap<K, V> {
+    fn json_schema_with_description(description: Option<&str>) -> String {
+        HashMap::<K, V>::json_schema_with_description(description)
+    }
+
+    fn json_schema_in(description: Option<&str>, definitions: &mut Definitions) -> String {
+        HashMap::<K, V>::json_schema_in(description, definitions)
+    }
+}
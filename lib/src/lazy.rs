@@ -0,0 +1,44 @@
+//! Lazily post-processed docs, as generated by `#[docs_const(lazy = ...)]`.
+
+use std::{fmt, ops::Deref};
+
+use once_cell::sync::OnceCell;
+
+/// Docs that are passed through a transform function the first time they are
+/// accessed.
+///
+/// This is the type of the `static` generated by
+/// [`docs_const`](crate::docs_const) with the `lazy` option. It derefs to the
+/// transformed [`String`].
+pub struct LazyDocs {
+    docs: &'static str,
+    transform: fn(&str) -> String,
+    value: OnceCell<String>,
+}
+
+impl LazyDocs {
+    /// Create a new instance which calls `transform` with `docs` on first
+    /// access.
+    pub const fn new(docs: &'static str, transform: fn(&str) -> String) -> Self {
+        Self { docs, transform, value: OnceCell::new() }
+    }
+
+    /// Get the transformed docs, running the transform if it has not run yet.
+    pub fn get(&self) -> &String {
+        self.value.get_or_init(|| (self.transform)(self.docs))
+    }
+}
+
+impl Deref for LazyDocs {
+    type Target = String;
+
+    fn deref(&self) -> &Self::Target {
+        self.get()
+    }
+}
+
+impl fmt::Debug for LazyDocs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("LazyDocs").field(self.get()).finish()
+    }
+}
@@ -1,29 +1,318 @@
 #![doc = include_str!("../../README.md")]
 
+#[cfg(feature = "arrow")]
+pub mod arrow;
+#[doc(hidden)]
+pub mod concat;
+#[cfg(feature = "serde")]
+pub mod defaults;
+mod delegate;
+pub mod form;
+pub mod json_schema;
+#[cfg(feature = "customise")]
+pub mod lazy;
+pub mod markdown;
+pub mod process;
+pub mod reference;
+#[cfg(feature = "render")]
+pub mod render;
+#[cfg(feature = "serde_path_to_error")]
+pub mod serde_path;
+pub mod snapshot;
+pub mod tree;
+pub(crate) mod util;
+
 pub use documented_macros::{
-    docs_const, Documented, DocumentedFields, DocumentedFieldsOpt, DocumentedOpt,
-    DocumentedVariants, DocumentedVariantsOpt,
+    cargo_features_docs, docs_const, Documented, DocumentedFields, DocumentedFieldsOpt,
+    DocumentedOpt, DocumentedSchema, DocumentedTree, DocumentedVariantFields, DocumentedVariants,
+    DocumentedVariantsOpt,
 };
+pub use json_schema::DocumentedSchema;
+#[cfg(feature = "customise")]
+pub use lazy::LazyDocs;
+pub use tree::DocumentedTree;
 
+#[cfg(feature = "phf")]
 #[doc(hidden)]
 pub use phf as _private_phf_reexport_for_macro;
 
+/// Compare the full documentation of a type against a checked-in snapshot
+/// file, panicking on mismatch.
+///
+/// The type must implement both [`Documented`] and [`DocumentedFields`]. The
+/// snapshot path is relative to the calling crate's `CARGO_MANIFEST_DIR`.
+///
+/// To create or update snapshots, set the environment variable
+/// `DOCUMENTED_UPDATE_SNAPSHOTS=1` (see [`snapshot::UPDATE_ENV_VAR`]).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use documented::{document_snapshot, Documented, DocumentedFields};
+///
+/// /// Opening theory.
+/// #[derive(Documented, DocumentedFields)]
+/// struct Sicilian {
+///     /// The Najdorf.
+///     najdorf: bool,
+/// }
+///
+/// #[test]
+/// fn sicilian_docs() {
+///     document_snapshot!(Sicilian, "snapshots/sicilian.txt");
+/// }
+/// ```
+#[macro_export]
+macro_rules! document_snapshot {
+    ($ty:ty, $path:literal) => {
+        $crate::snapshot::assert_snapshot(
+            &$crate::snapshot::render::<$ty>(::core::stringify!($ty)),
+            ::std::path::Path::new(::core::concat!(
+                ::core::env!("CARGO_MANIFEST_DIR"),
+                "/",
+                $path
+            )),
+        )
+    };
+}
+
+/// Concatenate the [`DOCS`](Documented::DOCS) of several types into one
+/// `&'static str` at compile time, separated by a blank line.
+///
+/// A custom separator can be given with `separator = <EXPR>` before the types.
+/// It must be a constant `&'static str` expression.
+///
+/// # Example
+///
+/// ```rust
+/// use documented::{docs_concat, Documented};
+///
+/// /// Castle early.
+/// #[derive(Documented)]
+/// struct Safety;
+///
+/// /// Control the centre.
+/// #[derive(Documented)]
+/// struct Centre;
+///
+/// const ADVICE: &str = docs_concat!(Safety, Centre);
+/// assert_eq!(ADVICE, "Castle early.\n\nControl the centre.");
+///
+/// const TERSE_ADVICE: &str = docs_concat!(separator = " ", Safety, Centre);
+/// assert_eq!(TERSE_ADVICE, "Castle early. Control the centre.");
+/// ```
+#[macro_export]
+macro_rules! docs_concat {
+    (separator = $separator:expr, $first:ty $(, $rest:ty)* $(,)?) => {{
+        const PARTS: &[&str] = &[
+            <$first as $crate::Documented>::DOCS,
+            $($separator, <$rest as $crate::Documented>::DOCS,)*
+        ];
+        const BYTES: [u8; $crate::concat::len(PARTS)] = $crate::concat::bytes(PARTS);
+        match ::core::str::from_utf8(&BYTES) {
+            ::core::result::Result::Ok(docs) => docs,
+            ::core::result::Result::Err(_) => ::core::panic!("concatenated docs are not valid UTF-8"),
+        }
+    }};
+    ($($ty:ty),+ $(,)?) => {
+        $crate::docs_concat!(separator = "\n\n", $($ty),+)
+    };
+}
+
+/// Implement [`Documented`] manually, for types that cannot use the derive
+/// macro (e.g. generated code).
+///
+/// [`ITEM_NAME`](Documented::ITEM_NAME) is the type as written, and
+/// [`MODULE_PATH`](Documented::MODULE_PATH) is the module of the invocation.
+///
+/// # Example
+///
+/// ```rust
+/// use documented::{impl_documented, Documented};
+///
+/// struct Gambit;
+/// impl_documented!(Gambit, "Sacrifice material for the initiative.");
+///
+/// assert_eq!(Gambit::DOCS, "Sacrifice material for the initiative.");
+/// assert_eq!(Gambit::ITEM_NAME, "Gambit");
+/// ```
+#[macro_export]
+macro_rules! impl_documented {
+    ($ty:ty, $docs:expr $(,)?) => {
+        impl $crate::Documented for $ty {
+            const DOCS: &'static str = $docs;
+            const ITEM_NAME: &'static str = ::core::stringify!($ty);
+            const MODULE_PATH: &'static str = ::core::module_path!();
+        }
+    };
+}
+
+/// Implement [`DocumentedOpt`] manually. See [`impl_documented!`].
+#[macro_export]
+macro_rules! impl_documented_opt {
+    ($ty:ty, $docs:expr $(,)?) => {
+        impl $crate::DocumentedOpt for $ty {
+            const DOCS: ::core::option::Option<&'static str> = $docs;
+            const ITEM_NAME: &'static str = ::core::stringify!($ty);
+            const MODULE_PATH: &'static str = ::core::module_path!();
+        }
+    };
+}
+
+/// Implement [`DocumentedFields`] manually, for types that cannot use the
+/// derive macro (e.g. generated code).
+///
+/// Fields are listed by name, in declaration order.
+///
+/// # Example
+///
+/// ```rust
+/// use documented::{impl_documented_fields, DocumentedFields};
+///
+/// struct Clock {
+///     base: u32,
+///     increment: u32,
+/// }
+/// impl_documented_fields!(Clock {
+///     base: "Minutes per player.",
+///     increment: "Seconds added per move.",
+/// });
+///
+/// assert_eq!(Clock::get_field_docs("increment"), Ok("Seconds added per move."));
+/// ```
+#[macro_export]
+macro_rules! impl_documented_fields {
+    ($ty:ty { $($field:ident: $docs:expr),* $(,)? }) => {
+        impl $crate::DocumentedFields for $ty {
+            const FIELD_DOCS: &'static [&'static str] = &[$($docs),*];
+            const FIELD_NAMES: &'static [&'static str] = &[$(::core::stringify!($field)),*];
+        }
+    };
+}
+
+/// Implement [`DocumentedFieldsOpt`] manually. See [`impl_documented_fields!`].
+///
+/// # Example
+///
+/// ```rust
+/// use documented::{impl_documented_fields_opt, DocumentedFieldsOpt, Error};
+///
+/// struct Clock {
+///     base: u32,
+///     delay: u32,
+/// }
+/// impl_documented_fields_opt!(Clock {
+///     base: Some("Minutes per player."),
+///     delay: None,
+/// });
+///
+/// assert_eq!(Clock::get_field_docs("base"), Ok("Minutes per player."));
+/// assert_eq!(Clock::get_field_docs("delay"), Err(Error::NoDocComments("delay".into())));
+/// ```
+#[macro_export]
+macro_rules! impl_documented_fields_opt {
+    ($ty:ty { $($field:ident: $docs:expr),* $(,)? }) => {
+        impl $crate::DocumentedFieldsOpt for $ty {
+            const FIELD_DOCS: &'static [::core::option::Option<&'static str>] = &[$($docs),*];
+            const FIELD_NAMES: &'static [&'static str] = &[$(::core::stringify!($field)),*];
+        }
+    };
+}
+
 /// Adds an associated constant [`DOCS`](Self::DOCS) on your type containing its
 /// documentation, allowing you to access its documentation at runtime.
 ///
 /// The associated derive macro of this trait will error if the type does not
 /// have any doc comments. Use [`DocumentedOpt`] if this is undesirable.
 ///
+/// This trait is implemented for references, [`Box`], [`Rc`](std::rc::Rc),
+/// [`Arc`](std::sync::Arc), and [`Option`] of documented types, delegating to
+/// the wrapped type. The same goes for [`DocumentedOpt`],
+/// [`DocumentedFields`], and [`DocumentedFieldsOpt`].
+///
 /// For how to use the derive macro, see [`macro@Documented`].
 pub trait Documented {
     /// The static doc comments on this type.
     const DOCS: &'static str;
+    /// The name of this type, as written in its definition (i.e. without
+    /// generics, module path, or `r#` prefix).
+    const ITEM_NAME: &'static str;
+    /// The module path of this type, as given by [`module_path!`] at its
+    /// definition.
+    const MODULE_PATH: &'static str;
 }
 
 /// The optional variant of [`Documented`].
 pub trait DocumentedOpt {
     /// The static doc comments on this type.
     const DOCS: Option<&'static str>;
+    /// The name of this type, as written in its definition (i.e. without
+    /// generics, module path, or `r#` prefix).
+    const ITEM_NAME: &'static str;
+    /// The module path of this type, as given by [`module_path!`] at its
+    /// definition.
+    const MODULE_PATH: &'static str;
+}
+
+/// An object-safe companion of [`Documented`], for accessing docs through
+/// trait objects.
+///
+/// This trait is implemented for every type implementing [`Documented`].
+///
+/// # Example
+///
+/// ```rust
+/// use documented::{Documented, DocumentedDyn};
+///
+/// /// Solid, but drawish.
+/// #[derive(Documented)]
+/// struct Berlin;
+///
+/// /// Sharp.
+/// #[derive(Documented)]
+/// struct Najdorf;
+///
+/// let repertoire: Vec<Box<dyn DocumentedDyn>> = vec![Box::new(Berlin), Box::new(Najdorf)];
+/// let docs = repertoire
+///     .iter()
+///     .map(|opening| format!("{}: {}", opening.item_name(), opening.docs()))
+///     .collect::<Vec<_>>();
+/// assert_eq!(docs, ["Berlin: Solid, but drawish.", "Najdorf: Sharp."]);
+/// ```
+pub trait DocumentedDyn {
+    /// The static doc comments on the type of this value.
+    fn docs(&self) -> &'static str;
+    /// The name of the type of this value. See [`Documented::ITEM_NAME`].
+    fn item_name(&self) -> &'static str;
+}
+
+impl<T: Documented + ?Sized> DocumentedDyn for T {
+    fn docs(&self) -> &'static str {
+        T::DOCS
+    }
+
+    fn item_name(&self) -> &'static str {
+        T::ITEM_NAME
+    }
+}
+
+/// The optional variant of [`DocumentedDyn`], implemented for every type
+/// implementing [`DocumentedOpt`].
+pub trait DocumentedOptDyn {
+    /// The static doc comments on the type of this value.
+    fn docs(&self) -> Option<&'static str>;
+    /// The name of the type of this value. See [`DocumentedOpt::ITEM_NAME`].
+    fn item_name(&self) -> &'static str;
+}
+
+impl<T: DocumentedOpt + ?Sized> DocumentedOptDyn for T {
+    fn docs(&self) -> Option<&'static str> {
+        T::DOCS
+    }
+
+    fn item_name(&self) -> &'static str {
+        T::ITEM_NAME
+    }
 }
 
 /// Adds an associated constant [`FIELD_DOCS`](Self::FIELD_DOCS) on your type
@@ -35,7 +324,9 @@ pub trait DocumentedOpt {
 /// Use [`DocumentedFieldsOpt`] if this is undesirable.
 ///
 /// This trait and associated derive macro works on structs, enums, and unions.
-/// For enums, you may find [`DocumentedVariants`] more ergonomic to use.
+/// For enums, you may find [`DocumentedVariants`] more ergonomic to use;
+/// deriving this trait on an enum without opting in is deprecated (see
+/// [`macro@DocumentedFields`]).
 ///
 /// For how to use the derive macro, see [`macro@DocumentedFields`].
 pub trait DocumentedFields {
@@ -52,25 +343,133 @@ pub trait DocumentedFields {
     /// It is therefore recommended to use [`Self::get_field_docs`] rather than
     /// the index to lookup the corresponding documentation.
     const FIELD_NAMES: &'static [&'static str];
+    /// The number of fields or variants, i.e. the length of
+    /// [`FIELD_DOCS`](Self::FIELD_DOCS).
+    const FIELD_COUNT: usize = Self::FIELD_DOCS.len();
 
     /// Method internally used by `documented`.
+    ///
+    /// Defaults to a linear search of [`FIELD_NAMES`](Self::FIELD_NAMES). The
+    /// derive macros override it with a perfect hash map, or with a `match`
+    /// if the `phf` feature is disabled.
     #[doc(hidden)]
-    fn __documented_get_index<T: AsRef<str>>(field_name: T) -> Option<usize>;
+    fn __documented_get_index<T: AsRef<str>>(field_name: T) -> Option<usize> {
+        let field_name = field_name.as_ref();
+        Self::FIELD_NAMES
+            .iter()
+            .position(|&name| name == field_name)
+    }
 
-    /// Get a field's documentation using its name.
+    /// Get a field's documentation using its index, e.g. for anonymous fields.
     ///
-    /// Note that for structs with anonymous fields (i.e. tuple structs), this
-    /// method will always return [`Error::NoSuchField`] by default. For this
-    /// case, you can either:
+    /// Errors with [`Error::NoSuchField`] (containing the index) if the index
+    /// is out of bounds.
+    fn get_field_docs_by_index(index: usize) -> Result<&'static str, Error> {
+        Self::FIELD_DOCS
+            .get(index)
+            .copied()
+            .ok_or_else(|| Error::NoSuchField {
+                name: index.to_string(),
+                suggestion: None,
+            })
+    }
+
+    /// Get a field's documentation using its name.
     ///
-    /// 1. use [`FIELD_DOCS`](Self::FIELD_DOCS) directly instead;
-    /// 2. [set a custom name](macro@DocumentedFields#2-set-a-custom-name-for-a-specific-field-for-get_field_docs-like-so) for the anonymous field.
+    /// Anonymous fields (i.e. fields in tuple structs) are looked up by their
+    /// index as a string, e.g. `"0"`, unless another field has that name. You
+    /// can also [set a custom name](macro@DocumentedFields#2-set-a-custom-name-for-a-specific-field-for-get_field_docs-like-so)
+    /// for them.
     fn get_field_docs<T: AsRef<str>>(field_name: T) -> Result<&'static str, Error> {
         let field_name = field_name.as_ref();
-        let index = Self::__documented_get_index(field_name)
-            .ok_or_else(|| Error::NoSuchField(field_name.into()))?;
+        let Some(index) = Self::__documented_get_index(field_name) else {
+            let candidates = Self::FIELD_NAMES.iter().copied();
+            return Self::__documented_get_flattened_field_docs(&[field_name])
+                .unwrap_or_else(|| Err(Error::no_such_field(field_name, candidates)));
+        };
         Ok(Self::FIELD_DOCS[index])
     }
+
+    /// Method internally used by `documented`.
+    #[doc(hidden)]
+    fn __documented_get_flattened_field_docs(
+        _path: &[&str],
+    ) -> Option<Result<&'static str, Error>> {
+        None
+    }
+
+    /// Method internally used by `documented`.
+    #[doc(hidden)]
+    fn __documented_get_nested_field_docs(
+        _index: usize,
+        _path: &[&str],
+    ) -> Option<Result<&'static str, Error>> {
+        None
+    }
+
+    /// Get the documentation of a possibly nested field using its path, e.g.
+    /// `["engine", "threads"]` or `"engine.threads".split('.')`.
+    ///
+    /// Only fields marked as [nested](macro@DocumentedFields#7-allow-looking-up-nested-fields-by-path-like-so)
+    /// can be descended into. Errors contain the full (dotted) path.
+    fn get_field_docs_by_path<I, S>(path: I) -> Result<&'static str, Error>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let path = path.into_iter().collect::<Vec<_>>();
+        let path = path.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+        let Some((&field_name, rest)) = path.split_first() else {
+            return Err(Error::NoSuchField { name: String::new(), suggestion: None });
+        };
+        let Some(&child_name) = rest.first() else {
+            return Self::get_field_docs(field_name);
+        };
+        let Some(index) = Self::__documented_get_index(field_name) else {
+            let candidates = Self::FIELD_NAMES.iter().copied();
+            return Self::__documented_get_flattened_field_docs(&path)
+                .unwrap_or_else(|| Err(Error::no_such_field(field_name, candidates)));
+        };
+        Self::__documented_get_nested_field_docs(index, rest)
+            .unwrap_or_else(|| {
+                Err(Error::NoSuchField {
+                    name: child_name.into(),
+                    suggestion: None,
+                })
+            })
+            .map_err(|err| err.prefixed(field_name))
+    }
+
+    /// Iterate over the name and docs of each field or variant, in field
+    /// order.
+    ///
+    /// Names are `None` for anonymous fields without a custom name.
+    ///
+    /// ```rust
+    /// # use documented::DocumentedFields;
+    /// #[derive(DocumentedFields)]
+    /// struct Move(
+    ///     /// The origin square.
+    ///     #[documented_fields(rename = "from")]
+    ///     u8,
+    ///     /// The destination square.
+    ///     u8,
+    /// );
+    ///
+    /// assert_eq!(
+    ///     Move::field_docs_iter().collect::<Vec<_>>(),
+    ///     [
+    ///         (Some("from"), "The origin square."),
+    ///         (None, "The destination square."),
+    ///     ]
+    /// );
+    /// ```
+    fn field_docs_iter() -> FieldDocsIter<&'static str> {
+        let names = util::names_by_index(Self::FIELD_NAMES, Self::FIELD_DOCS.len(), |name| {
+            Self::__documented_get_index(name)
+        });
+        FieldDocsIter::new(names, Self::FIELD_DOCS)
+    }
 }
 
 /// The optional variant of [`DocumentedFields`].
@@ -88,25 +487,112 @@ pub trait DocumentedFieldsOpt {
     /// It is therefore recommended to use [`Self::get_field_docs`] rather than
     /// the index to lookup the corresponding documentation.
     const FIELD_NAMES: &'static [&'static str];
+    /// The number of fields or variants, i.e. the length of
+    /// [`FIELD_DOCS`](Self::FIELD_DOCS).
+    const FIELD_COUNT: usize = Self::FIELD_DOCS.len();
 
     /// Method internally used by `documented`.
+    ///
+    /// Defaults to a linear search of [`FIELD_NAMES`](Self::FIELD_NAMES). The
+    /// derive macros override it with a perfect hash map, or with a `match`
+    /// if the `phf` feature is disabled.
     #[doc(hidden)]
-    fn __documented_get_index<T: AsRef<str>>(field_name: T) -> Option<usize>;
+    fn __documented_get_index<T: AsRef<str>>(field_name: T) -> Option<usize> {
+        let field_name = field_name.as_ref();
+        Self::FIELD_NAMES
+            .iter()
+            .position(|&name| name == field_name)
+    }
 
-    /// Get a field's documentation using its name.
+    /// Get a field's documentation using its index, e.g. for anonymous fields.
     ///
-    /// Note that for structs with anonymous fields (i.e. tuple structs), this
-    /// method will always return [`Error::NoSuchField`] by default. For this
-    /// case, you can either:
+    /// Errors with [`Error::NoSuchField`] if the index is out of bounds, and
+    /// with [`Error::NoDocComments`] if the field has no doc comments. Both
+    /// contain the index.
+    fn get_field_docs_by_index(index: usize) -> Result<&'static str, Error> {
+        Self::FIELD_DOCS
+            .get(index)
+            .ok_or_else(|| Error::NoSuchField {
+                name: index.to_string(),
+                suggestion: None,
+            })?
+            .ok_or_else(|| Error::NoDocComments(index.to_string()))
+    }
+
+    /// Get a field's documentation using its name.
     ///
-    /// 1. use [`FIELD_DOCS`](Self::FIELD_DOCS) directly instead;
-    /// 2. [set a custom name](macro@DocumentedFields#2-set-a-custom-name-for-a-specific-field-for-get_field_docs-like-so) for the anonymous field.
+    /// Anonymous fields (i.e. fields in tuple structs) are looked up by their
+    /// index as a string, e.g. `"0"`, unless another field has that name. You
+    /// can also [set a custom name](macro@DocumentedFields#2-set-a-custom-name-for-a-specific-field-for-get_field_docs-like-so)
+    /// for them.
     fn get_field_docs<T: AsRef<str>>(field_name: T) -> Result<&'static str, Error> {
         let field_name = field_name.as_ref();
-        let index = Self::__documented_get_index(field_name)
-            .ok_or_else(|| Error::NoSuchField(field_name.into()))?;
+        let Some(index) = Self::__documented_get_index(field_name) else {
+            let candidates = Self::FIELD_NAMES.iter().copied();
+            return Self::__documented_get_flattened_field_docs(&[field_name])
+                .unwrap_or_else(|| Err(Error::no_such_field(field_name, candidates)));
+        };
         Self::FIELD_DOCS[index].ok_or_else(|| Error::NoDocComments(field_name.into()))
     }
+
+    /// Method internally used by `documented`.
+    #[doc(hidden)]
+    fn __documented_get_flattened_field_docs(
+        _path: &[&str],
+    ) -> Option<Result<&'static str, Error>> {
+        None
+    }
+
+    /// Method internally used by `documented`.
+    #[doc(hidden)]
+    fn __documented_get_nested_field_docs(
+        _index: usize,
+        _path: &[&str],
+    ) -> Option<Result<&'static str, Error>> {
+        None
+    }
+
+    /// Get the documentation of a possibly nested field using its path, e.g.
+    /// `["engine", "threads"]` or `"engine.threads".split('.')`.
+    ///
+    /// Only fields marked as [nested](macro@DocumentedFields#7-allow-looking-up-nested-fields-by-path-like-so)
+    /// can be descended into. Errors contain the full (dotted) path.
+    fn get_field_docs_by_path<I, S>(path: I) -> Result<&'static str, Error>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let path = path.into_iter().collect::<Vec<_>>();
+        let path = path.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+        let Some((&field_name, rest)) = path.split_first() else {
+            return Err(Error::NoSuchField { name: String::new(), suggestion: None });
+        };
+        let Some(&child_name) = rest.first() else {
+            return Self::get_field_docs(field_name);
+        };
+        let Some(index) = Self::__documented_get_index(field_name) else {
+            let candidates = Self::FIELD_NAMES.iter().copied();
+            return Self::__documented_get_flattened_field_docs(&path)
+                .unwrap_or_else(|| Err(Error::no_such_field(field_name, candidates)));
+        };
+        Self::__documented_get_nested_field_docs(index, rest)
+            .unwrap_or_else(|| {
+                Err(Error::NoSuchField {
+                    name: child_name.into(),
+                    suggestion: None,
+                })
+            })
+            .map_err(|err| err.prefixed(field_name))
+    }
+
+    /// Iterate over the name and docs of each field or variant, in field
+    /// order. See [`DocumentedFields::field_docs_iter`].
+    fn field_docs_iter() -> FieldDocsIter<Option<&'static str>> {
+        let names = util::names_by_index(Self::FIELD_NAMES, Self::FIELD_DOCS.len(), |name| {
+            Self::__documented_get_index(name)
+        });
+        FieldDocsIter::new(names, Self::FIELD_DOCS)
+    }
 }
 
 /// Adds an associated function [`get_variant_docs`](Self::get_variant_docs) to
@@ -120,23 +606,263 @@ pub trait DocumentedFieldsOpt {
 ///
 /// For how to use the derive macro, see [`macro@DocumentedVariants`].
 pub trait DocumentedVariants {
+    /// The name and doc comments of each variant of this enum, indexed by
+    /// variant order.
+    const VARIANT_DOCS: &'static [(&'static str, &'static str)];
+
+    /// Method internally used by `documented`.
+    ///
+    /// Defaults to a linear search of [`VARIANT_DOCS`](Self::VARIANT_DOCS).
+    /// The derive macros override it with a perfect hash map, or with a
+    /// `match` if the `phf` feature is disabled.
+    #[doc(hidden)]
+    fn __documented_get_variant_index<T: AsRef<str>>(variant_name: T) -> Option<usize> {
+        let variant_name = variant_name.as_ref();
+        Self::VARIANT_DOCS
+            .iter()
+            .position(|&(name, _)| name == variant_name)
+    }
+
+    /// Get a variant's documentation using its name, without needing an
+    /// instance of the variant.
+    ///
+    /// Errors with [`Error::NoSuchField`] if no variant has this name.
+    fn get_variant_docs_by_name<T: AsRef<str>>(variant_name: T) -> Result<&'static str, Error> {
+        let variant_name = variant_name.as_ref();
+        let candidates = Self::VARIANT_DOCS.iter().map(|(name, _)| *name);
+        let index = Self::__documented_get_variant_index(variant_name)
+            .ok_or_else(|| Error::no_such_field(variant_name, candidates))?;
+        Ok(Self::VARIANT_DOCS[index].1)
+    }
+
     /// Get the documentation on this enum variant.
     fn get_variant_docs(&self) -> &'static str;
+    /// Get the shape of this enum variant.
+    fn get_variant_shape(&self) -> VariantShape;
 }
 
 /// The optional variant of [`DocumentedVariants`].
 pub trait DocumentedVariantsOpt {
+    /// The name and doc comments of each variant of this enum, indexed by
+    /// variant order.
+    const VARIANT_DOCS: &'static [(&'static str, Option<&'static str>)];
+
+    /// Method internally used by `documented`.
+    ///
+    /// Defaults to a linear search of [`VARIANT_DOCS`](Self::VARIANT_DOCS).
+    /// The derive macros override it with a perfect hash map, or with a
+    /// `match` if the `phf` feature is disabled.
+    #[doc(hidden)]
+    fn __documented_get_variant_index<T: AsRef<str>>(variant_name: T) -> Option<usize> {
+        let variant_name = variant_name.as_ref();
+        Self::VARIANT_DOCS
+            .iter()
+            .position(|&(name, _)| name == variant_name)
+    }
+
+    /// Get a variant's documentation using its name, without needing an
+    /// instance of the variant.
+    ///
+    /// Errors with [`Error::NoSuchField`] if no variant has this name, and
+    /// with [`Error::NoVariantDocComments`] if the variant has no doc
+    /// comments.
+    fn get_variant_docs_by_name<T: AsRef<str>>(variant_name: T) -> Result<&'static str, Error> {
+        let variant_name = variant_name.as_ref();
+        let candidates = Self::VARIANT_DOCS.iter().map(|(name, _)| *name);
+        let index = Self::__documented_get_variant_index(variant_name)
+            .ok_or_else(|| Error::no_such_field(variant_name, candidates))?;
+        Self::VARIANT_DOCS[index]
+            .1
+            .ok_or_else(|| Error::NoVariantDocComments(variant_name.into()))
+    }
+
     /// Get the documentation on this enum variant.
     fn get_variant_docs(&self) -> Option<&'static str>;
+    /// Get the shape of this enum variant.
+    fn get_variant_shape(&self) -> VariantShape;
+}
+
+/// An iterator over the names and docs of the fields or variants of a type.
+///
+/// Created by [`DocumentedFields::field_docs_iter`] and
+/// [`DocumentedFieldsOpt::field_docs_iter`].
+#[derive(Clone, Debug)]
+pub struct FieldDocsIter<D: 'static> {
+    names: std::vec::IntoIter<Option<&'static str>>,
+    docs: std::slice::Iter<'static, D>,
+}
+
+impl<D> FieldDocsIter<D> {
+    fn new(names: Vec<Option<&'static str>>, docs: &'static [D]) -> Self {
+        Self {
+            names: names.into_iter(),
+            docs: docs.iter(),
+        }
+    }
+}
+
+impl<D: Copy> Iterator for FieldDocsIter<D> {
+    type Item = (Option<&'static str>, D);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some((self.names.next()?, *self.docs.next()?))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.docs.size_hint()
+    }
+}
+
+impl<D: Copy> ExactSizeIterator for FieldDocsIter<D> {}
+
+/// The shape of an enum variant, with field types as written in the source.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum VariantShape {
+    /// A variant without fields, e.g. `Resign`.
+    Unit,
+    /// A variant with anonymous fields, e.g. `Move(Square, Square)`, as the
+    /// type of each field.
+    Tuple(&'static [&'static str]),
+    /// A variant with named fields, e.g. `Promote { to: Piece }`, as the name
+    /// and type of each field.
+    Struct(&'static [(&'static str, &'static str)]),
+}
+
+impl VariantShape {
+    /// The number of fields.
+    pub fn arity(&self) -> usize {
+        match self {
+            Self::Unit => 0,
+            Self::Tuple(types) => types.len(),
+            Self::Struct(fields) => fields.len(),
+        }
+    }
+
+    /// Render the signature of a variant with this shape, e.g.
+    /// `Move(Square, Square)` or `Promote { to: Piece }`.
+    pub fn signature(&self, variant_name: &str) -> String {
+        match self {
+            Self::Unit => variant_name.to_string(),
+            Self::Tuple(types) => format!("{variant_name}({})", types.join(", ")),
+            Self::Struct([]) => format!("{variant_name} {{}}"),
+            Self::Struct(fields) => {
+                let fields = fields
+                    .iter()
+                    .map(|(name, ty)| format!("{name}: {ty}"))
+                    .collect::<Vec<_>>();
+                format!("{variant_name} {{ {} }}", fields.join(", "))
+            }
+        }
+    }
+}
+
+/// Adds an associated constant [`VARIANT_FIELD_DOCS`](Self::VARIANT_FIELD_DOCS)
+/// describing the fields of every variant of an enum, along with their
+/// documentation, without needing an instance of the enum.
+///
+/// Fields without doc comments are `None`, so the associated derive macro
+/// never errors on missing docs.
+///
+/// This trait and associated derive macro works on enums only.
+///
+/// For how to use the derive macro, see [`macro@DocumentedVariantFields`].
+pub trait DocumentedVariantFields {
+    /// The name of each variant, indexed by variant order, along with the
+    /// name and doc comments of each of its fields, indexed by field order.
+    ///
+    /// Anonymous fields (i.e. fields in tuple variants) are named by their
+    /// index.
+    #[allow(clippy::type_complexity)]
+    const VARIANT_FIELD_DOCS: &'static [(
+        &'static str,
+        &'static [(&'static str, Option<&'static str>)],
+    )];
 }
 
 /// Errors of `documented`.
+///
+/// More variants may be added in minor releases, so matches on this type need
+/// a wildcard arm.
 #[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+#[non_exhaustive]
 pub enum Error {
     /// The requested field does not have doc comments.
+    ///
+    /// This includes the variants of an enum when looked up as fields, e.g.
+    /// with [`DocumentedFieldsOpt::get_field_docs`].
     #[error(r#"The field "{0}" has no doc comments"#)]
     NoDocComments(String),
+    /// The requested variant does not have doc comments, when looked up with
+    /// [`DocumentedVariantsOpt::get_variant_docs_by_name`].
+    #[error(r#"The variant "{0}" has no doc comments"#)]
+    NoVariantDocComments(String),
     /// The requested field does not exist.
-    #[error(r#"No field named "{0}" exists"#)]
-    NoSuchField(String),
+    #[error(r#"No field named "{name}" exists{}"#, did_you_mean(.suggestion))]
+    NoSuchField {
+        /// The requested name.
+        name: String,
+        /// The existing name closest to the requested one, if any is close
+        /// enough to be a likely misspelling.
+        suggestion: Option<&'static str>,
+    },
+}
+
+impl Error {
+    /// The existing field name closest to the requested one, if this is a
+    /// [`Error::NoSuchField`] and any name is close enough to be a likely
+    /// misspelling.
+    ///
+    /// ```rust
+    /// # use documented::DocumentedFields;
+    /// #[derive(DocumentedFields)]
+    /// struct Clock {
+    ///     /// Time left for white, in milliseconds.
+    ///     wtime: u64,
+    ///     /// Time left for black, in milliseconds.
+    ///     btime: u64,
+    /// }
+    ///
+    /// let err = Clock::get_field_docs("wtim").unwrap_err();
+    /// assert_eq!(err.suggestion(), Some("wtime"));
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     r#"No field named "wtim" exists; did you mean "wtime"?"#
+    /// );
+    /// ```
+    pub fn suggestion(&self) -> Option<&'static str> {
+        match self {
+            Self::NoSuchField { suggestion, .. } => *suggestion,
+            Self::NoDocComments(_) | Self::NoVariantDocComments(_) => None,
+        }
+    }
+
+    /// Create a [`Error::NoSuchField`], suggesting the closest of
+    /// `candidates`.
+    fn no_such_field(
+        name: impl Into<String>,
+        candidates: impl IntoIterator<Item = &'static str>,
+    ) -> Self {
+        let name = name.into();
+        let suggestion = util::closest_name(&name, candidates);
+        Self::NoSuchField { name, suggestion }
+    }
+
+    /// Prefix the field name of this error with the name of its parent field.
+    fn prefixed(self, parent: &str) -> Self {
+        match self {
+            Self::NoDocComments(name) => Self::NoDocComments(format!("{parent}.{name}")),
+            Self::NoVariantDocComments(name) => {
+                Self::NoVariantDocComments(format!("{parent}.{name}"))
+            }
+            Self::NoSuchField { name, suggestion } => Self::NoSuchField {
+                name: format!("{parent}.{name}"),
+                suggestion,
+            },
+        }
+    }
+}
+
+/// Format the suggestion of [`Error::NoSuchField`].
+fn did_you_mean(suggestion: &Option<&str>) -> String {
+    suggestion.map_or_else(String::new, |name| format!(r#"; did you mean "{name}"?"#))
 }
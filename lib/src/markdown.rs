@@ -0,0 +1,89 @@
+//! Rendering of documented types into markdown, e.g. to generate a
+//! configuration reference for a README.
+
+use crate::{util::field_names_by_index, DocumentedFields, DocumentedVariants};
+
+/// Render the fields of a type and their docs as a markdown table.
+///
+/// Anonymous fields without a custom name are listed by their index.
+///
+/// # Example
+///
+/// ```rust
+/// use documented::{markdown, DocumentedFields};
+///
+/// #[derive(DocumentedFields)]
+/// struct Clock {
+///     /// Minutes per side.
+///     minutes: u32,
+///     /// Seconds added per move.
+///     increment: u32,
+/// }
+///
+/// assert_eq!(
+///     markdown::field_table::<Clock>(),
+///     "| Field | Description |\n\
+///     | --- | --- |\n\
+///     | `minutes` | Minutes per side. |\n\
+///     | `increment` | Seconds added per move. |\n"
+/// );
+/// ```
+pub fn field_table<T: DocumentedFields>() -> String {
+    let rows = field_names_by_index::<T>()
+        .into_iter()
+        .zip(T::FIELD_DOCS)
+        .enumerate()
+        .map(|(index, (name, docs))| {
+            let name = name.map_or_else(|| index.to_string(), str::to_string);
+            (name, *docs)
+        });
+    table("Field", rows)
+}
+
+/// Render the variants of an enum and their docs as a markdown table.
+///
+/// # Example
+///
+/// ```rust
+/// use documented::{markdown, DocumentedVariants};
+///
+/// #[derive(DocumentedVariants)]
+/// enum Result {
+///     /// White won.
+///     WhiteWins,
+///     /// Neither | side won.
+///     Draw,
+/// }
+///
+/// assert_eq!(
+///     markdown::variant_table::<Result>(),
+///     "| Variant | Description |\n\
+///     | --- | --- |\n\
+///     | `WhiteWins` | White won. |\n\
+///     | `Draw` | Neither \\| side won. |\n"
+/// );
+/// ```
+pub fn variant_table<T: DocumentedVariants>() -> String {
+    let rows = T::VARIANT_DOCS
+        .iter()
+        .map(|&(name, docs)| (name.to_string(), docs));
+    table("Variant", rows)
+}
+
+/// Render a two-column table of names and docs.
+fn table(name_heading: &str, rows: impl Iterator<Item = (String, &'static str)>) -> String {
+    let mut table = format!("| {name_heading} | Description |\n| --- | --- |\n");
+    for (name, docs) in rows {
+        table.push_str(&format!("| `{name}` | {} |\n", escape_cell(docs)));
+    }
+    table
+}
+
+/// Escape pipes, and replace line breaks with `<br>`, since table cells can
+/// only span a single line.
+fn escape_cell(docs: &str) -> String {
+    docs.replace('|', "\\|")
+        .lines()
+        .collect::<Vec<_>>()
+        .join("<br>")
+}
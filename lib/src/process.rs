@@ -0,0 +1,121 @@
+//! Runtime processing of doc strings.
+//!
+//! The compile-time options of the macros cannot cover context-dependent
+//! rendering (e.g. terminal width), so [`Docs`] provides a small set of
+//! composable processing steps instead.
+
+use std::{borrow::Cow, fmt};
+
+use crate::Documented;
+
+/// A doc string being processed.
+///
+/// Each step computes its result into a new string, but only keeps it if it
+/// differs from the input. A pipeline that turns out to be a no-op therefore
+/// still allocates along the way, but ends up borrowing the original string.
+///
+/// # Example
+///
+/// ```rust
+/// use documented::{process::Docs, Documented};
+///
+/// ///   Play **the** `Caro-Kann`
+/// ///   if you want to be solid.
+/// #[derive(Documented)]
+/// #[documented(trim = false)]
+/// struct Advice;
+///
+/// let docs = Docs::of::<Advice>()
+///     .dedent()
+///     .strip_markdown()
+///     .unwrap_lines()
+///     .wrap(20)
+///     .to_string();
+/// assert_eq!(docs, "Play the Caro-Kann\nif you want to be\nsolid.");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Docs<'a>(Cow<'a, str>);
+
+impl<'a> Docs<'a> {
+    /// Start processing an arbitrary string.
+    pub fn new(docs: impl Into<Cow<'a, str>>) -> Self {
+        Self(docs.into())
+    }
+
+    /// Get the processed string.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Unwrap the processed string.
+    pub fn into_inner(self) -> Cow<'a, str> {
+        self.0
+    }
+
+    /// Apply a step, which returns `None` if it leaves the string unchanged.
+    fn apply(self, step: impl FnOnce(&str) -> Option<String>) -> Self {
+        match step(&self.0) {
+            Some(processed) => Self(processed.into()),
+            None => self,
+        }
+    }
+
+    /// Trim whitespace from both ends of each line.
+    pub fn trim_lines(self) -> Self {
+        self.apply(|docs| changed(docs, documented_text::trim_lines(docs, true, true)))
+    }
+
+    /// Remove the longest common leading whitespace from all non-blank lines,
+    /// preserving any further indentation. Blank lines become empty.
+    pub fn dedent(self) -> Self {
+        self.apply(|docs| changed(docs, documented_text::dedent(docs)))
+    }
+
+    /// Join the lines of each paragraph and list item with a space, keeping
+    /// blank lines as paragraph breaks. Code blocks are kept as they are.
+    ///
+    /// This is the same as the `unwrap_lines` option of the macros.
+    pub fn unwrap_lines(self) -> Self {
+        self.apply(|docs| changed(docs, documented_text::unwrap_lines(docs)))
+    }
+
+    /// Reflow each paragraph and list item to at most `columns` characters per
+    /// line, breaking on whitespace. Words longer than `columns` are kept on
+    /// their own line. Code blocks are kept as they are.
+    ///
+    /// This is the same as the `wrap` option of the macros.
+    pub fn wrap(self, columns: usize) -> Self {
+        self.apply(|docs| changed(docs, documented_text::wrap(docs, columns)))
+    }
+
+    /// Remove common markdown syntax: headings, emphasis, inline code
+    /// backticks, code fences, and links (including intra-doc links), keeping
+    /// only their text.
+    pub fn strip_markdown(self) -> Self {
+        self.apply(|docs| changed(docs, documented_text::strip_markdown(docs)))
+    }
+}
+
+impl Docs<'static> {
+    /// Start processing the docs of a type.
+    pub fn of<T: Documented + ?Sized>() -> Self {
+        Self(Cow::Borrowed(T::DOCS))
+    }
+}
+
+impl fmt::Display for Docs<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for Docs<'_> {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Return the processed string only if it differs from the original.
+fn changed(original: &str, processed: String) -> Option<String> {
+    (original != processed).then_some(processed)
+}
@@ -0,0 +1,109 @@
+//! Framework-agnostic rendering of a documented type into a "settings
+//! reference", e.g. to be served from an HTTP endpoint.
+
+use std::fmt::Write;
+
+use crate::{
+    util::{escape_html, escape_json, field_names_by_index},
+    Documented, DocumentedFields,
+};
+
+/// Render the docs of a type and its fields as an HTML fragment.
+///
+/// Anonymous fields without a custom name are titled with their index.
+///
+/// # Example
+///
+/// ```rust
+/// use documented::{reference, Documented, DocumentedFields};
+///
+/// /// Time control.
+/// #[derive(Documented, DocumentedFields)]
+/// struct Clock {
+///     /// Minutes <per> side.
+///     minutes: u32,
+/// }
+///
+/// assert_eq!(
+///     reference::html::<Clock>("Clock"),
+///     "<section class=\"documented-reference\">\n\
+///     <h2>Clock</h2>\n\
+///     <p>Time control.</p>\n\
+///     <dl>\n\
+///     <dt><code>minutes</code></dt>\n\
+///     <dd><p>Minutes &lt;per&gt; side.</p></dd>\n\
+///     </dl>\n\
+///     </section>\n"
+/// );
+/// ```
+pub fn html<T>(type_name: &str) -> String
+where
+    T: Documented + DocumentedFields,
+{
+    let mut html = String::from("<section class=\"documented-reference\">\n");
+    let _ = writeln!(html, "<h2>{}</h2>", escape_html(type_name));
+    let _ = writeln!(html, "{}", html_paragraphs(T::DOCS));
+    html.push_str("<dl>\n");
+    for (index, (name, docs)) in field_names_by_index::<T>()
+        .into_iter()
+        .zip(T::FIELD_DOCS)
+        .enumerate()
+    {
+        let name = name.map_or_else(|| index.to_string(), str::to_string);
+        let _ = writeln!(html, "<dt><code>{}</code></dt>", escape_html(&name));
+        let _ = writeln!(html, "<dd>{}</dd>", html_paragraphs(docs));
+    }
+    html.push_str("</dl>\n</section>\n");
+    html
+}
+
+/// Render the docs of a type and its fields as a JSON object.
+///
+/// The object has the shape
+/// `{"name": ..., "docs": ..., "fields": [{"name": ..., "docs": ...}, ...]}`,
+/// where the name of anonymous fields without a custom name is `null`.
+///
+/// # Example
+///
+/// ```rust
+/// use documented::{reference, Documented, DocumentedFields};
+///
+/// /// Time control.
+/// #[derive(Documented, DocumentedFields)]
+/// struct Clock {
+///     /// Minutes "per" side.
+///     minutes: u32,
+/// }
+///
+/// assert_eq!(
+///     reference::json::<Clock>("Clock"),
+///     r#"{"name":"Clock","docs":"Time control.","fields":[{"name":"minutes","docs":"Minutes \"per\" side."}]}"#
+/// );
+/// ```
+pub fn json<T>(type_name: &str) -> String
+where
+    T: Documented + DocumentedFields,
+{
+    let fields = field_names_by_index::<T>()
+        .into_iter()
+        .zip(T::FIELD_DOCS)
+        .map(|(name, docs)| {
+            let name = name.map_or_else(|| "null".to_string(), escape_json);
+            format!(r#"{{"name":{name},"docs":{}}}"#, escape_json(docs))
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+    format!(
+        r#"{{"name":{},"docs":{},"fields":[{fields}]}}"#,
+        escape_json(type_name),
+        escape_json(T::DOCS)
+    )
+}
+
+/// Escape HTML and wrap each blank-line-separated paragraph in `<p>`.
+fn html_paragraphs(docs: &str) -> String {
+    docs.split("\n\n")
+        .filter(|paragraph| !paragraph.trim().is_empty())
+        .map(|paragraph| format!("<p>{}</p>", escape_html(paragraph)))
+        .collect()
+}
@@ -0,0 +1,358 @@
+//! Rendering of markdown docs for terminals and web UIs.
+//!
+//! Only the markdown commonly found in doc comments is supported: headings,
+//! paragraphs, bullet lists, fenced code blocks, emphasis, inline code, and
+//! links (including intra-doc links).
+
+use documented_text::{inline_markdown, Inline};
+
+use crate::{util::escape_html, Documented, DocumentedFields, DocumentedVariants, Error};
+
+/// Render markdown docs with ANSI escape codes for terminals.
+///
+/// Headings are bold and underlined, code is cyan, and links are underlined.
+/// The destinations of links are not shown.
+///
+/// # Example
+///
+/// ```rust
+/// use documented::render;
+///
+/// assert_eq!(
+///     render::ansi("Play **the** `Caro-Kann`."),
+///     "Play \x1b[1mthe\x1b[22m \x1b[36mCaro-Kann\x1b[39m."
+/// );
+/// ```
+pub fn ansi(docs: &str) -> String {
+    render::<Ansi>(docs, "\n\n")
+}
+
+/// Render markdown docs as an HTML fragment.
+///
+/// Only links with a URL (i.e. not intra-doc links) are rendered as `<a>`
+/// elements.
+///
+/// # Example
+///
+/// ```rust
+/// use documented::render;
+///
+/// assert_eq!(
+///     render::html("# Openings\n\nPlay [the Caro-Kann](https://lichess.org)."),
+///     "<h1>Openings</h1>\n\
+///     <p>Play <a href=\"https://lichess.org\">the Caro-Kann</a>.</p>"
+/// );
+/// ```
+pub fn html(docs: &str) -> String {
+    render::<Html>(docs, "\n")
+}
+
+/// Render the docs of a type with ANSI escape codes, like [`ansi`].
+pub fn to_ansi<T: Documented + ?Sized>() -> String {
+    ansi(T::DOCS)
+}
+
+/// Render the docs of a type as an HTML fragment, like [`html`].
+pub fn to_html<T: Documented + ?Sized>() -> String {
+    html(T::DOCS)
+}
+
+/// Render the docs of a field with ANSI escape codes, like [`ansi`].
+///
+/// # Example
+///
+/// ```rust
+/// use documented::{render, DocumentedFields};
+///
+/// #[derive(DocumentedFields)]
+/// struct Clock {
+///     /// Minutes *per side*.
+///     minutes: u32,
+/// }
+///
+/// assert_eq!(
+///     render::field_to_ansi::<Clock>("minutes"),
+///     Ok("Minutes \x1b[3mper side\x1b[23m.".to_string())
+/// );
+/// ```
+pub fn field_to_ansi<T: DocumentedFields + ?Sized>(field_name: &str) -> Result<String, Error> {
+    T::get_field_docs(field_name).map(ansi)
+}
+
+/// Render the docs of a field as an HTML fragment, like [`html`].
+pub fn field_to_html<T: DocumentedFields + ?Sized>(field_name: &str) -> Result<String, Error> {
+    T::get_field_docs(field_name).map(html)
+}
+
+/// Render the docs of an enum variant with ANSI escape codes, like [`ansi`].
+pub fn variant_to_ansi<T: DocumentedVariants>(variant: &T) -> String {
+    ansi(variant.get_variant_docs())
+}
+
+/// Render the docs of an enum variant as an HTML fragment, like [`html`].
+pub fn variant_to_html<T: DocumentedVariants>(variant: &T) -> String {
+    html(variant.get_variant_docs())
+}
+
+/// A block of markdown.
+enum Block<'a> {
+    Heading(usize, String),
+    Paragraph(String),
+    List(Vec<String>),
+    Code(Vec<&'a str>),
+}
+
+/// Split markdown docs into blocks.
+fn blocks(docs: &str) -> Vec<Block<'_>> {
+    let mut blocks = Vec::new();
+    let mut lines = docs.lines().peekable();
+    while let Some(line) = lines.next() {
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() {
+            continue;
+        }
+        if trimmed.starts_with("```") {
+            let mut code = Vec::new();
+            for line in lines.by_ref() {
+                if line.trim_start().starts_with("```") {
+                    break;
+                }
+                code.push(line);
+            }
+            blocks.push(Block::Code(code));
+        } else if let Some((level, heading)) = heading(trimmed) {
+            blocks.push(Block::Heading(level, heading.to_string()));
+        } else if let Some(item) = list_item(trimmed) {
+            let mut items = vec![item.to_string()];
+            while let Some(line) = lines.next_if(|line| is_continuation(line)) {
+                let trimmed = line.trim_start();
+                match list_item(trimmed) {
+                    Some(item) => items.push(item.to_string()),
+                    None => {
+                        let last = items.last_mut().expect("there is at least one item");
+                        last.push('\n');
+                        last.push_str(trimmed);
+                    }
+                }
+            }
+            blocks.push(Block::List(items));
+        } else {
+            let mut paragraph = trimmed.to_string();
+            while let Some(line) = lines
+                .next_if(|line| is_continuation(line) && list_item(line.trim_start()).is_none())
+            {
+                paragraph.push('\n');
+                paragraph.push_str(line.trim_start());
+            }
+            blocks.push(Block::Paragraph(paragraph));
+        }
+    }
+    blocks
+}
+
+/// Whether a line continues the current paragraph or list.
+fn is_continuation(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    !trimmed.is_empty() && !trimmed.starts_with("```") && heading(trimmed).is_none()
+}
+
+/// The level and text of an ATX heading, e.g. `## Config`.
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    let text = &line[level..];
+    ((1..=6).contains(&level) && (text.is_empty() || text.starts_with(' ')))
+        .then(|| (level, text.trim()))
+}
+
+/// The text of a bullet list item, e.g. `- Castle early`.
+fn list_item(line: &str) -> Option<&str> {
+    ["- ", "* ", "+ "]
+        .iter()
+        .find_map(|bullet| line.strip_prefix(bullet))
+}
+
+fn render<F: Format>(docs: &str, block_separator: &str) -> String {
+    blocks(docs)
+        .iter()
+        .map(|block| {
+            let mut out = String::new();
+            match block {
+                Block::Heading(level, text) => F::heading(&mut out, *level, &inline::<F>(text)),
+                Block::Paragraph(text) => F::paragraph(&mut out, &inline::<F>(text)),
+                Block::List(items) => {
+                    let items = items
+                        .iter()
+                        .map(|item| inline::<F>(item))
+                        .collect::<Vec<_>>();
+                    F::list(&mut out, &items);
+                }
+                Block::Code(lines) => F::code_block(&mut out, lines),
+            }
+            out
+        })
+        .collect::<Vec<_>>()
+        .join(block_separator)
+}
+
+/// Render the inline markdown of a paragraph, heading, or list item.
+fn inline<F: Format>(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut strong = false;
+    let mut emphasis = false;
+    for element in inline_markdown(text) {
+        match element {
+            Inline::Text(text) => F::text(&mut out, &text),
+            Inline::Code(code) => F::code(&mut out, &code),
+            Inline::Link(text, url) => {
+                // intra-doc links have no meaning outside of rustdoc
+                let url = url.filter(|url| url.contains("://"));
+                F::link(&mut out, &inline::<F>(&text), url.as_deref());
+            }
+            Inline::Delimiter(marker, run) => {
+                let (toggle_strong, toggle_emphasis) = match run {
+                    1 => (false, true),
+                    2 => (true, false),
+                    3 => (true, true),
+                    _ => {
+                        F::text(&mut out, &marker.to_string().repeat(run));
+                        continue;
+                    }
+                };
+                if toggle_strong {
+                    strong = !strong;
+                    F::strong(&mut out, strong);
+                }
+                if toggle_emphasis {
+                    emphasis = !emphasis;
+                    F::emphasis(&mut out, emphasis);
+                }
+            }
+        }
+    }
+    // delimiters are paired, so all styles are closed again here
+    out
+}
+
+/// An output format. Block methods receive already rendered inline content.
+trait Format {
+    fn text(out: &mut String, text: &str);
+    fn code(out: &mut String, code: &str);
+    fn strong(out: &mut String, open: bool);
+    fn emphasis(out: &mut String, open: bool);
+    fn link(out: &mut String, text: &str, url: Option<&str>);
+    fn heading(out: &mut String, level: usize, text: &str);
+    fn paragraph(out: &mut String, text: &str);
+    fn list(out: &mut String, items: &[String]);
+    fn code_block(out: &mut String, lines: &[&str]);
+}
+
+struct Ansi;
+
+impl Format for Ansi {
+    fn text(out: &mut String, text: &str) {
+        out.push_str(text);
+    }
+
+    fn code(out: &mut String, code: &str) {
+        out.push_str("\x1b[36m");
+        out.push_str(code);
+        out.push_str("\x1b[39m");
+    }
+
+    fn strong(out: &mut String, open: bool) {
+        out.push_str(if open { "\x1b[1m" } else { "\x1b[22m" });
+    }
+
+    fn emphasis(out: &mut String, open: bool) {
+        out.push_str(if open { "\x1b[3m" } else { "\x1b[23m" });
+    }
+
+    fn link(out: &mut String, text: &str, _url: Option<&str>) {
+        out.push_str("\x1b[4m");
+        out.push_str(text);
+        out.push_str("\x1b[24m");
+    }
+
+    fn heading(out: &mut String, _level: usize, text: &str) {
+        out.push_str("\x1b[1m\x1b[4m");
+        out.push_str(text);
+        out.push_str("\x1b[24m\x1b[22m");
+    }
+
+    fn paragraph(out: &mut String, text: &str) {
+        out.push_str(text);
+    }
+
+    fn list(out: &mut String, items: &[String]) {
+        let items = items
+            .iter()
+            .map(|item| format!("  \u{2022} {}", item.replace('\n', "\n    ")))
+            .collect::<Vec<_>>();
+        out.push_str(&items.join("\n"));
+    }
+
+    fn code_block(out: &mut String, lines: &[&str]) {
+        let lines = lines
+            .iter()
+            .map(|line| format!("    \x1b[36m{line}\x1b[39m"))
+            .collect::<Vec<_>>();
+        out.push_str(&lines.join("\n"));
+    }
+}
+
+struct Html;
+
+impl Format for Html {
+    fn text(out: &mut String, text: &str) {
+        out.push_str(&escape_html(text));
+    }
+
+    fn code(out: &mut String, code: &str) {
+        out.push_str("<code>");
+        out.push_str(&escape_html(code));
+        out.push_str("</code>");
+    }
+
+    fn strong(out: &mut String, open: bool) {
+        out.push_str(if open { "<strong>" } else { "</strong>" });
+    }
+
+    fn emphasis(out: &mut String, open: bool) {
+        out.push_str(if open { "<em>" } else { "</em>" });
+    }
+
+    fn link(out: &mut String, text: &str, url: Option<&str>) {
+        match url {
+            Some(url) => {
+                out.push_str("<a href=\"");
+                out.push_str(&escape_html(url));
+                out.push_str("\">");
+                out.push_str(text);
+                out.push_str("</a>");
+            }
+            None => out.push_str(text),
+        }
+    }
+
+    fn heading(out: &mut String, level: usize, text: &str) {
+        out.push_str(&format!("<h{level}>{text}</h{level}>"));
+    }
+
+    fn paragraph(out: &mut String, text: &str) {
+        out.push_str(&format!("<p>{text}</p>"));
+    }
+
+    fn list(out: &mut String, items: &[String]) {
+        out.push_str("<ul>");
+        for item in items {
+            out.push_str(&format!("<li>{item}</li>"));
+        }
+        out.push_str("</ul>");
+    }
+
+    fn code_block(out: &mut String, lines: &[&str]) {
+        out.push_str("<pre><code>");
+        out.push_str(&escape_html(&lines.join("\n")));
+        out.push_str("</code></pre>");
+    }
+}
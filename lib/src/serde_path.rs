@@ -0,0 +1,67 @@
+//! Docs of the field a deserialisation error occurred at, using the path
+//! tracked by [`serde_path_to_error`].
+//!
+//! Field names are resolved with [`DocumentedFields::get_field_docs_by_path`],
+//! so they need to match the serialised names (e.g. via `rename_all`), and
+//! nested types need to be marked as
+//! [nested](macro@crate::DocumentedFields#7-allow-looking-up-nested-fields-by-path-like-so).
+
+use serde_path_to_error::{Path, Segment};
+
+use crate::{DocumentedFields, DocumentedFieldsOpt, Error};
+
+/// Get the docs of the field at `path`, starting from `T`.
+///
+/// Sequence indices are skipped, so that e.g. `engines[0].threads` resolves to
+/// the `threads` field of the type marked as nested under `engines`.
+///
+/// # Example
+///
+/// ```rust
+/// use documented::{serde_path::field_docs, DocumentedFields};
+/// use serde::Deserialize;
+///
+/// #[derive(Deserialize, DocumentedFields)]
+/// struct Config {
+///     /// Engine settings.
+///     #[documented_fields(nested)]
+///     engine: Engine,
+/// }
+///
+/// #[derive(Deserialize, DocumentedFields)]
+/// struct Engine {
+///     /// Number of search threads.
+///     threads: u16,
+/// }
+///
+/// let json = r#"{ "engine": { "threads": "many" } }"#;
+/// let deserializer = &mut serde_json::Deserializer::from_str(json);
+/// let Err(err) = serde_path_to_error::deserialize::<_, Config>(deserializer) else {
+///     unreachable!("\"many\" is not a number");
+/// };
+///
+/// assert_eq!(field_docs::<Config>(err.path()), Ok("Number of search threads."));
+/// ```
+pub fn field_docs<T: DocumentedFields>(path: &Path) -> Result<&'static str, Error> {
+    T::get_field_docs_by_path(segments(path)?)
+}
+
+/// The optional variant of [`field_docs`].
+pub fn field_docs_opt<T: DocumentedFieldsOpt>(path: &Path) -> Result<&'static str, Error> {
+    T::get_field_docs_by_path(segments(path)?)
+}
+
+/// Get the field names of a path, skipping sequence indices.
+fn segments(path: &Path) -> Result<Vec<&str>, Error> {
+    path.iter()
+        .filter_map(|segment| match segment {
+            Segment::Map { key } => Some(Ok(key.as_str())),
+            Segment::Enum { variant } => Some(Ok(variant.as_str())),
+            Segment::Seq { .. } => None,
+            Segment::Unknown => Some(Err(Error::NoSuchField {
+                name: path.to_string(),
+                suggestion: None,
+            })),
+        })
+        .collect()
+}
@@ -0,0 +1,68 @@
+//! Documentation snapshots, so that doc changes show up in code review as
+//! plain text diffs.
+//!
+//! See [`document_snapshot!`](crate::document_snapshot) for usage.
+
+use std::{env, fs, path::Path};
+
+use crate::{util::field_names_by_index, Documented, DocumentedFields};
+
+/// Environment variable which, when set to `1`, makes
+/// [`document_snapshot!`](crate::document_snapshot) (re)write snapshot files
+/// instead of comparing against them.
+pub const UPDATE_ENV_VAR: &str = "DOCUMENTED_UPDATE_SNAPSHOTS";
+
+/// Render the full documentation of a type (container and fields/variants)
+/// into the canonical snapshot format.
+///
+/// Anonymous fields without a custom name are titled with their index.
+pub fn render<T>(type_name: &str) -> String
+where
+    T: Documented + DocumentedFields,
+{
+    let names = field_names_by_index::<T>();
+
+    let mut rendered = format!("# {type_name}\n\n{}\n", T::DOCS);
+    for (index, (name, docs)) in names.into_iter().zip(T::FIELD_DOCS).enumerate() {
+        let title = name.map_or_else(|| index.to_string(), str::to_string);
+        rendered.push_str(&format!("\n## {title}\n\n{docs}\n"));
+    }
+    rendered
+}
+
+/// Compare a rendered snapshot against the file at `path`, or write it to the
+/// file if [`UPDATE_ENV_VAR`] is set.
+///
+/// Used internally by [`document_snapshot!`](crate::document_snapshot).
+#[doc(hidden)]
+pub fn assert_snapshot(rendered: &str, path: &Path) {
+    if env::var(UPDATE_ENV_VAR).as_deref() == Ok("1") {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).expect("failed to create snapshot directory");
+        }
+        fs::write(path, rendered).expect("failed to write snapshot");
+        return;
+    }
+    compare_snapshot(rendered, path);
+}
+
+/// Compare a rendered snapshot against the file at `path`, panicking on
+/// mismatch regardless of [`UPDATE_ENV_VAR`].
+#[doc(hidden)]
+pub fn compare_snapshot(rendered: &str, path: &Path) {
+    let Ok(expected) = fs::read_to_string(path) else {
+        panic!(
+            "Documentation snapshot {} does not exist.\n\
+            Rerun with `{UPDATE_ENV_VAR}=1` to create it.",
+            path.display()
+        );
+    };
+    assert!(
+        expected == rendered,
+        "Documentation snapshot {} is outdated.\n\
+        Rerun with `{UPDATE_ENV_VAR}=1` to update it.\n\n\
+        --- expected ---\n{expected}\n\
+        --- actual ---\n{rendered}",
+        path.display()
+    );
+}
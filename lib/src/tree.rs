@@ -0,0 +1,60 @@
+//! Static trees of the docs of nested types, e.g. for rendering a full
+//! settings reference from a single root type.
+//!
+//! See [`DocumentedTree`] and [`macro@crate::DocumentedTree`] for usage.
+
+/// A node in a [`DocumentedTree`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DocNode {
+    /// The name of the type, field, or variant.
+    pub name: &'static str,
+    /// The doc comments on the type, field, or variant, if any.
+    pub docs: Option<&'static str>,
+    /// A node for each field (or variant, for enums), in declaration order.
+    pub children: &'static [DocNode],
+}
+
+impl DocNode {
+    /// Get a direct child of this node by name.
+    pub fn child(&self, name: &str) -> Option<&'static DocNode> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    /// Get a descendant of this node by its path, e.g. `["engine", "threads"]`.
+    ///
+    /// An empty path returns `None`.
+    pub fn descendant<I, S>(&self, path: I) -> Option<&'static DocNode>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut path = path.into_iter();
+        let mut node = self.child(path.next()?.as_ref())?;
+        for name in path {
+            node = node.child(name.as_ref())?;
+        }
+        Some(node)
+    }
+
+    /// Visit this node and all of its descendants depth-first, along with
+    /// their depth relative to this node.
+    pub fn walk(&self, mut visit: impl FnMut(usize, &DocNode)) {
+        fn walk_inner(node: &DocNode, depth: usize, visit: &mut impl FnMut(usize, &DocNode)) {
+            visit(depth, node);
+            for child in node.children {
+                walk_inner(child, depth + 1, visit);
+            }
+        }
+        walk_inner(self, 0, &mut visit);
+    }
+}
+
+/// Adds an associated constant [`DOC_TREE`](Self::DOC_TREE) with the docs of
+/// a type and its fields or variants, recursing into fields marked as
+/// nested.
+///
+/// For how to use the derive macro, see [`macro@crate::DocumentedTree`].
+pub trait DocumentedTree {
+    /// The root node, named after this type.
+    const DOC_TREE: DocNode;
+}
@@ -0,0 +1,106 @@
+//! Shared helpers of the runtime modules.
+
+use std::fmt::Write;
+
+use crate::DocumentedFields;
+
+/// Get the name of each field of `T`, indexed by field order.
+///
+/// Anonymous fields without a custom name are `None`.
+pub(crate) fn field_names_by_index<T: DocumentedFields>() -> Vec<Option<&'static str>> {
+    names_by_index(T::FIELD_NAMES, T::FIELD_DOCS.len(), |name| {
+        T::__documented_get_index(name)
+    })
+}
+
+/// Place each of `field_names` at the index returned by `get_index`, in a list
+/// of `len` fields.
+pub(crate) fn names_by_index(
+    field_names: &[&'static str],
+    len: usize,
+    get_index: impl Fn(&str) -> Option<usize>,
+) -> Vec<Option<&'static str>> {
+    let mut names = vec![None; len];
+    for &name in field_names {
+        if let Some(index) = get_index(name) {
+            names[index] = Some(name);
+        }
+    }
+    names
+}
+
+/// Escape a string for HTML text content.
+pub(crate) fn escape_html(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Escape a string into a quoted JSON string.
+pub(crate) fn escape_json(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// Find the candidate closest to `name` by edit distance, for suggesting a
+/// fix for a misspelt name. A candidate that only differs in case always
+/// wins, and otherwise up to one edit per three characters is allowed.
+pub(crate) fn closest_name(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'static str>,
+) -> Option<&'static str> {
+    let max_distance = name.chars().count().max(3) / 3;
+    candidates
+        .into_iter()
+        .filter(|candidate| *candidate != name)
+        .map(|candidate| {
+            let distance = if candidate.eq_ignore_ascii_case(name) {
+                0
+            } else {
+                edit_distance(name, candidate)
+            };
+            (distance, candidate)
+        })
+        .filter(|&(distance, _)| distance <= max_distance)
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// The Levenshtein distance between `a` and `b`, in characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut prev = (0..=b.len()).collect::<Vec<_>>();
+    for (i, ca) in a.chars().enumerate() {
+        let mut curr = vec![i + 1; b.len() + 1];
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != cb);
+            curr[j + 1] = substitution.min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        prev = curr;
+    }
+    prev[b.len()]
+}